@@ -7,7 +7,10 @@ use pendzl::{
 };
 
 use crate::{
-    finance::general_vest::VestingData,
+    finance::{
+        general_vest::VestingData,
+        native_asset::{handle_native_transfer_in, handle_native_transfer_out},
+    },
     token::psp22::{PSP22Ref, PSP22},
 };
 
@@ -249,10 +252,7 @@ where
                 let mut psp22: PSP22Ref = asset.into();
                 psp22.transfer(to, amount as Balance, vec![])?
             }
-            None => match Self::env().transfer(to, amount) {
-                Ok(_) => {}
-                Err(_) => return Err(VestingError::NativeTransferFailed),
-            },
+            None => handle_native_transfer_out(to, amount)?,
         }
         Ok(())
     }
@@ -269,11 +269,7 @@ where
                 let to = Self::env().account_id();
                 psp22.transfer_from(from, to, amount as Balance, vec![])?
             }
-            None => {
-                if Self::env().transferred_value() != amount {
-                    return Err(VestingError::InvalidAmountPaid);
-                }
-            }
+            None => handle_native_transfer_in(amount)?,
         }
         Ok(())
     }
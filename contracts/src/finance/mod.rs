@@ -2,3 +2,9 @@
 // SPDX-License-Identifier: MIT
 #[cfg(any(feature = "general_vest", feature = "provide_vest_schedule_info"))]
 pub mod general_vest;
+#[cfg(any(
+    feature = "general_vest_impl",
+    feature = "psp22_dividends_impl",
+    feature = "psp22_native_wrapper_impl"
+))]
+pub(crate) mod native_asset;
@@ -0,0 +1,66 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::env::DefaultEnvironment;
+use pendzl::traits::{AccountId, Balance};
+
+#[cfg(feature = "general_vest")]
+use crate::finance::general_vest::VestingError;
+#[cfg(feature = "psp22")]
+use crate::token::psp22::PSP22Error;
+
+/// Errors of the native currency handling shared by GeneralVest, PSP22Dividends and PSP22NativeWrapper.
+/// Implementations map them into their own error types.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum NativeAssetError {
+    /// Returned if the transfer of native currency fails.
+    TransferFailed,
+    /// Returned if the value transferred with the call differs from the expected amount.
+    InvalidAmountPaid,
+}
+
+/// Checks that exactly `amount` of native currency was transferred with the call.
+pub(crate) fn handle_native_transfer_in(
+    amount: Balance,
+) -> Result<(), NativeAssetError> {
+    if ink::env::transferred_value::<DefaultEnvironment>() != amount {
+        return Err(NativeAssetError::InvalidAmountPaid);
+    }
+    Ok(())
+}
+
+/// Transfers `amount` of native currency from the contract to `to`.
+pub(crate) fn handle_native_transfer_out(
+    to: AccountId,
+    amount: Balance,
+) -> Result<(), NativeAssetError> {
+    ink::env::transfer::<DefaultEnvironment>(to, amount)
+        .map_err(|_| NativeAssetError::TransferFailed)
+}
+
+#[cfg(feature = "general_vest")]
+impl From<NativeAssetError> for VestingError {
+    fn from(error: NativeAssetError) -> Self {
+        match error {
+            NativeAssetError::TransferFailed => {
+                VestingError::NativeTransferFailed
+            }
+            NativeAssetError::InvalidAmountPaid => {
+                VestingError::InvalidAmountPaid
+            }
+        }
+    }
+}
+
+#[cfg(feature = "psp22")]
+impl From<NativeAssetError> for PSP22Error {
+    fn from(error: NativeAssetError) -> Self {
+        match error {
+            NativeAssetError::TransferFailed => {
+                PSP22Error::NativeTransferFailed
+            }
+            NativeAssetError::InvalidAmountPaid => {
+                PSP22Error::InvalidAmountPaid
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22BatchRef = contract_ref!(PSP22Batch, DefaultEnvironment);
+
+/// trait extending PSP22 with batch transfers and approvals.
+///
+/// All batch messages are atomic - if any of the legs fails, none of them is applied.
+#[ink::trait_definition]
+pub trait PSP22Batch {
+    /// Transfers tokens from the caller's account to each of the `transfers` recipients
+    /// with additional `data` in unspecified format.
+    ///
+    /// On success a `Transfer` event is emitted for each leg.
+    ///
+    /// # Errors
+    ///
+    /// Returns with error `InsufficientBalance` if there are not enough tokens on
+    /// the caller's account Balance to cover all the legs.
+    /// Returns with error `SafeTransferCheckFailed` if any of the recipients is a contract and rejected the transfer.
+    #[ink(message)]
+    fn batch_transfer(
+        &mut self,
+        transfers: Vec<(AccountId, Balance)>,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error>;
+
+    /// Transfers tokens on the behalf of `from` to each of the `transfers` recipients.
+    ///
+    /// The allowance of the caller is decreased once by the sum of all legs.
+    ///
+    /// On success an `Approval` event and a `Transfer` event for each leg are emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with error `InsufficientAllowance` if there are not enough tokens allowed
+    /// for the caller to withdraw from `from` to cover all the legs.
+    /// Returns with error `InsufficientBalance` if there are not enough tokens on
+    /// the account Balance of `from` to cover all the legs.
+    /// Returns with error `SafeTransferCheckFailed` if any of the recipients is a contract and rejected the transfer.
+    #[ink(message)]
+    fn batch_transfer_from(
+        &mut self,
+        from: AccountId,
+        transfers: Vec<(AccountId, Balance)>,
+    ) -> Result<(), PSP22Error>;
+
+    /// Sets the allowance of each of the `approvals` spenders to spend the caller's tokens.
+    ///
+    /// An `Approval` event is emitted for each spender.
+    #[ink(message)]
+    fn batch_approve(
+        &mut self,
+        approvals: Vec<(AccountId, Balance)>,
+    ) -> Result<(), PSP22Error>;
+}
+
+/// trait that is derived by Pendzl PSP22Batch implementation macro
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22BatchInternal {
+    /// Transfers tokens `from` to each of the `transfers` recipients on behalf of `operator`
+    /// (the caller performing the transfer) calling `PSP22Internal::_do_safe_transfer_check` for each leg.
+    ///
+    /// Checks that `from` has enough balance to cover all the legs before any of them is applied.
+    ///
+    /// On success emits a `Transfer` event for each leg.
+    fn _batch_transfer(
+        &mut self,
+        from: &AccountId,
+        operator: &AccountId,
+        transfers: &[(AccountId, Balance)],
+        data: &[u8],
+    ) -> Result<(), PSP22Error>;
+
+    /// Sets the allowance of each of the `approvals` spenders to spend `owner`'s tokens.
+    ///
+    /// On success emits an `Approval` event for each spender.
+    fn _batch_approve(
+        &mut self,
+        owner: &AccountId,
+        approvals: &[(AccountId, Balance)],
+    ) -> Result<(), PSP22Error>;
+}
@@ -0,0 +1,78 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{prelude::vec::Vec, primitives::AccountId};
+use pendzl::{
+    math::errors::MathError,
+    traits::{Balance, DefaultEnv},
+};
+
+use super::PSP22BatchInternal;
+use crate::token::psp22::{PSP22Error, PSP22Internal};
+
+fn total_value(legs: &[(AccountId, Balance)]) -> Result<Balance, MathError> {
+    legs.iter().try_fold(0, |acc: Balance, (_, value)| {
+        acc.checked_add(*value).ok_or(MathError::Overflow)
+    })
+}
+
+pub trait PSP22BatchDefaultImpl:
+    PSP22BatchInternal + PSP22Internal + DefaultEnv
+{
+    fn batch_transfer_default_impl(
+        &mut self,
+        transfers: Vec<(AccountId, Balance)>,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error> {
+        let from = Self::env().caller();
+        self._batch_transfer(&from, &from, &transfers, &data)
+    }
+
+    fn batch_transfer_from_default_impl(
+        &mut self,
+        from: AccountId,
+        transfers: Vec<(AccountId, Balance)>,
+    ) -> Result<(), PSP22Error> {
+        let caller = Self::env().caller();
+        let total = total_value(&transfers)?;
+        self._spend_allowance(&from, &caller, &total)?;
+        self._batch_transfer(&from, &caller, &transfers, &[])
+    }
+
+    fn batch_approve_default_impl(
+        &mut self,
+        approvals: Vec<(AccountId, Balance)>,
+    ) -> Result<(), PSP22Error> {
+        self._batch_approve(&Self::env().caller(), &approvals)
+    }
+}
+
+pub trait PSP22BatchInternalDefaultImpl: PSP22Internal {
+    fn _batch_transfer_default_impl(
+        &mut self,
+        from: &AccountId,
+        operator: &AccountId,
+        transfers: &[(AccountId, Balance)],
+        data: &[u8],
+    ) -> Result<(), PSP22Error> {
+        let total = total_value(transfers)?;
+        if self._balance_of(from) < total {
+            return Err(PSP22Error::InsufficientBalance);
+        }
+        for (to, value) in transfers {
+            self._do_safe_transfer_check(from, to, operator, value, data)?;
+            self._update(Some(from), Some(to), value)?;
+        }
+        Ok(())
+    }
+
+    fn _batch_approve_default_impl(
+        &mut self,
+        owner: &AccountId,
+        approvals: &[(AccountId, Balance)],
+    ) -> Result<(), PSP22Error> {
+        for (spender, value) in approvals {
+            self._approve(owner, spender, value)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+pub use crate::token::psp22::PSP22Error;
+pub use ink::{prelude::vec::Vec, primitives::AccountId};
+pub use pendzl::traits::Balance;
+
+include!("batch_trait.rs");
+
+#[cfg(feature = "psp22_batch_impl")]
+mod implementation;
+
+#[cfg(feature = "psp22_batch_impl")]
+pub use implementation::*;
@@ -7,6 +7,10 @@ use super::super::PSP22Error;
 use ink::{contract_ref, env::DefaultEnvironment, primitives::AccountId};
 pub use pendzl::traits::Balance;
 pub type PSP22BurnableRef = contract_ref!(PSP22Burnable, DefaultEnvironment);
+pub type PSP22BurnableFromRef =
+    contract_ref!(PSP22BurnableFrom, DefaultEnvironment);
+pub type PSP22BurnableSelfRef =
+    contract_ref!(PSP22BurnableSelf, DefaultEnvironment);
 
 /// trait extending PSP22 with burn functionality
 #[ink::trait_definition]
@@ -22,3 +26,41 @@ pub trait PSP22Burnable {
         amount: Balance,
     ) -> Result<(), PSP22Error>;
 }
+
+/// trait extending PSP22 with burn functionality respecting allowances
+#[ink::trait_definition]
+pub trait PSP22BurnableFrom {
+    /// Destroys `amount` tokens from `account`, deducting from the caller's
+    /// allowance.
+    ///
+    /// On success a `Transfer` event is emitted (burning) and an `Approval` event
+    /// with the decreased allowance.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientAllowance` error if the caller's allowance is smaller than `amount`.
+    ///
+    /// Returns `InsufficientBalance` error if `account` does not have enough tokens.
+    #[ink(message)]
+    fn burn_from(
+        &mut self,
+        account: AccountId,
+        amount: Balance,
+    ) -> Result<(), PSP22Error>;
+}
+
+/// trait extending PSP22 with burn functionality limited to the caller's tokens
+///
+/// The message is named `burn_self` so it doesn't clash with `PSP22Burnable::burn` when both traits are implemented.
+#[ink::trait_definition]
+pub trait PSP22BurnableSelf {
+    /// Destroys `amount` tokens of the caller.
+    ///
+    /// On success a `Transfer` event is emitted (burning).
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientBalance` error if the caller does not have enough tokens.
+    #[ink(message)]
+    fn burn_self(&mut self, amount: Balance) -> Result<(), PSP22Error>;
+}
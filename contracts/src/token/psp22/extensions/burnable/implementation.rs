@@ -6,6 +6,7 @@ use crate::token::psp22::PSP22Error;
 pub use crate::token::psp22::PSP22Internal;
 
 pub use pendzl::traits::Balance;
+use pendzl::traits::DefaultEnv;
 
 pub trait PSP22BurnableDefaultImpl: PSP22Internal {
     fn burn_default_impl(
@@ -16,3 +17,23 @@ pub trait PSP22BurnableDefaultImpl: PSP22Internal {
         self._burn_from(&account, &amount)
     }
 }
+
+pub trait PSP22BurnableFromDefaultImpl: PSP22Internal + DefaultEnv {
+    fn burn_from_default_impl(
+        &mut self,
+        account: AccountId,
+        amount: Balance,
+    ) -> Result<(), PSP22Error> {
+        self._spend_allowance(&account, &Self::env().caller(), &amount)?;
+        self._burn_from(&account, &amount)
+    }
+}
+
+pub trait PSP22BurnableSelfDefaultImpl: PSP22Internal + DefaultEnv {
+    fn burn_self_default_impl(
+        &mut self,
+        amount: Balance,
+    ) -> Result<(), PSP22Error> {
+        self._burn_from(&Self::env().caller(), &amount)
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22CappedRef = contract_ref!(PSP22Capped, DefaultEnvironment);
+
+/// trait extending PSP22 with a cap on the total supply
+#[ink::trait_definition]
+pub trait PSP22Capped {
+    /// Returns the maximal total supply of the token.
+    #[ink(message)]
+    fn cap(&self) -> Balance;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22CappedInternal and PSP22Capped implementation can be derived.
+pub trait PSP22CappedStorage {
+    /// Returns the cap.
+    fn cap(&self) -> Balance;
+
+    /// Sets the cap.
+    fn set_cap(&mut self, cap: &Balance);
+}
+
+/// trait that is derived by Pendzl PSP22Capped implementation macro assuming StorageFieldGetter<PSP22CappedStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22CappedInternal {
+    /// Returns the cap.
+    fn _cap(&self) -> Balance;
+
+    /// Sets the cap.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CapExceeded` if `cap` is lower than the current total supply.
+    fn _set_cap(&mut self, cap: &Balance) -> Result<(), PSP22Error>;
+
+    /// Checks if `amount` of tokens can be minted without exceeding the cap.
+    ///
+    /// Called by the `PSP22Internal::_update` derived by the `implementation` macro on every mint.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CapExceeded` if the total supply would exceed the cap.
+    fn _ensure_cap_not_exceeded(
+        &self,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+}
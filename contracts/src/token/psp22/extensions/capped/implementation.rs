@@ -0,0 +1,75 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use pendzl::{
+    math::errors::MathError,
+    traits::{Balance, StorageFieldGetter},
+};
+
+use super::{PSP22CappedInternal, PSP22CappedStorage};
+use crate::token::psp22::{PSP22Error, PSP22Internal};
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22CappedData {
+    #[lazy]
+    pub cap: Balance,
+}
+
+impl PSP22CappedData {
+    pub fn new(cap: Balance) -> Self {
+        let mut instance: PSP22CappedData = Default::default();
+        instance.cap.set(&cap);
+        instance
+    }
+}
+
+impl PSP22CappedStorage for PSP22CappedData {
+    fn cap(&self) -> Balance {
+        self.cap.get_or_default()
+    }
+
+    fn set_cap(&mut self, cap: &Balance) {
+        self.cap.set(cap);
+    }
+}
+
+pub trait PSP22CappedDefaultImpl: PSP22CappedInternal {
+    fn cap_default_impl(&self) -> Balance {
+        self._cap()
+    }
+}
+
+pub trait PSP22CappedInternalDefaultImpl:
+    StorageFieldGetter<PSP22CappedData> + PSP22Internal
+where
+    PSP22CappedData: PSP22CappedStorage,
+{
+    fn _cap_default_impl(&self) -> Balance {
+        self.data().cap()
+    }
+
+    fn _set_cap_default_impl(
+        &mut self,
+        cap: &Balance,
+    ) -> Result<(), PSP22Error> {
+        if *cap < self._total_supply() {
+            return Err(PSP22Error::CapExceeded);
+        }
+        self.data().set_cap(cap);
+        Ok(())
+    }
+
+    fn _ensure_cap_not_exceeded_default_impl(
+        &self,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        let new_total_supply = self
+            ._total_supply()
+            .checked_add(*amount)
+            .ok_or(MathError::Overflow)?;
+        if new_total_supply > self.data().cap() {
+            return Err(PSP22Error::CapExceeded);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,12 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+pub use crate::token::psp22::PSP22Error;
+pub use pendzl::traits::Balance;
+
+include!("capped_trait.rs");
+
+#[cfg(feature = "psp22_capped_impl")]
+mod implementation;
+
+#[cfg(feature = "psp22_capped_impl")]
+pub use implementation::*;
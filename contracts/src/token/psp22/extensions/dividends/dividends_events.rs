@@ -0,0 +1,30 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+/// Emitted when `amount` of `asset` is distributed to the token holders.
+#[ink::event]
+#[derive(Debug)]
+pub struct DividendsDistributed {
+    /// The account that paid the dividends.
+    #[ink(topic)]
+    pub from: AccountId,
+    /// The distributed asset (`None` for the native currency).
+    #[ink(topic)]
+    pub asset: Option<AccountId>,
+    /// The amount of the distributed asset.
+    pub amount: Balance,
+}
+
+/// Emitted when `account` withdraws `amount` of its `asset` dividends.
+#[ink::event]
+#[derive(Debug)]
+pub struct DividendsWithdrawn {
+    /// The account that withdrew the dividends.
+    #[ink(topic)]
+    pub account: AccountId,
+    /// The withdrawn asset (`None` for the native currency).
+    #[ink(topic)]
+    pub asset: Option<AccountId>,
+    /// The amount of the withdrawn asset.
+    pub amount: Balance,
+}
@@ -0,0 +1,208 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22DividendsRef = contract_ref!(PSP22Dividends, DefaultEnvironment);
+
+/// trait extending PSP22 with distribution of dividends to the token holders.
+///
+/// Dividends can be paid in the native currency (`asset` = `None`) or in any PSP22 token.
+/// Each distribution increases a magnified dividends-per-share accumulator of the asset and
+/// transfers of the token update per account corrections, so no iteration over the holders is needed.
+/// The holders claim their dividends with `withdraw_dividends`.
+#[ink::trait_definition]
+pub trait PSP22Dividends {
+    /// Distributes `amount` of `asset` among the token holders proportionally to their balances.
+    ///
+    /// For the native currency (`asset` = `None`) `amount` must be transferred with the call.
+    /// For a PSP22 `asset` the caller must have approved the contract to spend at least `amount` of it.
+    ///
+    /// On success a `DividendsDistributed` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ZeroTotalSupply` error if there are no tokens to distribute the dividends to.
+    ///
+    /// Returns `InvalidAmountPaid` error if the native currency transferred with the call differs from `amount`.
+    ///
+    /// Returns `Custom("M::Overflow")` error if the dividends per token of `asset` would exceed `u128::MAX / DIVIDENDS_MAGNITUDE`.
+    ///
+    /// Returns with error if the transfer of the PSP22 `asset` fails.
+    #[ink(message, payable)]
+    fn distribute_dividends(
+        &mut self,
+        asset: Option<AccountId>,
+        amount: Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Transfers all `asset` dividends withdrawable by the caller to the caller.
+    ///
+    /// Returns the withdrawn amount.
+    ///
+    /// On success a `DividendsWithdrawn` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NativeTransferFailed` error if the transfer of the native currency fails.
+    ///
+    /// Returns with error if the transfer of the PSP22 `asset` fails.
+    #[ink(message)]
+    fn withdraw_dividends(
+        &mut self,
+        asset: Option<AccountId>,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Returns the amount of `asset` dividends that `account` can withdraw.
+    #[ink(message)]
+    fn withdrawable_dividends_of(
+        &self,
+        account: AccountId,
+        asset: Option<AccountId>,
+    ) -> Balance;
+
+    /// Returns the amount of `asset` dividends that `account` has already withdrawn.
+    #[ink(message)]
+    fn withdrawn_dividends_of(
+        &self,
+        account: AccountId,
+        asset: Option<AccountId>,
+    ) -> Balance;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22DividendsInternal and PSP22Dividends implementation can be derived.
+pub trait PSP22DividendsStorage {
+    /// Returns the assets in which dividends were distributed.
+    fn assets(&self) -> Vec<Option<AccountId>>;
+
+    /// Returns the magnified dividends-per-share accumulator of `asset`.
+    fn magnified_dividends_per_share(&self, asset: &Option<AccountId>) -> u128;
+
+    /// Sets the magnified dividends-per-share accumulator of `asset` and registers `asset` if it is new.
+    fn set_magnified_dividends_per_share(
+        &mut self,
+        asset: &Option<AccountId>,
+        value: &u128,
+    );
+
+    /// Returns the correction of `asset` dividends of `account`.
+    fn correction(&self, account: &AccountId, asset: &Option<AccountId>) -> i128;
+
+    /// Sets the correction of `asset` dividends of `account`.
+    fn set_correction(
+        &mut self,
+        account: &AccountId,
+        asset: &Option<AccountId>,
+        value: &i128,
+    );
+
+    /// Returns the amount of `asset` dividends withdrawn by `account`.
+    fn withdrawn(&self, account: &AccountId, asset: &Option<AccountId>) -> Balance;
+
+    /// Sets the amount of `asset` dividends withdrawn by `account`.
+    fn set_withdrawn(
+        &mut self,
+        account: &AccountId,
+        asset: &Option<AccountId>,
+        value: &Balance,
+    );
+}
+
+/// trait that is derived by Pendzl PSP22Dividends implementation macro assuming StorageFieldGetter<PSP22DividendsStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22DividendsInternal {
+    /// Returns the amount of `asset` dividends earned by `account` so far (including the withdrawn ones).
+    fn _accumulative_dividends_of(
+        &self,
+        account: &AccountId,
+        asset: &Option<AccountId>,
+    ) -> Balance;
+
+    /// Returns the amount of `asset` dividends that `account` can withdraw.
+    fn _withdrawable_dividends_of(
+        &self,
+        account: &AccountId,
+        asset: &Option<AccountId>,
+    ) -> Balance;
+
+    /// Returns the amount of `asset` dividends that `account` has already withdrawn.
+    fn _withdrawn_dividends_of(
+        &self,
+        account: &AccountId,
+        asset: &Option<AccountId>,
+    ) -> Balance;
+
+    /// Transfers `amount` of `asset` from `from` to the contract and distributes it
+    /// among the token holders.
+    ///
+    /// On success emits a `DividendsDistributed` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ZeroTotalSupply` if the total supply is zero.
+    ///
+    /// Returns `Custom("M::Overflow")` if the dividends per token of `asset` would exceed `u128::MAX / DIVIDENDS_MAGNITUDE`,
+    /// before anything is transferred in.
+    ///
+    /// Returns with error if `_handle_dividends_transfer_in` fails.
+    fn _distribute_dividends(
+        &mut self,
+        from: &AccountId,
+        asset: &Option<AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Transfers all `asset` dividends withdrawable by `account` to `account`.
+    ///
+    /// Returns the withdrawn amount. On success emits a `DividendsWithdrawn` event
+    /// (if the amount is not zero).
+    ///
+    /// # Errors
+    ///
+    /// Returns with error if `_handle_dividends_transfer_out` fails.
+    fn _withdraw_dividends(
+        &mut self,
+        account: &AccountId,
+        asset: &Option<AccountId>,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Updates the corrections of `from` and `to` for all assets after `amount` of tokens was moved.
+    ///
+    /// Called by the `PSP22Internal::_update` derived by the `implementation` macro.
+    fn _update_dividends_corrections(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Handles the transfer of `amount` of `asset` from `from` into the contract.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidAmountPaid` if the native currency transferred with the call differs from `amount`.
+    ///
+    /// Returns with error if the transfer_from of the PSP22 `asset` fails.
+    fn _handle_dividends_transfer_in(
+        &mut self,
+        asset: &Option<AccountId>,
+        from: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Handles the transfer of `amount` of `asset` from the contract to `to`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NativeTransferFailed` if the transfer of the native currency fails.
+    ///
+    /// Returns with error if the transfer of the PSP22 `asset` fails.
+    fn _handle_dividends_transfer_out(
+        &mut self,
+        asset: &Option<AccountId>,
+        to: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+}
@@ -0,0 +1,325 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{
+    prelude::vec, prelude::vec::Vec, primitives::AccountId, storage::Mapping,
+};
+use pendzl::{
+    math::{
+        errors::MathError,
+        operations::{mul_div, Rounding},
+    },
+    traits::{Balance, DefaultEnv, StorageFieldGetter},
+};
+
+use super::{
+    DividendsDistributed, DividendsWithdrawn, PSP22DividendsInternal,
+    PSP22DividendsStorage,
+};
+use crate::{
+    finance::native_asset::{
+        handle_native_transfer_in, handle_native_transfer_out,
+    },
+    token::psp22::{PSP22Error, PSP22Internal, PSP22Ref, PSP22},
+};
+
+/// Magnitude of the dividends-per-share accumulators.
+///
+/// Dividends smaller than `total_supply / DIVIDENDS_MAGNITUDE` are too small to be
+/// accounted and stay in the contract. The dividends distributed per token (summed over all
+/// distributions of the asset) can not exceed `u128::MAX / DIVIDENDS_MAGNITUDE` (~3.4 * 10^20),
+/// bigger distributions fail with `Custom("M::Overflow")`.
+pub const DIVIDENDS_MAGNITUDE: u128 = 1_000_000_000_000_000_000;
+
+/// Key of per holder dividends accounting - the holder and the dividends asset (`None` - native currency).
+pub type HolderAssetKey = (AccountId, Option<AccountId>);
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22DividendsData {
+    #[lazy]
+    pub assets: Vec<Option<AccountId>>,
+    pub magnified_dividends_per_share: Mapping<Option<AccountId>, u128>,
+    pub corrections: Mapping<HolderAssetKey, i128>,
+    pub withdrawn: Mapping<HolderAssetKey, Balance>,
+}
+
+impl PSP22DividendsStorage for PSP22DividendsData {
+    fn assets(&self) -> Vec<Option<AccountId>> {
+        self.assets.get_or_default()
+    }
+
+    fn magnified_dividends_per_share(&self, asset: &Option<AccountId>) -> u128 {
+        self.magnified_dividends_per_share
+            .get(asset)
+            .unwrap_or_default()
+    }
+
+    fn set_magnified_dividends_per_share(
+        &mut self,
+        asset: &Option<AccountId>,
+        value: &u128,
+    ) {
+        if !self.magnified_dividends_per_share.contains(asset) {
+            let mut assets = self.assets.get_or_default();
+            assets.push(*asset);
+            self.assets.set(&assets);
+        }
+        self.magnified_dividends_per_share.insert(asset, value);
+    }
+
+    fn correction(
+        &self,
+        account: &AccountId,
+        asset: &Option<AccountId>,
+    ) -> i128 {
+        self.corrections.get((*account, *asset)).unwrap_or_default()
+    }
+
+    fn set_correction(
+        &mut self,
+        account: &AccountId,
+        asset: &Option<AccountId>,
+        value: &i128,
+    ) {
+        self.corrections.insert((*account, *asset), value);
+    }
+
+    fn withdrawn(
+        &self,
+        account: &AccountId,
+        asset: &Option<AccountId>,
+    ) -> Balance {
+        self.withdrawn.get((*account, *asset)).unwrap_or_default()
+    }
+
+    fn set_withdrawn(
+        &mut self,
+        account: &AccountId,
+        asset: &Option<AccountId>,
+        value: &Balance,
+    ) {
+        self.withdrawn.insert((*account, *asset), value);
+    }
+}
+
+pub trait PSP22DividendsDefaultImpl:
+    PSP22DividendsInternal + DefaultEnv
+{
+    fn distribute_dividends_default_impl(
+        &mut self,
+        asset: Option<AccountId>,
+        amount: Balance,
+    ) -> Result<(), PSP22Error> {
+        self._distribute_dividends(&Self::env().caller(), &asset, &amount)
+    }
+
+    fn withdraw_dividends_default_impl(
+        &mut self,
+        asset: Option<AccountId>,
+    ) -> Result<Balance, PSP22Error> {
+        self._withdraw_dividends(&Self::env().caller(), &asset)
+    }
+
+    fn withdrawable_dividends_of_default_impl(
+        &self,
+        account: AccountId,
+        asset: Option<AccountId>,
+    ) -> Balance {
+        self._withdrawable_dividends_of(&account, &asset)
+    }
+
+    fn withdrawn_dividends_of_default_impl(
+        &self,
+        account: AccountId,
+        asset: Option<AccountId>,
+    ) -> Balance {
+        self._withdrawn_dividends_of(&account, &asset)
+    }
+}
+
+pub trait PSP22DividendsInternalDefaultImpl:
+    StorageFieldGetter<PSP22DividendsData> + PSP22Internal + PSP22DividendsInternal
+where
+    PSP22DividendsData: PSP22DividendsStorage,
+{
+    fn _accumulative_dividends_of_default_impl(
+        &self,
+        account: &AccountId,
+        asset: &Option<AccountId>,
+    ) -> Balance {
+        let magnified_dividends_per_share =
+            self.data().magnified_dividends_per_share(asset);
+        let dividends = mul_div(
+            magnified_dividends_per_share,
+            self._balance_of(account),
+            DIVIDENDS_MAGNITUDE,
+            Rounding::Down,
+        )
+        .unwrap_or_default();
+        let correction = self.data().correction(account, asset);
+        if correction >= 0 {
+            dividends.saturating_add(correction.unsigned_abs())
+        } else {
+            dividends.saturating_sub(correction.unsigned_abs())
+        }
+    }
+
+    fn _withdrawable_dividends_of_default_impl(
+        &self,
+        account: &AccountId,
+        asset: &Option<AccountId>,
+    ) -> Balance {
+        self._accumulative_dividends_of(account, asset)
+            .saturating_sub(self._withdrawn_dividends_of(account, asset))
+    }
+
+    fn _withdrawn_dividends_of_default_impl(
+        &self,
+        account: &AccountId,
+        asset: &Option<AccountId>,
+    ) -> Balance {
+        self.data().withdrawn(account, asset)
+    }
+
+    fn _distribute_dividends_default_impl(
+        &mut self,
+        from: &AccountId,
+        asset: &Option<AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        let total_supply = self._total_supply();
+        if total_supply == 0 {
+            return Err(PSP22Error::ZeroTotalSupply);
+        }
+        // the accumulator overflow is checked before any asset is transferred in
+        let increase = mul_div(
+            *amount,
+            DIVIDENDS_MAGNITUDE,
+            total_supply,
+            Rounding::Down,
+        )?;
+        let magnified_dividends_per_share = self
+            .data()
+            .magnified_dividends_per_share(asset)
+            .checked_add(increase)
+            .ok_or(MathError::Overflow)?;
+        self._handle_dividends_transfer_in(asset, from, amount)?;
+        self.data().set_magnified_dividends_per_share(
+            asset,
+            &magnified_dividends_per_share,
+        );
+        Self::env().emit_event(DividendsDistributed {
+            from: *from,
+            asset: *asset,
+            amount: *amount,
+        });
+        Ok(())
+    }
+
+    fn _withdraw_dividends_default_impl(
+        &mut self,
+        account: &AccountId,
+        asset: &Option<AccountId>,
+    ) -> Result<Balance, PSP22Error> {
+        let amount = self._withdrawable_dividends_of(account, asset);
+        if amount == 0 {
+            return Ok(0);
+        }
+        let withdrawn = self
+            ._withdrawn_dividends_of(account, asset)
+            .checked_add(amount)
+            .ok_or(MathError::Overflow)?;
+        self.data().set_withdrawn(account, asset, &withdrawn);
+        self._handle_dividends_transfer_out(asset, account, &amount)?;
+        Self::env().emit_event(DividendsWithdrawn {
+            account: *account,
+            asset: *asset,
+            amount,
+        });
+        Ok(amount)
+    }
+
+    fn _update_dividends_corrections_default_impl(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        for asset in self.data().assets() {
+            let magnified_dividends_per_share =
+                self.data().magnified_dividends_per_share(&asset);
+            // corrections are rounded in favor of the contract so the dividends
+            // accounted to the holders never exceed the distributed ones
+            if let Some(from) = from {
+                let change = mul_div(
+                    magnified_dividends_per_share,
+                    *amount,
+                    DIVIDENDS_MAGNITUDE,
+                    Rounding::Down,
+                )?;
+                let correction = self
+                    .data()
+                    .correction(from, &asset)
+                    .checked_add(
+                        i128::try_from(change)
+                            .map_err(|_| MathError::Overflow)?,
+                    )
+                    .ok_or(MathError::Overflow)?;
+                self.data().set_correction(from, &asset, &correction);
+            }
+            if let Some(to) = to {
+                let change = mul_div(
+                    magnified_dividends_per_share,
+                    *amount,
+                    DIVIDENDS_MAGNITUDE,
+                    Rounding::Up,
+                )?;
+                let correction = self
+                    .data()
+                    .correction(to, &asset)
+                    .checked_sub(
+                        i128::try_from(change)
+                            .map_err(|_| MathError::Overflow)?,
+                    )
+                    .ok_or(MathError::Underflow)?;
+                self.data().set_correction(to, &asset, &correction);
+            }
+        }
+        Ok(())
+    }
+
+    fn _handle_dividends_transfer_in_default_impl(
+        &mut self,
+        asset: &Option<AccountId>,
+        from: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        match asset {
+            Some(asset) => {
+                let mut psp22: PSP22Ref = (*asset).into();
+                psp22.transfer_from(
+                    *from,
+                    Self::env().account_id(),
+                    *amount,
+                    vec![],
+                )
+            }
+            None => Ok(handle_native_transfer_in(*amount)?),
+        }
+    }
+
+    fn _handle_dividends_transfer_out_default_impl(
+        &mut self,
+        asset: &Option<AccountId>,
+        to: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        match asset {
+            Some(asset) => {
+                let mut psp22: PSP22Ref = (*asset).into();
+                psp22.transfer(*to, *amount, vec![])
+            }
+            None => Ok(handle_native_transfer_out(*to, *amount)?),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+pub use crate::token::psp22::PSP22Error;
+pub use ink::{prelude::vec::Vec, primitives::AccountId};
+pub use pendzl::traits::Balance;
+
+include!("dividends_events.rs");
+include!("dividends_trait.rs");
+
+#[cfg(feature = "psp22_dividends_impl")]
+mod implementation;
+
+#[cfg(feature = "psp22_dividends_impl")]
+pub use implementation::*;
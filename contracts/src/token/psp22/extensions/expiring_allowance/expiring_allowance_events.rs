@@ -0,0 +1,13 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+/// Emitted when the allowance of `spender` to spend `owner`'s tokens is set to expire at `expires_at`.
+#[ink::event]
+#[derive(Debug)]
+pub struct AllowanceExpirySet {
+    #[ink(topic)]
+    pub owner: AccountId,
+    #[ink(topic)]
+    pub spender: AccountId,
+    pub expires_at: Timestamp,
+}
@@ -0,0 +1,110 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22ExpiringAllowanceRef =
+    contract_ref!(PSP22ExpiringAllowance, DefaultEnvironment);
+
+/// trait extending PSP22 with allowances that lapse automatically.
+///
+/// An expired allowance reads as zero and can not be spent nor increased.
+/// `approve` sets an allowance without expiry, `increase_allowance` and `decrease_allowance` keep the expiry.
+#[ink::trait_definition]
+pub trait PSP22ExpiringAllowance {
+    /// Allows `spender` to withdraw from the caller's account multiple times, up to
+    /// the total amount of `value` until `expires_at`.
+    ///
+    /// On success `Approval` and `AllowanceExpirySet` events are emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AllowanceExpired` if `expires_at` is not in the future.
+    #[ink(message)]
+    fn approve_with_expiry(
+        &mut self,
+        spender: AccountId,
+        value: Balance,
+        expires_at: Timestamp,
+    ) -> Result<(), PSP22Error>;
+
+    /// Returns the timestamp at which the allowance of `spender` to spend `owner`'s tokens expires
+    /// or None if the allowance doesn't expire.
+    #[ink(message)]
+    fn allowance_expiry(
+        &self,
+        owner: AccountId,
+        spender: AccountId,
+    ) -> Option<Timestamp>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22ExpiringAllowanceInternal and PSP22ExpiringAllowance implementation can be derived.
+pub trait PSP22ExpiringAllowanceStorage {
+    /// Returns the expiry of the allowance of `spender` to spend `owner`'s tokens.
+    fn expiry(&self, owner: &AccountId, spender: &AccountId)
+        -> Option<Timestamp>;
+
+    /// Sets the expiry of the allowance of `spender` to spend `owner`'s tokens. None removes the expiry.
+    fn set_expiry(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        expires_at: Option<Timestamp>,
+    );
+}
+
+/// trait that is derived by Pendzl PSP22ExpiringAllowance implementation macro assuming StorageFieldGetter<PSP22ExpiringAllowanceStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22ExpiringAllowanceInternal {
+    /// Returns the expiry of the allowance of `spender` to spend `owner`'s tokens.
+    fn _allowance_expiry(
+        &self,
+        owner: &AccountId,
+        spender: &AccountId,
+    ) -> Option<Timestamp>;
+
+    /// Returns true if the allowance of `spender` to spend `owner`'s tokens is expired.
+    ///
+    /// Called by the `PSP22Internal::_allowance` derived by the `implementation` macro.
+    fn _is_allowance_expired(
+        &self,
+        owner: &AccountId,
+        spender: &AccountId,
+    ) -> bool;
+
+    /// Sets allowance of `spender` to spend `value` of `owner`'s tokens until `expires_at`.
+    ///
+    /// On success emits `Approval` and `AllowanceExpirySet` events.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AllowanceExpired` if `expires_at` is not in the future.
+    fn _approve_with_expiry(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        value: &Balance,
+        expires_at: &Timestamp,
+    ) -> Result<(), PSP22Error>;
+
+    /// Removes the expiry of the allowance of `spender` to spend `owner`'s tokens.
+    ///
+    /// Called by the `PSP22Internal::_approve` derived by the `implementation` macro.
+    fn _clear_allowance_expiry(&mut self, owner: &AccountId, spender: &AccountId);
+
+    /// Checks that the allowance of `spender` to spend `owner`'s tokens is not expired.
+    ///
+    /// Called by the `PSP22Internal::_spend_allowance` and `PSP22Internal::_increase_allowance_from_to`
+    /// derived by the `implementation` macro.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AllowanceExpired` if the allowance is expired.
+    fn _ensure_allowance_not_expired(
+        &self,
+        owner: &AccountId,
+        spender: &AccountId,
+    ) -> Result<(), PSP22Error>;
+}
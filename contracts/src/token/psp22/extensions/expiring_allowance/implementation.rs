@@ -0,0 +1,130 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{primitives::AccountId, storage::Mapping};
+use pendzl::traits::{Balance, DefaultEnv, StorageFieldGetter, Timestamp};
+
+use super::{
+    AllowanceExpirySet, PSP22ExpiringAllowanceInternal,
+    PSP22ExpiringAllowanceStorage,
+};
+use crate::token::psp22::{PSP22Error, PSP22Internal};
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22ExpiringAllowanceData {
+    pub expiries: Mapping<(AccountId, AccountId), Timestamp>,
+}
+
+impl PSP22ExpiringAllowanceStorage for PSP22ExpiringAllowanceData {
+    fn expiry(
+        &self,
+        owner: &AccountId,
+        spender: &AccountId,
+    ) -> Option<Timestamp> {
+        self.expiries.get((*owner, *spender))
+    }
+
+    fn set_expiry(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        expires_at: Option<Timestamp>,
+    ) {
+        match expires_at {
+            Some(expires_at) => {
+                self.expiries.insert((*owner, *spender), &expires_at);
+            }
+            None => self.expiries.remove((*owner, *spender)),
+        }
+    }
+}
+
+pub trait PSP22ExpiringAllowanceDefaultImpl:
+    DefaultEnv + PSP22ExpiringAllowanceInternal
+{
+    fn approve_with_expiry_default_impl(
+        &mut self,
+        spender: AccountId,
+        value: Balance,
+        expires_at: Timestamp,
+    ) -> Result<(), PSP22Error> {
+        let owner = Self::env().caller();
+        self._approve_with_expiry(&owner, &spender, &value, &expires_at)
+    }
+
+    fn allowance_expiry_default_impl(
+        &self,
+        owner: AccountId,
+        spender: AccountId,
+    ) -> Option<Timestamp> {
+        self._allowance_expiry(&owner, &spender)
+    }
+}
+
+pub trait PSP22ExpiringAllowanceInternalDefaultImpl:
+    StorageFieldGetter<PSP22ExpiringAllowanceData>
+    + PSP22Internal
+    + PSP22ExpiringAllowanceInternal
+where
+    PSP22ExpiringAllowanceData: PSP22ExpiringAllowanceStorage,
+{
+    fn _allowance_expiry_default_impl(
+        &self,
+        owner: &AccountId,
+        spender: &AccountId,
+    ) -> Option<Timestamp> {
+        self.data().expiry(owner, spender)
+    }
+
+    fn _is_allowance_expired_default_impl(
+        &self,
+        owner: &AccountId,
+        spender: &AccountId,
+    ) -> bool {
+        self._allowance_expiry(owner, spender)
+            .is_some_and(|expires_at| {
+                expires_at <= Self::env().block_timestamp()
+            })
+    }
+
+    fn _approve_with_expiry_default_impl(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        value: &Balance,
+        expires_at: &Timestamp,
+    ) -> Result<(), PSP22Error> {
+        if *expires_at <= Self::env().block_timestamp() {
+            return Err(PSP22Error::AllowanceExpired);
+        }
+        self._approve(owner, spender, value)?;
+        self.data().set_expiry(owner, spender, Some(*expires_at));
+        Self::env().emit_event(AllowanceExpirySet {
+            owner: *owner,
+            spender: *spender,
+            expires_at: *expires_at,
+        });
+        Ok(())
+    }
+
+    fn _clear_allowance_expiry_default_impl(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+    ) {
+        if self._allowance_expiry(owner, spender).is_some() {
+            self.data().set_expiry(owner, spender, None);
+        }
+    }
+
+    fn _ensure_allowance_not_expired_default_impl(
+        &self,
+        owner: &AccountId,
+        spender: &AccountId,
+    ) -> Result<(), PSP22Error> {
+        if self._is_allowance_expired(owner, spender) {
+            return Err(PSP22Error::AllowanceExpired);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+/// Emitted when the fee is changed.
+#[ink::event]
+#[derive(Debug)]
+pub struct FeeUpdated {
+    pub fee_bps: u16,
+}
+
+/// Emitted when the fee recipient is changed.
+#[ink::event]
+#[derive(Debug)]
+pub struct FeeRecipientUpdated {
+    #[ink(topic)]
+    pub recipient: Option<AccountId>,
+}
+
+/// Emitted when `account` is exempted from the fee or the exemption is removed.
+#[ink::event]
+#[derive(Debug)]
+pub struct FeeExemptionUpdated {
+    #[ink(topic)]
+    pub account: AccountId,
+    pub exempt: bool,
+}
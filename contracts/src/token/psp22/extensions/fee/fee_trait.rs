@@ -0,0 +1,139 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22FeeRef = contract_ref!(PSP22Fee, DefaultEnvironment);
+
+/// Denominator of the fee expressed in basis points (100% = 10_000 bps).
+pub const FEE_DENOMINATOR: u16 = 10_000;
+
+/// trait extending PSP22 with a fee charged on transfers.
+///
+/// A fee of `fee_bps` basis points of each transferred amount is transferred to the fee recipient
+/// and the recipient of the transfer receives the rest. Mints, burns, transfers from and to
+/// the fee recipient and transfers from or to exempt accounts are not charged.
+///
+/// The setters should be guarded - the implementation macro requires `PSP22Fee(guard = ...)`.
+#[ink::trait_definition]
+pub trait PSP22Fee {
+    /// Returns the fee in basis points.
+    #[ink(message)]
+    fn fee_bps(&self) -> u16;
+
+    /// Returns the account receiving the fees. No fee is charged if it is not set.
+    #[ink(message)]
+    fn fee_recipient(&self) -> Option<AccountId>;
+
+    /// Returns true if transfers from and to `account` are not charged.
+    #[ink(message)]
+    fn is_fee_exempt(&self, account: AccountId) -> bool;
+
+    /// Sets the fee in basis points.
+    ///
+    /// On success a `FeeUpdated` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidFee` if `fee_bps` is greater than `FEE_DENOMINATOR`.
+    #[ink(message)]
+    fn set_fee_bps(&mut self, fee_bps: u16) -> Result<(), PSP22Error>;
+
+    /// Sets the fee recipient.
+    ///
+    /// On success a `FeeRecipientUpdated` event is emitted.
+    #[ink(message)]
+    fn set_fee_recipient(
+        &mut self,
+        recipient: Option<AccountId>,
+    ) -> Result<(), PSP22Error>;
+
+    /// Exempts `account` from the fee or removes the exemption.
+    ///
+    /// On success a `FeeExemptionUpdated` event is emitted.
+    #[ink(message)]
+    fn set_fee_exempt(
+        &mut self,
+        account: AccountId,
+        exempt: bool,
+    ) -> Result<(), PSP22Error>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22FeeInternal and PSP22Fee implementation can be derived.
+pub trait PSP22FeeStorage {
+    /// Returns the fee in basis points.
+    fn fee_bps(&self) -> u16;
+
+    /// Sets the fee in basis points.
+    fn set_fee_bps(&mut self, fee_bps: &u16);
+
+    /// Returns the fee recipient.
+    fn fee_recipient(&self) -> Option<AccountId>;
+
+    /// Sets the fee recipient.
+    fn set_fee_recipient(&mut self, recipient: &Option<AccountId>);
+
+    /// Returns true if `account` is exempt from the fee.
+    fn is_fee_exempt(&self, account: &AccountId) -> bool;
+
+    /// Exempts `account` from the fee or removes the exemption.
+    fn set_fee_exempt(&mut self, account: &AccountId, exempt: bool);
+}
+
+/// trait that is derived by Pendzl PSP22Fee implementation macro assuming StorageFieldGetter<PSP22FeeStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22FeeInternal {
+    /// Returns the fee in basis points.
+    fn _fee_bps(&self) -> u16;
+
+    /// Returns the fee recipient.
+    fn _fee_recipient(&self) -> Option<AccountId>;
+
+    /// Returns true if `account` is exempt from the fee.
+    fn _is_fee_exempt(&self, account: &AccountId) -> bool;
+
+    /// Sets the fee in basis points.
+    ///
+    /// On success emits a `FeeUpdated` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidFee` if `fee_bps` is greater than `FEE_DENOMINATOR`.
+    fn _set_fee_bps(&mut self, fee_bps: &u16) -> Result<(), PSP22Error>;
+
+    /// Sets the fee recipient.
+    ///
+    /// On success emits a `FeeRecipientUpdated` event.
+    fn _set_fee_recipient(
+        &mut self,
+        recipient: &Option<AccountId>,
+    ) -> Result<(), PSP22Error>;
+
+    /// Exempts `account` from the fee or removes the exemption.
+    ///
+    /// On success emits a `FeeExemptionUpdated` event.
+    fn _set_fee_exempt(
+        &mut self,
+        account: &AccountId,
+        exempt: bool,
+    ) -> Result<(), PSP22Error>;
+
+    /// Rounding used to calculate the fee. Override to change it (default: `Rounding::Down`).
+    fn _fee_rounding(&self) -> Rounding;
+
+    /// Returns the fee charged on transfer of `amount`.
+    fn _fee_of(&self, amount: &Balance) -> Result<Balance, PSP22Error>;
+
+    /// Transfers the fee of the transfer of `amount` `from` `to` to the fee recipient
+    /// and returns the net amount that should be transferred to `to`.
+    ///
+    /// Called by the `PSP22Internal::_update` derived by the `implementation` macro on every update.
+    fn _charge_fee(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<Balance, PSP22Error>;
+}
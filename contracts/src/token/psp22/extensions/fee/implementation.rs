@@ -0,0 +1,199 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{primitives::AccountId, storage::Mapping};
+use pendzl::{
+    math::{
+        errors::MathError,
+        operations::{mul_div, Rounding},
+    },
+    traits::{Balance, StorageFieldGetter},
+};
+
+use super::{
+    FeeExemptionUpdated, FeeRecipientUpdated, FeeUpdated, PSP22FeeInternal,
+    PSP22FeeStorage, FEE_DENOMINATOR,
+};
+use crate::token::psp22::{PSP22Error, PSP22Internal};
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22FeeData {
+    #[lazy]
+    pub fee_bps: u16,
+    #[lazy]
+    pub fee_recipient: Option<AccountId>,
+    pub exempt: Mapping<AccountId, ()>,
+}
+
+impl PSP22FeeData {
+    pub fn new(fee_bps: u16, fee_recipient: Option<AccountId>) -> Self {
+        assert!(fee_bps <= FEE_DENOMINATOR, "fee exceeds 100%");
+        let mut instance: PSP22FeeData = Default::default();
+        instance.fee_bps.set(&fee_bps);
+        instance.fee_recipient.set(&fee_recipient);
+        instance
+    }
+}
+
+impl PSP22FeeStorage for PSP22FeeData {
+    fn fee_bps(&self) -> u16 {
+        self.fee_bps.get_or_default()
+    }
+
+    fn set_fee_bps(&mut self, fee_bps: &u16) {
+        self.fee_bps.set(fee_bps);
+    }
+
+    fn fee_recipient(&self) -> Option<AccountId> {
+        self.fee_recipient.get_or_default()
+    }
+
+    fn set_fee_recipient(&mut self, recipient: &Option<AccountId>) {
+        self.fee_recipient.set(recipient);
+    }
+
+    fn is_fee_exempt(&self, account: &AccountId) -> bool {
+        self.exempt.contains(account)
+    }
+
+    fn set_fee_exempt(&mut self, account: &AccountId, exempt: bool) {
+        if exempt {
+            self.exempt.insert(account, &());
+        } else {
+            self.exempt.remove(account);
+        }
+    }
+}
+
+pub trait PSP22FeeDefaultImpl: PSP22FeeInternal {
+    fn fee_bps_default_impl(&self) -> u16 {
+        self._fee_bps()
+    }
+
+    fn fee_recipient_default_impl(&self) -> Option<AccountId> {
+        self._fee_recipient()
+    }
+
+    fn is_fee_exempt_default_impl(&self, account: AccountId) -> bool {
+        self._is_fee_exempt(&account)
+    }
+
+    fn set_fee_bps_default_impl(
+        &mut self,
+        fee_bps: u16,
+    ) -> Result<(), PSP22Error> {
+        self._set_fee_bps(&fee_bps)
+    }
+
+    fn set_fee_recipient_default_impl(
+        &mut self,
+        recipient: Option<AccountId>,
+    ) -> Result<(), PSP22Error> {
+        self._set_fee_recipient(&recipient)
+    }
+
+    fn set_fee_exempt_default_impl(
+        &mut self,
+        account: AccountId,
+        exempt: bool,
+    ) -> Result<(), PSP22Error> {
+        self._set_fee_exempt(&account, exempt)
+    }
+}
+
+pub trait PSP22FeeInternalDefaultImpl:
+    StorageFieldGetter<PSP22FeeData> + PSP22Internal + PSP22FeeInternal
+where
+    PSP22FeeData: PSP22FeeStorage,
+{
+    fn _fee_bps_default_impl(&self) -> u16 {
+        self.data().fee_bps()
+    }
+
+    fn _fee_recipient_default_impl(&self) -> Option<AccountId> {
+        self.data().fee_recipient()
+    }
+
+    fn _is_fee_exempt_default_impl(&self, account: &AccountId) -> bool {
+        self.data().is_fee_exempt(account)
+    }
+
+    fn _set_fee_bps_default_impl(
+        &mut self,
+        fee_bps: &u16,
+    ) -> Result<(), PSP22Error> {
+        if *fee_bps > FEE_DENOMINATOR {
+            return Err(PSP22Error::InvalidFee);
+        }
+        self.data().set_fee_bps(fee_bps);
+        Self::env().emit_event(FeeUpdated { fee_bps: *fee_bps });
+        Ok(())
+    }
+
+    fn _set_fee_recipient_default_impl(
+        &mut self,
+        recipient: &Option<AccountId>,
+    ) -> Result<(), PSP22Error> {
+        self.data().set_fee_recipient(recipient);
+        Self::env().emit_event(FeeRecipientUpdated {
+            recipient: *recipient,
+        });
+        Ok(())
+    }
+
+    fn _set_fee_exempt_default_impl(
+        &mut self,
+        account: &AccountId,
+        exempt: bool,
+    ) -> Result<(), PSP22Error> {
+        self.data().set_fee_exempt(account, exempt);
+        Self::env().emit_event(FeeExemptionUpdated {
+            account: *account,
+            exempt,
+        });
+        Ok(())
+    }
+
+    fn _fee_rounding_default_impl(&self) -> Rounding {
+        Rounding::Down
+    }
+
+    fn _fee_of_default_impl(
+        &self,
+        amount: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        Ok(mul_div(
+            *amount,
+            self._fee_bps() as Balance,
+            FEE_DENOMINATOR as Balance,
+            self._fee_rounding(),
+        )?)
+    }
+
+    fn _charge_fee_default_impl(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        let (Some(from), Some(to), Some(recipient)) =
+            (from, to, self._fee_recipient())
+        else {
+            return Ok(*amount);
+        };
+        if *from == recipient
+            || *to == recipient
+            || self._is_fee_exempt(from)
+            || self._is_fee_exempt(to)
+        {
+            return Ok(*amount);
+        }
+        let fee = self._fee_of(amount)?;
+        if fee == 0 {
+            return Ok(*amount);
+        }
+        // the fee leg is not charged as it is transferred to the fee recipient
+        self._update(Some(from), Some(&recipient), &fee)?;
+        Ok(amount.checked_sub(fee).ok_or(MathError::Underflow)?)
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22FlashLenderRef =
+    contract_ref!(PSP22FlashLender, DefaultEnvironment);
+
+pub type PSP22FlashBorrowerRef =
+    contract_ref!(PSP22FlashBorrower, DefaultEnvironment);
+
+/// Selector of `PSP22FlashBorrower::on_flashloan`, used by the lender to call the borrower.
+pub const ON_FLASHLOAN_SELECTOR: [u8; 4] = [0x0e, 0xb2, 0x6a, 0x44];
+
+/// trait extending PSP22 with flash loans (ERC-3156 analogue) - tokens are minted to the borrower
+/// and must be returned (with a fee) within the same call.
+#[ink::trait_definition]
+pub trait PSP22FlashLender {
+    /// Returns the maximal amount of tokens that can be flash loaned.
+    ///
+    /// Returns 0 for the shares of a PSP22Vault - the flash minted shares would dilute
+    /// the shares deposited or minted during the flash loan.
+    #[ink(message)]
+    fn max_flash_loan(&self) -> Balance;
+
+    /// Returns the fee charged for a flash loan of `amount` tokens.
+    #[ink(message)]
+    fn flash_fee(&self, amount: Balance) -> Result<Balance, PSP22Error>;
+
+    /// Mints `amount` tokens to `receiver` and calls `PSP22FlashBorrower::on_flashloan` on it.
+    /// After the call `amount` + fee is burned from `receiver`, so before returning
+    /// `receiver` must approve this contract to spend `amount` + fee of its tokens.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MaxFlashLoanExceeded` if `amount` exceeds `max_flash_loan`.
+    /// Returns `FlashBorrowerCallFailed` if the call to `receiver` fails.
+    /// Returns the error returned by `receiver` if it rejects the loan.
+    /// Returns `InsufficientAllowance` if `receiver` did not approve the repayment.
+    #[ink(message)]
+    fn flash_loan(
+        &mut self,
+        receiver: AccountId,
+        amount: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error>;
+}
+
+/// trait that must be implemented by a contract receiving flash loans from `PSP22FlashLender`.
+#[ink::trait_definition]
+pub trait PSP22FlashBorrower {
+    /// Called by the lender token (the caller) after `amount` tokens were minted to this contract.
+    /// `initiator` is the account that requested the flash loan.
+    ///
+    /// The contract must approve the lender to spend `amount` + `fee` tokens before returning.
+    /// Returning an error rejects the flash loan.
+    #[ink(message, selector = 0x0eb26a44)]
+    fn on_flashloan(
+        &mut self,
+        initiator: AccountId,
+        amount: Balance,
+        fee: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error>;
+}
+
+/// trait that is derived by Pendzl PSP22FlashLender implementation macro
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22FlashLenderInternal {
+    /// Returns the maximal amount of tokens that can be flash loaned.
+    fn _max_flash_loan(&self) -> Balance;
+
+    /// Returns the fee charged for a flash loan of `amount` tokens. Zero by default.
+    fn _flash_fee(&self, amount: &Balance) -> Result<Balance, PSP22Error>;
+
+    /// Returns the account receiving the flash loan fees.
+    /// If `None` (the default) the fees are burned.
+    fn _flash_fee_receiver(&self) -> Option<AccountId>;
+
+    /// Performs the flash loan. See `PSP22FlashLender::flash_loan`.
+    fn _flash_loan(
+        &mut self,
+        receiver: &AccountId,
+        amount: &Balance,
+        data: &[u8],
+    ) -> Result<(), PSP22Error>;
+}
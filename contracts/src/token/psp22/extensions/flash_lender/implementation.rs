@@ -0,0 +1,101 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{
+    env::{
+        call::{build_call, ExecutionInput, Selector},
+        CallFlags, DefaultEnvironment,
+    },
+    prelude::vec::Vec,
+    primitives::AccountId,
+};
+use pendzl::{
+    math::errors::MathError,
+    traits::{Balance, DefaultEnv},
+};
+
+use super::{PSP22FlashLenderInternal, ON_FLASHLOAN_SELECTOR};
+use crate::token::psp22::{PSP22Error, PSP22Internal};
+
+pub trait PSP22FlashLenderDefaultImpl: PSP22FlashLenderInternal {
+    fn max_flash_loan_default_impl(&self) -> Balance {
+        self._max_flash_loan()
+    }
+
+    fn flash_fee_default_impl(
+        &self,
+        amount: Balance,
+    ) -> Result<Balance, PSP22Error> {
+        self._flash_fee(&amount)
+    }
+
+    fn flash_loan_default_impl(
+        &mut self,
+        receiver: AccountId,
+        amount: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error> {
+        self._flash_loan(&receiver, &amount, &data)
+    }
+}
+
+pub trait PSP22FlashLenderInternalDefaultImpl:
+    DefaultEnv + PSP22Internal + PSP22FlashLenderInternal
+{
+    fn _max_flash_loan_default_impl(&self) -> Balance {
+        Balance::MAX - self._total_supply()
+    }
+
+    fn _flash_fee_default_impl(
+        &self,
+        _amount: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        Ok(0)
+    }
+
+    fn _flash_fee_receiver_default_impl(&self) -> Option<AccountId> {
+        None
+    }
+
+    fn _flash_loan_default_impl(
+        &mut self,
+        receiver: &AccountId,
+        amount: &Balance,
+        data: &[u8],
+    ) -> Result<(), PSP22Error> {
+        if *amount > self._max_flash_loan() {
+            return Err(PSP22Error::MaxFlashLoanExceeded);
+        }
+        let fee = self._flash_fee(amount)?;
+        let repayment = amount.checked_add(fee).ok_or(MathError::Overflow)?;
+
+        self._mint_to(receiver, amount)?;
+
+        // reentry is allowed so the borrower can approve the repayment
+        let call = build_call::<DefaultEnvironment>()
+            .call(*receiver)
+            .call_flags(CallFlags::ALLOW_REENTRY)
+            .exec_input(
+                ExecutionInput::new(Selector::new(ON_FLASHLOAN_SELECTOR))
+                    .push_arg(Self::env().caller())
+                    .push_arg(amount)
+                    .push_arg(fee)
+                    .push_arg(data),
+            )
+            .returns::<Result<(), PSP22Error>>();
+
+        match call.try_invoke() {
+            Ok(Ok(result)) => result?,
+            _ => return Err(PSP22Error::FlashBorrowerCallFailed),
+        }
+
+        self._spend_allowance(receiver, &Self::env().account_id(), &repayment)?;
+
+        match self._flash_fee_receiver() {
+            Some(fee_receiver) if fee > 0 => {
+                self._burn_from(receiver, amount)?;
+                self._transfer(receiver, &fee_receiver, &fee)
+            }
+            _ => self._burn_from(receiver, &repayment),
+        }
+    }
+}
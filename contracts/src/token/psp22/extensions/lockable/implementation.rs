@@ -0,0 +1,141 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{prelude::vec::Vec, primitives::AccountId, storage::Mapping};
+use pendzl::{
+    math::errors::MathError,
+    traits::{Balance, StorageFieldGetter, Timestamp},
+};
+
+use super::{
+    Lock, Locked, PSP22LockableInternal, PSP22LockableStorage, MAX_LOCKS,
+};
+use crate::token::psp22::{PSP22Error, PSP22Internal};
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22LockableData {
+    pub locks: Mapping<AccountId, Vec<Lock>>,
+}
+
+impl PSP22LockableStorage for PSP22LockableData {
+    fn locks(&self, account: &AccountId) -> Vec<Lock> {
+        self.locks.get(account).unwrap_or_default()
+    }
+
+    fn set_locks(&mut self, account: &AccountId, locks: &[Lock]) {
+        if locks.is_empty() {
+            self.locks.remove(account);
+        } else {
+            self.locks.insert(account, &locks.to_vec());
+        }
+    }
+}
+
+pub trait PSP22LockableDefaultImpl: PSP22LockableInternal {
+    fn locked_balance_of_default_impl(&self, account: AccountId) -> Balance {
+        self._locked_balance_of(&account)
+    }
+
+    fn locks_of_default_impl(&self, account: AccountId) -> Vec<Lock> {
+        self._locks_of(&account)
+    }
+
+    fn lock_default_impl(
+        &mut self,
+        account: AccountId,
+        amount: Balance,
+        until: Timestamp,
+    ) -> Result<(), PSP22Error> {
+        self._lock(&account, &amount, &until)
+    }
+}
+
+pub trait PSP22LockableInternalDefaultImpl:
+    StorageFieldGetter<PSP22LockableData> + PSP22Internal + PSP22LockableInternal
+where
+    PSP22LockableData: PSP22LockableStorage,
+{
+    fn _locked_balance_of_default_impl(&self, account: &AccountId) -> Balance {
+        self._locks_of(account)
+            .iter()
+            .fold(0, |locked, lock| locked.saturating_add(lock.amount))
+    }
+
+    fn _locks_of_default_impl(&self, account: &AccountId) -> Vec<Lock> {
+        let now = Self::env().block_timestamp();
+        self.data()
+            .locks(account)
+            .into_iter()
+            .filter(|lock| lock.until > now)
+            .collect()
+    }
+
+    fn _lock_default_impl(
+        &mut self,
+        account: &AccountId,
+        amount: &Balance,
+        until: &Timestamp,
+    ) -> Result<(), PSP22Error> {
+        if *amount == 0 || *until <= Self::env().block_timestamp() {
+            return Err(PSP22Error::InvalidLock);
+        }
+        let locked = self
+            ._locked_balance_of(account)
+            .checked_add(*amount)
+            .ok_or(MathError::Overflow)?;
+        if locked > self._balance_of(account) {
+            return Err(PSP22Error::InsufficientUnlockedBalance);
+        }
+        let mut locks = self._locks_of(account);
+        match locks.iter_mut().find(|lock| lock.until == *until) {
+            Some(lock) => lock.amount += *amount,
+            None => {
+                if locks.len() >= MAX_LOCKS as usize {
+                    return Err(PSP22Error::TooManyLocks);
+                }
+                locks.push(Lock {
+                    amount: *amount,
+                    until: *until,
+                });
+            }
+        }
+        self.data().set_locks(account, &locks);
+        Self::env().emit_event(Locked {
+            account: *account,
+            amount: *amount,
+            until: *until,
+        });
+        Ok(())
+    }
+
+    fn _ensure_unlocked_default_impl(
+        &mut self,
+        from: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        let Some(from) = from else {
+            return Ok(());
+        };
+        let stored_locks = self.data().locks(from).len();
+        if stored_locks == 0 {
+            return Ok(());
+        }
+        let locks = self._locks_of(from);
+        if locks.len() < stored_locks {
+            self.data().set_locks(from, &locks);
+        }
+        let locked = locks.iter().fold(0, |locked: Balance, lock| {
+            locked.saturating_add(lock.amount)
+        });
+        if locked == 0 {
+            return Ok(());
+        }
+        // if the balance is lower than amount `_update` fails with `InsufficientBalance`
+        if let Some(left) = self._balance_of(from).checked_sub(*amount) {
+            if left < locked {
+                return Err(PSP22Error::InsufficientUnlockedBalance);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,12 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+/// Emitted when `amount` of `account`'s tokens is locked until `until`.
+#[ink::event]
+#[derive(Debug)]
+pub struct Locked {
+    #[ink(topic)]
+    pub account: AccountId,
+    pub amount: Balance,
+    pub until: Timestamp,
+}
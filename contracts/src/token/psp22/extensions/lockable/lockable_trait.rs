@@ -0,0 +1,100 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use ink::{contract_ref, env::DefaultEnvironment};
+
+/// Maximum number of not expired locks of an account - bounds the locks iterated on every transfer.
+pub const MAX_LOCKS: u32 = 16;
+
+pub type PSP22LockableRef = contract_ref!(PSP22Lockable, DefaultEnvironment);
+
+/// trait extending PSP22 with time locks on account balances.
+///
+/// Locked tokens stay in the holder's account (so they still count e.g. as votes)
+/// but can not be transferred nor burned until the lock expires.
+///
+/// `lock` should be guarded - the implementation macro requires `PSP22Lockable(guard = ...)`.
+#[ink::trait_definition]
+pub trait PSP22Lockable {
+    /// Returns the amount of `account`'s tokens that are currently locked.
+    #[ink(message)]
+    fn locked_balance_of(&self, account: AccountId) -> Balance;
+
+    /// Returns the locks of `account` that are not expired.
+    #[ink(message)]
+    fn locks_of(&self, account: AccountId) -> Vec<Lock>;
+
+    /// Locks `amount` of `account`'s tokens until `until`.
+    ///
+    /// The lock is added to the existing locks of `account` - merged with the lock of the same `until` if there is one.
+    ///
+    /// On success a `Locked` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidLock` if `amount` is zero or `until` is not in the future.
+    ///
+    /// Returns `InsufficientUnlockedBalance` if the locked balance of `account` would exceed its balance.
+    ///
+    /// Returns `TooManyLocks` if `account` already has `MAX_LOCKS` locks and none of them ends at `until`.
+    #[ink(message)]
+    fn lock(
+        &mut self,
+        account: AccountId,
+        amount: Balance,
+        until: Timestamp,
+    ) -> Result<(), PSP22Error>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22LockableInternal and PSP22Lockable implementation can be derived.
+pub trait PSP22LockableStorage {
+    /// Returns all locks of `account`, including the expired ones.
+    fn locks(&self, account: &AccountId) -> Vec<Lock>;
+
+    /// Sets the locks of `account`.
+    fn set_locks(&mut self, account: &AccountId, locks: &[Lock]);
+}
+
+/// trait that is derived by Pendzl PSP22Lockable implementation macro assuming StorageFieldGetter<PSP22LockableStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22LockableInternal {
+    /// Returns the amount of `account`'s tokens that are currently locked.
+    fn _locked_balance_of(&self, account: &AccountId) -> Balance;
+
+    /// Returns the locks of `account` that are not expired.
+    fn _locks_of(&self, account: &AccountId) -> Vec<Lock>;
+
+    /// Locks `amount` of `account`'s tokens until `until`. Expired locks of `account` are removed.
+    ///
+    /// On success emits a `Locked` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidLock` if `amount` is zero or `until` is not in the future.
+    ///
+    /// Returns `InsufficientUnlockedBalance` if the locked balance of `account` would exceed its balance.
+    ///
+    /// Returns `TooManyLocks` if `account` already has `MAX_LOCKS` locks and none of them ends at `until`.
+    fn _lock(
+        &mut self,
+        account: &AccountId,
+        amount: &Balance,
+        until: &Timestamp,
+    ) -> Result<(), PSP22Error>;
+
+    /// Checks that `amount` can be moved from `from` without touching its locked tokens.
+    /// Expired locks of `from` are removed.
+    ///
+    /// Called by the `PSP22Internal::_update` derived by the `implementation` macro.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientUnlockedBalance` if the balance left would be lower than the locked balance.
+    fn _ensure_unlocked(
+        &mut self,
+        from: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+}
@@ -0,0 +1,10 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+/// Emitted when the token name or symbol is changed. Contains the current `name` and `symbol`.
+#[ink::event]
+#[derive(Debug)]
+pub struct MetadataUpdated {
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+}
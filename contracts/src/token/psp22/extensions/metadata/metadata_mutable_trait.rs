@@ -0,0 +1,56 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+pub type PSP22MetadataMutableRef =
+    contract_ref!(PSP22MetadataMutable, DefaultEnvironment);
+
+/// trait extending PSP22Metadata with setters of the token name and symbol.
+///
+/// The setters should be guarded - the implementation macro requires `PSP22MetadataMutable(guard = ...)`.
+#[ink::trait_definition]
+pub trait PSP22MetadataMutable {
+    /// Sets the token name.
+    ///
+    /// On success a `MetadataUpdated` event is emitted.
+    #[ink(message)]
+    fn set_token_name(&mut self, name: Option<String>)
+        -> Result<(), PSP22Error>;
+
+    /// Sets the token symbol.
+    ///
+    /// On success a `MetadataUpdated` event is emitted.
+    #[ink(message)]
+    fn set_token_symbol(
+        &mut self,
+        symbol: Option<String>,
+    ) -> Result<(), PSP22Error>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22MetadataMutableInternal and PSP22MetadataMutable implementation can be derived.
+pub trait PSP22MetadataMutableStorage {
+    fn set_token_name(&mut self, name: &Option<String>);
+
+    fn set_token_symbol(&mut self, symbol: &Option<String>);
+}
+
+/// trait that is derived by Pendzl PSP22MetadataMutable implementation macro assuming StorageFieldGetter<PSP22MetadataMutableStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22MetadataMutableInternal {
+    /// Sets the token name.
+    ///
+    /// On success emits a `MetadataUpdated` event.
+    fn _set_token_name(
+        &mut self,
+        name: &Option<String>,
+    ) -> Result<(), PSP22Error>;
+
+    /// Sets the token symbol.
+    ///
+    /// On success emits a `MetadataUpdated` event.
+    fn _set_token_symbol(
+        &mut self,
+        symbol: &Option<String>,
+    ) -> Result<(), PSP22Error>;
+}
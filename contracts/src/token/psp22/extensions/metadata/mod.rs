@@ -16,3 +16,17 @@ mod vault_implementation;
 
 #[cfg(all(feature = "psp22_metadata_impl", feature = "psp22_vault_impl"))]
 pub use vault_implementation::*;
+
+#[cfg(feature = "psp22_metadata_mutable")]
+pub use crate::token::psp22::PSP22Error;
+
+#[cfg(feature = "psp22_metadata_mutable")]
+include!("metadata_events.rs");
+#[cfg(feature = "psp22_metadata_mutable")]
+include!("metadata_mutable_trait.rs");
+
+#[cfg(feature = "psp22_metadata_mutable_impl")]
+mod mutable_implementation;
+
+#[cfg(feature = "psp22_metadata_mutable_impl")]
+pub use mutable_implementation::*;
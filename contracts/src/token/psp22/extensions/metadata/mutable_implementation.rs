@@ -0,0 +1,70 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use ink::prelude::string::String;
+
+use pendzl::traits::StorageFieldGetter;
+
+use super::{
+    MetadataUpdated, PSP22Error, PSP22MetadataData,
+    PSP22MetadataMutableInternal, PSP22MetadataMutableStorage,
+};
+
+// works with both the plain and the vault PSP22MetadataData
+impl PSP22MetadataMutableStorage for PSP22MetadataData {
+    fn set_token_name(&mut self, name: &Option<String>) {
+        self.name.set(name);
+    }
+
+    fn set_token_symbol(&mut self, symbol: &Option<String>) {
+        self.symbol.set(symbol);
+    }
+}
+
+pub trait PSP22MetadataMutableDefaultImpl:
+    PSP22MetadataMutableInternal
+{
+    fn set_token_name_default_impl(
+        &mut self,
+        name: Option<String>,
+    ) -> Result<(), PSP22Error> {
+        self._set_token_name(&name)
+    }
+
+    fn set_token_symbol_default_impl(
+        &mut self,
+        symbol: Option<String>,
+    ) -> Result<(), PSP22Error> {
+        self._set_token_symbol(&symbol)
+    }
+}
+
+pub trait PSP22MetadataMutableInternalDefaultImpl:
+    StorageFieldGetter<PSP22MetadataData> + PSP22MetadataMutableInternal
+where
+    PSP22MetadataData: PSP22MetadataMutableStorage,
+{
+    fn _set_token_name_default_impl(
+        &mut self,
+        name: &Option<String>,
+    ) -> Result<(), PSP22Error> {
+        self.data().set_token_name(name);
+        Self::env().emit_event(MetadataUpdated {
+            name: name.clone(),
+            symbol: self.data().symbol.get_or_default(),
+        });
+        Ok(())
+    }
+
+    fn _set_token_symbol_default_impl(
+        &mut self,
+        symbol: &Option<String>,
+    ) -> Result<(), PSP22Error> {
+        self.data().set_token_symbol(symbol);
+        Self::env().emit_event(MetadataUpdated {
+            name: self.data().name.get_or_default(),
+            symbol: symbol.clone(),
+        });
+        Ok(())
+    }
+}
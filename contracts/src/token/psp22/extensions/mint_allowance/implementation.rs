@@ -0,0 +1,79 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{primitives::AccountId, storage::Mapping};
+use pendzl::traits::{Balance, StorageFieldGetter};
+
+use super::{
+    MinterAllowanceSet, PSP22MintAllowanceInternal, PSP22MintAllowanceStorage,
+};
+use crate::token::psp22::PSP22Error;
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22MintAllowanceData {
+    pub allowances: Mapping<AccountId, Balance>,
+}
+
+impl PSP22MintAllowanceStorage for PSP22MintAllowanceData {
+    fn minter_allowance(&self, minter: &AccountId) -> Balance {
+        self.allowances.get(minter).unwrap_or_default()
+    }
+
+    fn set_minter_allowance(&mut self, minter: &AccountId, amount: &Balance) {
+        if *amount == 0 {
+            self.allowances.remove(minter);
+        } else {
+            self.allowances.insert(minter, amount);
+        }
+    }
+}
+
+pub trait PSP22MintAllowanceDefaultImpl: PSP22MintAllowanceInternal {
+    fn minter_allowance_default_impl(&self, minter: AccountId) -> Balance {
+        self._minter_allowance(&minter)
+    }
+
+    fn set_minter_allowance_default_impl(
+        &mut self,
+        minter: AccountId,
+        amount: Balance,
+    ) -> Result<(), PSP22Error> {
+        self._set_minter_allowance(&minter, &amount)
+    }
+}
+
+pub trait PSP22MintAllowanceInternalDefaultImpl:
+    StorageFieldGetter<PSP22MintAllowanceData> + PSP22MintAllowanceInternal
+where
+    PSP22MintAllowanceData: PSP22MintAllowanceStorage,
+{
+    fn _minter_allowance_default_impl(&self, minter: &AccountId) -> Balance {
+        self.data().minter_allowance(minter)
+    }
+
+    fn _set_minter_allowance_default_impl(
+        &mut self,
+        minter: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        self.data().set_minter_allowance(minter, amount);
+        Self::env().emit_event(MinterAllowanceSet {
+            minter: *minter,
+            allowance: *amount,
+        });
+        Ok(())
+    }
+
+    fn _spend_minter_allowance_default_impl(
+        &mut self,
+        minter: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        let new_allowance = self
+            ._minter_allowance(minter)
+            .checked_sub(*amount)
+            .ok_or(PSP22Error::MintAllowanceExceeded)?;
+        self.data().set_minter_allowance(minter, &new_allowance);
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+/// Emitted when the mint allowance of `minter` is set to `allowance`.
+#[ink::event]
+#[derive(Debug)]
+pub struct MinterAllowanceSet {
+    #[ink(topic)]
+    pub minter: AccountId,
+    pub allowance: Balance,
+}
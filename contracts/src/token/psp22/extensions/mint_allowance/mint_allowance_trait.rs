@@ -0,0 +1,69 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22MintAllowanceRef =
+    contract_ref!(PSP22MintAllowance, DefaultEnvironment);
+
+/// trait extending PSP22Mintable with a separate mint quota for every minter.
+///
+/// `PSP22Mintable::mint` decreases the caller's mint allowance by the minted amount.
+///
+/// `set_minter_allowance` should be guarded - the implementation macro requires `PSP22MintAllowance(guard = ...)`.
+#[ink::trait_definition]
+pub trait PSP22MintAllowance {
+    /// Returns the amount of tokens `minter` can still mint.
+    #[ink(message)]
+    fn minter_allowance(&self, minter: AccountId) -> Balance;
+
+    /// Sets the amount of tokens `minter` can mint.
+    ///
+    /// On success a `MinterAllowanceSet` event is emitted.
+    #[ink(message)]
+    fn set_minter_allowance(
+        &mut self,
+        minter: AccountId,
+        amount: Balance,
+    ) -> Result<(), PSP22Error>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22MintAllowanceInternal and PSP22MintAllowance implementation can be derived.
+pub trait PSP22MintAllowanceStorage {
+    /// Returns the mint allowance of `minter`.
+    fn minter_allowance(&self, minter: &AccountId) -> Balance;
+
+    /// Sets the mint allowance of `minter`.
+    fn set_minter_allowance(&mut self, minter: &AccountId, amount: &Balance);
+}
+
+/// trait that is derived by Pendzl PSP22MintAllowance implementation macro assuming StorageFieldGetter<PSP22MintAllowanceStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22MintAllowanceInternal {
+    /// Returns the amount of tokens `minter` can still mint.
+    fn _minter_allowance(&self, minter: &AccountId) -> Balance;
+
+    /// Sets the amount of tokens `minter` can mint.
+    ///
+    /// On success emits a `MinterAllowanceSet` event.
+    fn _set_minter_allowance(
+        &mut self,
+        minter: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Decreases the mint allowance of `minter` by `amount`.
+    ///
+    /// Called by the `PSP22Mintable::mint` derived by the `implementation` macro.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MintAllowanceExceeded` if the mint allowance of `minter` is lower than `amount`.
+    fn _spend_minter_allowance(
+        &mut self,
+        minter: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+}
@@ -0,0 +1,53 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::primitives::AccountId;
+use pendzl::traits::{Balance, DefaultEnv};
+
+use super::PSP22NativeWrapperInternal;
+use crate::{
+    finance::native_asset::{
+        handle_native_transfer_in, handle_native_transfer_out,
+    },
+    token::psp22::{PSP22Error, PSP22Internal},
+};
+
+pub trait PSP22NativeWrapperDefaultImpl:
+    PSP22NativeWrapperInternal + DefaultEnv
+{
+    fn deposit_default_impl(&mut self) -> Result<(), PSP22Error> {
+        self._deposit_native(
+            &Self::env().caller(),
+            &Self::env().transferred_value(),
+        )
+    }
+
+    fn withdraw_default_impl(
+        &mut self,
+        amount: Balance,
+    ) -> Result<(), PSP22Error> {
+        self._withdraw_native(&Self::env().caller(), &amount)
+    }
+}
+
+pub trait PSP22NativeWrapperInternalDefaultImpl:
+    PSP22Internal + PSP22NativeWrapperInternal
+{
+    fn _deposit_native_default_impl(
+        &mut self,
+        account: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        handle_native_transfer_in(*amount)?;
+        self._mint_to(account, amount)
+    }
+
+    fn _withdraw_native_default_impl(
+        &mut self,
+        account: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        self._burn_from(account, amount)?;
+        handle_native_transfer_out(*account, *amount)?;
+        Ok(())
+    }
+}
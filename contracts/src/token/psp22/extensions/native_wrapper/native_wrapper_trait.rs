@@ -0,0 +1,56 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22NativeWrapperRef =
+    contract_ref!(PSP22NativeWrapper, DefaultEnvironment);
+
+/// trait extending PSP22 with wrapping of the native currency one-to-one.
+///
+/// Useful to let contracts working with PSP22 tokens (ex. PSP22Vault) accept the native currency.
+#[ink::trait_definition]
+pub trait PSP22NativeWrapper {
+    /// Mints wrapped tokens to the caller equal to the native currency transferred with the call.
+    ///
+    /// On success a `Transfer` event is emitted (minting).
+    #[ink(message, payable)]
+    fn deposit(&mut self) -> Result<(), PSP22Error>;
+
+    /// Burns `amount` of wrapped tokens from the caller and transfers the same `amount`
+    /// of the native currency to the caller.
+    ///
+    /// On success a `Transfer` event is emitted (burning).
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientBalance` error if the caller does not have enough wrapped tokens.
+    ///
+    /// Returns `NativeTransferFailed` error if the transfer of the native currency fails.
+    #[ink(message)]
+    fn withdraw(&mut self, amount: Balance) -> Result<(), PSP22Error>;
+}
+
+/// trait that is derived by Pendzl PSP22NativeWrapper implementation macro assuming PSP22Internal is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22NativeWrapperInternal {
+    /// Mints `amount` of wrapped tokens to `account`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidAmountPaid` error if the native currency transferred with the call differs from `amount`.
+    fn _deposit_native(
+        &mut self,
+        account: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Burns `amount` of wrapped tokens from `account` and transfers the same `amount`
+    /// of the native currency to `account`.
+    fn _withdraw_native(
+        &mut self,
+        account: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+}
@@ -0,0 +1,104 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{env::hash::Blake2x256, primitives::AccountId, storage::Mapping};
+use pendzl::{
+    math::errors::MathError,
+    traits::{Balance, StorageFieldGetter, Timestamp},
+};
+
+use super::{
+    PSP22PermitInternal, PSP22PermitStorage, PermitMessage, PermitSignature,
+    PERMIT_DOMAIN_NAME, PERMIT_DOMAIN_VERSION,
+};
+use crate::token::psp22::{PSP22Error, PSP22Internal};
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22PermitData {
+    pub nonces: Mapping<AccountId, u64>,
+}
+
+impl PSP22PermitStorage for PSP22PermitData {
+    fn nonce(&self, owner: &AccountId) -> u64 {
+        self.nonces.get(owner).unwrap_or_default()
+    }
+
+    fn use_nonce(&mut self, owner: &AccountId) -> Result<u64, PSP22Error> {
+        let nonce = self.nonce(owner);
+        self.nonces
+            .insert(owner, &nonce.checked_add(1).ok_or(MathError::Overflow)?);
+        Ok(nonce)
+    }
+}
+
+pub trait PSP22PermitDefaultImpl: PSP22PermitInternal {
+    fn permit_default_impl(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        value: Balance,
+        deadline: Timestamp,
+        signature: PermitSignature,
+    ) -> Result<(), PSP22Error> {
+        self._permit(&owner, &spender, &value, &deadline, &signature)
+    }
+
+    fn nonces_default_impl(&self, owner: AccountId) -> u64 {
+        self._nonces(&owner)
+    }
+
+    fn domain_separator_default_impl(&self) -> [u8; 32] {
+        self._domain_separator()
+    }
+}
+
+pub trait PSP22PermitInternalDefaultImpl:
+    StorageFieldGetter<PSP22PermitData> + PSP22Internal + PSP22PermitInternal
+where
+    PSP22PermitData: PSP22PermitStorage,
+{
+    fn _nonces_default_impl(&self, owner: &AccountId) -> u64 {
+        self.data().nonce(owner)
+    }
+
+    fn _domain_separator_default_impl(&self) -> [u8; 32] {
+        let mut domain_separator = [0_u8; 32];
+        ink::env::hash_encoded::<Blake2x256, _>(
+            &(
+                PERMIT_DOMAIN_NAME,
+                PERMIT_DOMAIN_VERSION,
+                Self::env().account_id(),
+            ),
+            &mut domain_separator,
+        );
+        domain_separator
+    }
+
+    fn _permit_default_impl(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        value: &Balance,
+        deadline: &Timestamp,
+        signature: &PermitSignature,
+    ) -> Result<(), PSP22Error> {
+        if Self::env().block_timestamp() > *deadline {
+            return Err(PSP22Error::PermitExpired);
+        }
+
+        let message = PermitMessage {
+            domain_separator: self._domain_separator(),
+            owner: *owner,
+            spender: *spender,
+            value: *value,
+            deadline: *deadline,
+            nonce: self.data().nonce(owner),
+        };
+        if !signature.verify(&scale::Encode::encode(&message), owner) {
+            return Err(PSP22Error::PermitInvalidSignature);
+        }
+
+        self.data().use_nonce(owner)?;
+        self._approve(owner, spender, value)
+    }
+}
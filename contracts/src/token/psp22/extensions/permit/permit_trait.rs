@@ -0,0 +1,76 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22PermitRef = contract_ref!(PSP22Permit, DefaultEnvironment);
+
+/// trait extending PSP22 with permit functionality - allowances can be set
+/// by anyone presenting a signature of the owner.
+#[ink::trait_definition]
+pub trait PSP22Permit {
+    /// Sets `value` as the allowance of `spender` over `owner`'s tokens,
+    /// given `owner`'s signature of the corresponding `PermitMessage`.
+    ///
+    /// On success an `Approval` event is emitted and the nonce of `owner` is increased.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PermitExpired` if `deadline` is in the past.
+    /// Returns `PermitInvalidSignature` if `signature` is not a valid signature of `owner`.
+    #[ink(message)]
+    fn permit(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        value: Balance,
+        deadline: Timestamp,
+        signature: PermitSignature,
+    ) -> Result<(), PSP22Error>;
+
+    /// Returns the current nonce of `owner`. It must be included in the next `PermitMessage`.
+    #[ink(message)]
+    fn nonces(&self, owner: AccountId) -> u64;
+
+    /// Returns the domain separator that must be included in every `PermitMessage`.
+    #[ink(message)]
+    fn domain_separator(&self) -> [u8; 32];
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22PermitInternal and PSP22Permit implementation can be derived.
+pub trait PSP22PermitStorage {
+    /// Returns the current nonce of `owner`.
+    fn nonce(&self, owner: &AccountId) -> u64;
+
+    /// Increases the nonce of `owner` by one and returns the used nonce.
+    fn use_nonce(&mut self, owner: &AccountId) -> Result<u64, PSP22Error>;
+}
+
+/// trait that is derived by Pendzl PSP22Permit implementation macro assuming StorageFieldGetter<PSP22PermitStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22PermitInternal {
+    /// Returns the current nonce of `owner`.
+    fn _nonces(&self, owner: &AccountId) -> u64;
+
+    /// Returns the domain separator of the contract.
+    fn _domain_separator(&self) -> [u8; 32];
+
+    /// Verifies the `signature` of `owner` and sets `value` as the allowance of `spender`.
+    ///
+    /// On success emits `Approval` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PermitExpired` if `deadline` is in the past.
+    /// Returns `PermitInvalidSignature` if `signature` is not a valid signature of `owner`.
+    fn _permit(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        value: &Balance,
+        deadline: &Timestamp,
+        signature: &PermitSignature,
+    ) -> Result<(), PSP22Error>;
+}
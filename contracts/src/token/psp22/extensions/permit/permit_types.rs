@@ -0,0 +1,68 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use ink::env::hash::Blake2x256;
+
+/// Name of the domain used to compute the `PSP22Permit` domain separator.
+pub const PERMIT_DOMAIN_NAME: &[u8] = b"PSP22Permit";
+/// Version of the domain used to compute the `PSP22Permit` domain separator.
+pub const PERMIT_DOMAIN_VERSION: &[u8] = b"1";
+
+/// Signature of the token owner over the SCALE-encoded `PermitMessage`.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PermitSignature {
+    /// sr25519 signature of the encoded message.
+    Sr25519([u8; 64]),
+    /// ecdsa signature of the blake2x256 hash of the encoded message.
+    /// The signer's account is the blake2x256 hash of the compressed public key.
+    Ecdsa([u8; 65]),
+}
+
+impl PermitSignature {
+    /// Returns true if `message` was signed by `signer`.
+    pub fn verify(&self, message: &[u8], signer: &AccountId) -> bool {
+        match self {
+            PermitSignature::Sr25519(signature) => {
+                ink::env::sr25519_verify(signature, message, signer.as_ref())
+                    .is_ok()
+            }
+            PermitSignature::Ecdsa(signature) => {
+                let mut message_hash = [0_u8; 32];
+                ink::env::hash_bytes::<Blake2x256>(message, &mut message_hash);
+                let mut public_key = [0_u8; 33];
+                if ink::env::ecdsa_recover(
+                    signature,
+                    &message_hash,
+                    &mut public_key,
+                )
+                .is_err()
+                {
+                    return false;
+                }
+                let mut recovered = [0_u8; 32];
+                ink::env::hash_bytes::<Blake2x256>(&public_key, &mut recovered);
+                AccountId::from(recovered) == *signer
+            }
+        }
+    }
+}
+
+/// The message that is signed by the owner to permit an allowance.
+/// The signed bytes are the SCALE encoding of this struct.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct PermitMessage {
+    /// Domain separator of the token contract, see `PSP22Permit::domain_separator`.
+    pub domain_separator: [u8; 32],
+    /// The account of the token owner.
+    pub owner: AccountId,
+    /// The account of the authorized spender.
+    pub spender: AccountId,
+    /// The allowance to set.
+    pub value: Balance,
+    /// The timestamp after which the permit can not be used.
+    pub deadline: Timestamp,
+    /// The current nonce of the owner, see `PSP22Permit::nonces`.
+    pub nonce: u64,
+}
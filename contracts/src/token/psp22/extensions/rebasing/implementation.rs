@@ -0,0 +1,232 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{primitives::AccountId, storage::Mapping};
+use pendzl::{
+    math::{
+        errors::MathError,
+        operations::{mul_div, Rounding},
+    },
+    traits::{Balance, StorageFieldGetter},
+};
+
+use super::{PSP22RebasingInternal, PSP22RebasingStorage, Rebased};
+use crate::token::psp22::{PSP22Error, PSP22Storage};
+
+/// Shares based implementation of `PSP22Storage`.
+///
+/// Contracts implementing `PSP22Rebasing` use it as the PSP22 storage field in place of `PSP22Data`.
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22RebasingData {
+    #[lazy]
+    pub total_supply: Balance,
+    #[lazy]
+    pub total_shares: Balance,
+    pub shares: Mapping<AccountId, Balance>,
+    pub allowances: Mapping<(AccountId, AccountId), Balance>,
+}
+
+impl PSP22RebasingData {
+    /// Converts `amount` of tokens to shares using the current ratio.
+    /// Tokens are converted one to one if there is no supply.
+    pub fn to_shares(
+        &self,
+        amount: &Balance,
+        rounding: Rounding,
+    ) -> Result<Balance, MathError> {
+        let total_supply = self.total_supply.get_or_default();
+        let total_shares = self.total_shares.get_or_default();
+        if total_supply == 0 || total_shares == 0 {
+            return Ok(*amount);
+        }
+        mul_div(*amount, total_shares, total_supply, rounding)
+    }
+
+    /// Converts `shares` to tokens using the current ratio.
+    pub fn to_balance(
+        &self,
+        shares: &Balance,
+        rounding: Rounding,
+    ) -> Result<Balance, MathError> {
+        let total_shares = self.total_shares.get_or_default();
+        if total_shares == 0 {
+            return Ok(0);
+        }
+        mul_div(
+            *shares,
+            self.total_supply.get_or_default(),
+            total_shares,
+            rounding,
+        )
+    }
+}
+
+impl PSP22Storage for PSP22RebasingData {
+    fn total_supply(&self) -> Balance {
+        self.total_supply.get_or_default()
+    }
+    fn increase_total_supply(
+        &mut self,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        let new_total_supply = self
+            .total_supply
+            .get_or_default()
+            .checked_add(*amount)
+            .ok_or(MathError::Overflow)?;
+        self.total_supply.set(&new_total_supply);
+        Ok(())
+    }
+    fn decrease_total_supply(
+        &mut self,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        let new_total_supply = self
+            .total_supply()
+            .checked_sub(*amount)
+            .ok_or(MathError::Underflow)?;
+        // the supply left (rounding) after the last shares are burned backs no shares,
+        // it would be credited to the next minter
+        if self.total_shares() == 0 {
+            self.total_supply.set(&0);
+        } else {
+            self.total_supply.set(&new_total_supply);
+        }
+        Ok(())
+    }
+
+    fn balance_of(&self, account: &AccountId) -> Balance {
+        self.to_balance(&self.shares_of(account), Rounding::Down)
+            .unwrap_or_default()
+    }
+    fn increase_balance_of(
+        &mut self,
+        account: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        let shares = self.to_shares(amount, Rounding::Down)?;
+        let new_shares = self
+            .shares_of(account)
+            .checked_add(shares)
+            .ok_or(MathError::Overflow)?;
+        let new_total_shares = self
+            .total_shares()
+            .checked_add(shares)
+            .ok_or(MathError::Overflow)?;
+        self.shares.insert(account, &new_shares);
+        self.total_shares.set(&new_total_shares);
+        Ok(())
+    }
+    fn decrease_balance_of(
+        &mut self,
+        account: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        let shares = self.to_shares(amount, Rounding::Up)?;
+        let new_shares = self
+            .shares_of(account)
+            .checked_sub(shares)
+            .ok_or(PSP22Error::InsufficientBalance)?;
+        let new_total_shares = self
+            .total_shares()
+            .checked_sub(shares)
+            .ok_or(MathError::Underflow)?;
+        self.shares.insert(account, &new_shares);
+        self.total_shares.set(&new_total_shares);
+        Ok(())
+    }
+
+    fn allowance(&self, owner: &AccountId, spender: &AccountId) -> Balance {
+        self.allowances.get((*owner, *spender)).unwrap_or_default()
+    }
+    fn set_allowance(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        value: &Balance,
+    ) {
+        self.allowances.insert((*owner, *spender), value);
+    }
+    fn increase_allowance(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        amount: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        let new_allowance = self
+            .allowance(owner, spender)
+            .checked_add(*amount)
+            .ok_or(MathError::Overflow)?;
+        self.allowances.insert((*owner, *spender), &new_allowance);
+        Ok(new_allowance)
+    }
+    fn decrease_allowance(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        amount: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        let new_allowance = self
+            .allowance(owner, spender)
+            .checked_sub(*amount)
+            .ok_or(PSP22Error::InsufficientAllowance)?;
+        self.allowances.insert((*owner, *spender), &new_allowance);
+        Ok(new_allowance)
+    }
+}
+
+impl PSP22RebasingStorage for PSP22RebasingData {
+    fn shares_of(&self, account: &AccountId) -> Balance {
+        self.shares.get(account).unwrap_or_default()
+    }
+
+    fn total_shares(&self) -> Balance {
+        self.total_shares.get_or_default()
+    }
+
+    fn set_total_supply(&mut self, total_supply: &Balance) {
+        self.total_supply.set(total_supply);
+    }
+}
+
+pub trait PSP22RebasingDefaultImpl: PSP22RebasingInternal {
+    fn shares_of_default_impl(&self, account: AccountId) -> Balance {
+        self._shares_of(&account)
+    }
+
+    fn total_shares_default_impl(&self) -> Balance {
+        self._total_shares()
+    }
+}
+
+pub trait PSP22RebasingInternalDefaultImpl:
+    StorageFieldGetter<PSP22RebasingData> + PSP22RebasingInternal
+where
+    PSP22RebasingData: PSP22RebasingStorage,
+{
+    fn _shares_of_default_impl(&self, account: &AccountId) -> Balance {
+        PSP22RebasingStorage::shares_of(self.data(), account)
+    }
+
+    fn _total_shares_default_impl(&self) -> Balance {
+        PSP22RebasingStorage::total_shares(self.data())
+    }
+
+    fn _rebase_default_impl(
+        &mut self,
+        new_total_supply: &Balance,
+    ) -> Result<(), PSP22Error> {
+        // a supply without shares would be credited to the first minter
+        let no_shares = PSP22RebasingStorage::total_shares(self.data()) == 0;
+        if (*new_total_supply == 0) != no_shares {
+            return Err(PSP22Error::InvalidRebase);
+        }
+        let old_total_supply = PSP22Storage::total_supply(self.data());
+        self.data().set_total_supply(new_total_supply);
+        Self::env().emit_event(Rebased {
+            old_total_supply,
+            new_total_supply: *new_total_supply,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,12 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+/// Emitted when the total supply is rebased from `old_total_supply` to `new_total_supply`.
+///
+/// Every balance changes proportionally, no `Transfer` events are emitted.
+#[ink::event]
+#[derive(Debug)]
+pub struct Rebased {
+    pub old_total_supply: Balance,
+    pub new_total_supply: Balance,
+}
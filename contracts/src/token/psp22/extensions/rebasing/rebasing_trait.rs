@@ -0,0 +1,56 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22RebasingRef = contract_ref!(PSP22Rebasing, DefaultEnvironment);
+
+/// trait extending PSP22 with an elastic supply.
+///
+/// Balances are stored as shares and converted to tokens using the total supply to total shares ratio,
+/// so a rebase changes every balance at once.
+#[ink::trait_definition]
+pub trait PSP22Rebasing {
+    /// Returns the amount of shares owned by `account`.
+    #[ink(message)]
+    fn shares_of(&self, account: AccountId) -> Balance;
+
+    /// Returns the total amount of shares.
+    #[ink(message)]
+    fn total_shares(&self) -> Balance;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22RebasingInternal and PSP22Rebasing implementation can be derived.
+pub trait PSP22RebasingStorage {
+    /// Returns the amount of shares owned by `account`.
+    fn shares_of(&self, account: &AccountId) -> Balance;
+
+    /// Returns the total amount of shares.
+    fn total_shares(&self) -> Balance;
+
+    /// Sets the total supply without changing any shares.
+    fn set_total_supply(&mut self, total_supply: &Balance);
+}
+
+/// trait that is derived by Pendzl PSP22Rebasing implementation macro assuming StorageFieldGetter<PSP22RebasingStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22RebasingInternal {
+    /// Returns the amount of shares owned by `account`.
+    fn _shares_of(&self, account: &AccountId) -> Balance;
+
+    /// Returns the total amount of shares.
+    fn _total_shares(&self) -> Balance;
+
+    /// Sets the total supply to `new_total_supply` changing every balance proportionally in O(1).
+    ///
+    /// On success emits a `Rebased` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidRebase` if `new_total_supply` is zero while there are shares
+    /// or non zero while there are no shares.
+    fn _rebase(&mut self, new_total_supply: &Balance)
+        -> Result<(), PSP22Error>;
+}
@@ -0,0 +1,125 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{primitives::AccountId, storage::Mapping};
+use pendzl::traits::StorageFieldGetter;
+
+use super::{
+    AddedToList, PSP22RestrictedInternal, PSP22RestrictedStorage,
+    RemovedFromList, RestrictionMode,
+};
+use crate::token::psp22::PSP22Error;
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22RestrictedData {
+    #[lazy]
+    pub mode: RestrictionMode,
+    pub list: Mapping<AccountId, ()>,
+}
+
+impl PSP22RestrictedData {
+    pub fn new(mode: RestrictionMode) -> Self {
+        let mut instance: PSP22RestrictedData = Default::default();
+        instance.mode.set(&mode);
+        instance
+    }
+}
+
+impl PSP22RestrictedStorage for PSP22RestrictedData {
+    fn mode(&self) -> RestrictionMode {
+        self.mode.get_or_default()
+    }
+
+    fn is_listed(&self, account: &AccountId) -> bool {
+        self.list.contains(account)
+    }
+
+    fn add(&mut self, account: &AccountId) {
+        self.list.insert(account, &());
+    }
+
+    fn remove(&mut self, account: &AccountId) {
+        self.list.remove(account);
+    }
+}
+
+pub trait PSP22RestrictedDefaultImpl: PSP22RestrictedInternal {
+    fn restriction_mode_default_impl(&self) -> RestrictionMode {
+        self._restriction_mode()
+    }
+
+    fn is_listed_default_impl(&self, account: AccountId) -> bool {
+        self._is_listed(&account)
+    }
+
+    fn is_restricted_default_impl(&self, account: AccountId) -> bool {
+        self._is_restricted(&account)
+    }
+
+    fn add_to_list_default_impl(
+        &mut self,
+        account: AccountId,
+    ) -> Result<(), PSP22Error> {
+        self._add_to_list(&account)
+    }
+
+    fn remove_from_list_default_impl(
+        &mut self,
+        account: AccountId,
+    ) -> Result<(), PSP22Error> {
+        self._remove_from_list(&account)
+    }
+}
+
+pub trait PSP22RestrictedInternalDefaultImpl:
+    StorageFieldGetter<PSP22RestrictedData> + PSP22RestrictedInternal
+where
+    PSP22RestrictedData: PSP22RestrictedStorage,
+{
+    fn _restriction_mode_default_impl(&self) -> RestrictionMode {
+        self.data().mode()
+    }
+
+    fn _is_listed_default_impl(&self, account: &AccountId) -> bool {
+        self.data().is_listed(account)
+    }
+
+    fn _is_restricted_default_impl(&self, account: &AccountId) -> bool {
+        match self._restriction_mode() {
+            RestrictionMode::AllowlistMode => !self._is_listed(account),
+            RestrictionMode::BlocklistMode => self._is_listed(account),
+        }
+    }
+
+    fn _add_to_list_default_impl(
+        &mut self,
+        account: &AccountId,
+    ) -> Result<(), PSP22Error> {
+        self.data().add(account);
+        Self::env().emit_event(AddedToList { account: *account });
+        Ok(())
+    }
+
+    fn _remove_from_list_default_impl(
+        &mut self,
+        account: &AccountId,
+    ) -> Result<(), PSP22Error> {
+        self.data().remove(account);
+        Self::env().emit_event(RemovedFromList { account: *account });
+        Ok(())
+    }
+
+    fn _ensure_not_restricted_default_impl(
+        &self,
+        accounts: &[Option<&AccountId>],
+    ) -> Result<(), PSP22Error> {
+        if accounts
+            .iter()
+            .flatten()
+            .any(|account| self._is_restricted(account))
+        {
+            return Err(PSP22Error::AccountRestricted);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,18 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+/// Emitted when `account` is added to the list.
+#[ink::event]
+#[derive(Debug)]
+pub struct AddedToList {
+    #[ink(topic)]
+    pub account: AccountId,
+}
+
+/// Emitted when `account` is removed from the list.
+#[ink::event]
+#[derive(Debug)]
+pub struct RemovedFromList {
+    #[ink(topic)]
+    pub account: AccountId,
+}
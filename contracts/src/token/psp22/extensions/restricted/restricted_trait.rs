@@ -0,0 +1,99 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22RestrictedRef =
+    contract_ref!(PSP22Restricted, DefaultEnvironment);
+
+/// trait extending PSP22 with an allowlist or a blocklist of accounts.
+///
+/// Restricted accounts can not send, receive nor approve tokens, nor spend the allowances given to them.
+/// An allowance given to a restricted spender can still be revoked (set to 0).
+///
+/// The list modifiers should be guarded - the implementation macro requires `PSP22Restricted(guard = ...)`.
+#[ink::trait_definition]
+pub trait PSP22Restricted {
+    /// Returns whether the list is an allowlist or a blocklist.
+    #[ink(message)]
+    fn restriction_mode(&self) -> RestrictionMode;
+
+    /// Returns true if `account` is on the list.
+    #[ink(message)]
+    fn is_listed(&self, account: AccountId) -> bool;
+
+    /// Returns true if `account` can not send, receive nor approve tokens.
+    #[ink(message)]
+    fn is_restricted(&self, account: AccountId) -> bool;
+
+    /// Adds `account` to the list.
+    ///
+    /// On success an `AddedToList` event is emitted.
+    #[ink(message)]
+    fn add_to_list(&mut self, account: AccountId) -> Result<(), PSP22Error>;
+
+    /// Removes `account` from the list.
+    ///
+    /// On success a `RemovedFromList` event is emitted.
+    #[ink(message)]
+    fn remove_from_list(
+        &mut self,
+        account: AccountId,
+    ) -> Result<(), PSP22Error>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22RestrictedInternal and PSP22Restricted implementation can be derived.
+pub trait PSP22RestrictedStorage {
+    /// Returns the restriction mode.
+    fn mode(&self) -> RestrictionMode;
+
+    /// Returns true if `account` is on the list.
+    fn is_listed(&self, account: &AccountId) -> bool;
+
+    /// Adds `account` to the list.
+    fn add(&mut self, account: &AccountId);
+
+    /// Removes `account` from the list.
+    fn remove(&mut self, account: &AccountId);
+}
+
+/// trait that is derived by Pendzl PSP22Restricted implementation macro assuming StorageFieldGetter<PSP22RestrictedStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22RestrictedInternal {
+    /// Returns the restriction mode.
+    fn _restriction_mode(&self) -> RestrictionMode;
+
+    /// Returns true if `account` is on the list.
+    fn _is_listed(&self, account: &AccountId) -> bool;
+
+    /// Returns true if `account` is restricted according to the restriction mode.
+    fn _is_restricted(&self, account: &AccountId) -> bool;
+
+    /// Adds `account` to the list.
+    ///
+    /// On success emits an `AddedToList` event.
+    fn _add_to_list(&mut self, account: &AccountId) -> Result<(), PSP22Error>;
+
+    /// Removes `account` from the list.
+    ///
+    /// On success emits a `RemovedFromList` event.
+    fn _remove_from_list(
+        &mut self,
+        account: &AccountId,
+    ) -> Result<(), PSP22Error>;
+
+    /// Checks that none of the given accounts is restricted.
+    ///
+    /// Called by the `PSP22Internal::_update`, `PSP22Internal::_approve`, `PSP22Internal::_increase_allowance_from_to`
+    /// and `PSP22Internal::_spend_allowance` derived by the `implementation` macro.
+    ///
+    /// # Errors
+    ///
+    /// Returns `AccountRestricted` if any of the accounts is restricted.
+    fn _ensure_not_restricted(
+        &self,
+        accounts: &[Option<&AccountId>],
+    ) -> Result<(), PSP22Error>;
+}
@@ -0,0 +1,16 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+/// Defines how the list of accounts is interpreted.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum RestrictionMode {
+    /// Only the listed accounts can hold, send and approve tokens.
+    AllowlistMode,
+    /// The listed accounts can not hold, send nor approve tokens.
+    #[default]
+    BlocklistMode,
+}
@@ -0,0 +1,239 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{primitives::AccountId, storage::Mapping};
+use pendzl::{
+    math::errors::MathError,
+    traits::{Balance, StorageFieldGetter},
+};
+
+use super::{
+    PSP22SnapshotInternal, PSP22SnapshotStorage, Snapshot, SnapshotCheckpoint,
+    SnapshotId,
+};
+use crate::token::psp22::{PSP22Error, PSP22Internal};
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22SnapshotData {
+    #[lazy]
+    pub current_snapshot_id: SnapshotId,
+    pub balance_checkpoints: Mapping<(AccountId, u32), SnapshotCheckpoint>,
+    pub balance_checkpoints_len: Mapping<AccountId, u32>,
+    pub total_supply_checkpoints: Mapping<u32, SnapshotCheckpoint>,
+    #[lazy]
+    pub total_supply_checkpoints_len: u32,
+}
+
+/// Returns the value of the first checkpoint taken at or after `snapshot_id`.
+/// Checkpoints are sorted by snapshot id, so binary search is used.
+fn lookup_checkpoint(
+    len: u32,
+    snapshot_id: SnapshotId,
+    checkpoint_at: impl Fn(u32) -> SnapshotCheckpoint,
+) -> Option<Balance> {
+    let (mut low, mut high) = (0, len);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if checkpoint_at(mid).snapshot_id < snapshot_id {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    if low == len {
+        None
+    } else {
+        Some(checkpoint_at(low).value)
+    }
+}
+
+impl PSP22SnapshotStorage for PSP22SnapshotData {
+    fn current_snapshot_id(&self) -> SnapshotId {
+        self.current_snapshot_id.get_or_default()
+    }
+
+    fn increase_snapshot_id(&mut self) -> Result<SnapshotId, PSP22Error> {
+        let id = self
+            .current_snapshot_id()
+            .checked_add(1)
+            .ok_or(MathError::Overflow)?;
+        self.current_snapshot_id.set(&id);
+        Ok(id)
+    }
+
+    fn balance_checkpoint(
+        &self,
+        account: &AccountId,
+        snapshot_id: SnapshotId,
+    ) -> Option<Balance> {
+        lookup_checkpoint(
+            self.balance_checkpoints_len
+                .get(account)
+                .unwrap_or_default(),
+            snapshot_id,
+            |index| {
+                self.balance_checkpoints
+                    .get((account, index))
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    fn total_supply_checkpoint(
+        &self,
+        snapshot_id: SnapshotId,
+    ) -> Option<Balance> {
+        lookup_checkpoint(
+            self.total_supply_checkpoints_len.get_or_default(),
+            snapshot_id,
+            |index| {
+                self.total_supply_checkpoints.get(index).unwrap_or_default()
+            },
+        )
+    }
+
+    fn update_balance_checkpoint(
+        &mut self,
+        account: &AccountId,
+        balance: &Balance,
+    ) -> Result<(), PSP22Error> {
+        let current_snapshot_id = self.current_snapshot_id();
+        let len = self
+            .balance_checkpoints_len
+            .get(account)
+            .unwrap_or_default();
+        let last_snapshot_id = match len.checked_sub(1) {
+            Some(last) => {
+                self.balance_checkpoints
+                    .get((account, last))
+                    .unwrap_or_default()
+                    .snapshot_id
+            }
+            None => 0,
+        };
+        if last_snapshot_id < current_snapshot_id {
+            self.balance_checkpoints.insert(
+                (account, len),
+                &SnapshotCheckpoint {
+                    snapshot_id: current_snapshot_id,
+                    value: *balance,
+                },
+            );
+            self.balance_checkpoints_len.insert(
+                account,
+                &len.checked_add(1).ok_or(MathError::Overflow)?,
+            );
+        }
+        Ok(())
+    }
+
+    fn update_total_supply_checkpoint(
+        &mut self,
+        total_supply: &Balance,
+    ) -> Result<(), PSP22Error> {
+        let current_snapshot_id = self.current_snapshot_id();
+        let len = self.total_supply_checkpoints_len.get_or_default();
+        let last_snapshot_id = match len.checked_sub(1) {
+            Some(last) => {
+                self.total_supply_checkpoints
+                    .get(last)
+                    .unwrap_or_default()
+                    .snapshot_id
+            }
+            None => 0,
+        };
+        if last_snapshot_id < current_snapshot_id {
+            self.total_supply_checkpoints.insert(
+                len,
+                &SnapshotCheckpoint {
+                    snapshot_id: current_snapshot_id,
+                    value: *total_supply,
+                },
+            );
+            self.total_supply_checkpoints_len
+                .set(&len.checked_add(1).ok_or(MathError::Overflow)?);
+        }
+        Ok(())
+    }
+}
+
+pub trait PSP22SnapshotDefaultImpl: PSP22SnapshotInternal {
+    fn balance_of_at_default_impl(
+        &self,
+        account: AccountId,
+        snapshot_id: SnapshotId,
+    ) -> Option<Balance> {
+        self._balance_of_at(&account, snapshot_id)
+    }
+
+    fn total_supply_at_default_impl(
+        &self,
+        snapshot_id: SnapshotId,
+    ) -> Option<Balance> {
+        self._total_supply_at(snapshot_id)
+    }
+}
+
+pub trait PSP22SnapshotInternalDefaultImpl:
+    StorageFieldGetter<PSP22SnapshotData> + PSP22Internal
+where
+    PSP22SnapshotData: PSP22SnapshotStorage,
+{
+    fn _snapshot_default_impl(&mut self) -> Result<SnapshotId, PSP22Error> {
+        let id = self.data().increase_snapshot_id()?;
+        Self::env().emit_event(Snapshot { id });
+        Ok(id)
+    }
+
+    fn _current_snapshot_id_default_impl(&self) -> SnapshotId {
+        self.data().current_snapshot_id()
+    }
+
+    fn _balance_of_at_default_impl(
+        &self,
+        account: &AccountId,
+        snapshot_id: SnapshotId,
+    ) -> Option<Balance> {
+        if snapshot_id == 0 || snapshot_id > self.data().current_snapshot_id() {
+            return None;
+        }
+        Some(
+            self.data()
+                .balance_checkpoint(account, snapshot_id)
+                .unwrap_or_else(|| self._balance_of(account)),
+        )
+    }
+
+    fn _total_supply_at_default_impl(
+        &self,
+        snapshot_id: SnapshotId,
+    ) -> Option<Balance> {
+        if snapshot_id == 0 || snapshot_id > self.data().current_snapshot_id() {
+            return None;
+        }
+        Some(
+            self.data()
+                .total_supply_checkpoint(snapshot_id)
+                .unwrap_or_else(|| self._total_supply()),
+        )
+    }
+
+    fn _update_snapshots_default_impl(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+    ) -> Result<(), PSP22Error> {
+        if self.data().current_snapshot_id() == 0 {
+            return Ok(());
+        }
+        if from.is_none() || to.is_none() {
+            let total_supply = self._total_supply();
+            self.data().update_total_supply_checkpoint(&total_supply)?;
+        }
+        for account in [from, to].into_iter().flatten() {
+            let balance = self._balance_of(account);
+            self.data().update_balance_checkpoint(account, &balance)?;
+        }
+        Ok(())
+    }
+}
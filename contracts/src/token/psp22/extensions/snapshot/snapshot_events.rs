@@ -0,0 +1,10 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+/// Emitted when a new snapshot is taken.
+#[ink::event]
+#[derive(Debug)]
+pub struct Snapshot {
+    #[ink(topic)]
+    pub id: SnapshotId,
+}
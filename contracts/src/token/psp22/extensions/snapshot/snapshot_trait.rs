@@ -0,0 +1,99 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22SnapshotRef = contract_ref!(PSP22Snapshot, DefaultEnvironment);
+
+/// trait extending PSP22 with historical balance and total supply queries
+#[ink::trait_definition]
+pub trait PSP22Snapshot {
+    /// Returns the balance of `account` at the time the snapshot `snapshot_id` was taken.
+    ///
+    /// Returns `None` if the snapshot `snapshot_id` does not exist.
+    #[ink(message)]
+    fn balance_of_at(
+        &self,
+        account: AccountId,
+        snapshot_id: SnapshotId,
+    ) -> Option<Balance>;
+
+    /// Returns the total supply at the time the snapshot `snapshot_id` was taken.
+    ///
+    /// Returns `None` if the snapshot `snapshot_id` does not exist.
+    #[ink(message)]
+    fn total_supply_at(&self, snapshot_id: SnapshotId) -> Option<Balance>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22SnapshotInternal and PSP22Snapshot implementation can be derived.
+pub trait PSP22SnapshotStorage {
+    /// Returns the id of the last taken snapshot.
+    fn current_snapshot_id(&self) -> SnapshotId;
+
+    /// Increases the current snapshot id and returns it.
+    fn increase_snapshot_id(&mut self) -> Result<SnapshotId, PSP22Error>;
+
+    /// Returns the recorded balance of `account` at `snapshot_id`.
+    /// `None` means the balance did not change since the snapshot was taken.
+    fn balance_checkpoint(
+        &self,
+        account: &AccountId,
+        snapshot_id: SnapshotId,
+    ) -> Option<Balance>;
+
+    /// Returns the recorded total supply at `snapshot_id`.
+    /// `None` means the total supply did not change since the snapshot was taken.
+    fn total_supply_checkpoint(&self, snapshot_id: SnapshotId)
+        -> Option<Balance>;
+
+    /// Records `balance` as the balance of `account` at the current snapshot, if it was not recorded yet.
+    fn update_balance_checkpoint(
+        &mut self,
+        account: &AccountId,
+        balance: &Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Records `total_supply` as the total supply at the current snapshot, if it was not recorded yet.
+    fn update_total_supply_checkpoint(
+        &mut self,
+        total_supply: &Balance,
+    ) -> Result<(), PSP22Error>;
+}
+
+/// trait that is derived by Pendzl PSP22Snapshot implementation macro assuming StorageFieldGetter<PSP22SnapshotStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22SnapshotInternal {
+    /// Takes a new snapshot and returns its id.
+    ///
+    /// On success a `Snapshot` event is emitted.
+    fn _snapshot(&mut self) -> Result<SnapshotId, PSP22Error>;
+
+    /// Returns the id of the last taken snapshot.
+    fn _current_snapshot_id(&self) -> SnapshotId;
+
+    /// Returns the balance of `account` at the time the snapshot `snapshot_id` was taken.
+    ///
+    /// Returns `None` if the snapshot `snapshot_id` does not exist.
+    fn _balance_of_at(
+        &self,
+        account: &AccountId,
+        snapshot_id: SnapshotId,
+    ) -> Option<Balance>;
+
+    /// Returns the total supply at the time the snapshot `snapshot_id` was taken.
+    ///
+    /// Returns `None` if the snapshot `snapshot_id` does not exist.
+    fn _total_supply_at(&self, snapshot_id: SnapshotId) -> Option<Balance>;
+
+    /// Records the balances of `from` and `to` and the total supply (on mint and burn)
+    /// before they are changed.
+    ///
+    /// Called by the `PSP22Internal::_update` derived by the `implementation` macro.
+    fn _update_snapshots(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+    ) -> Result<(), PSP22Error>;
+}
@@ -0,0 +1,16 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+/// Identifier of a snapshot. Snapshot ids start at 1, 0 means no snapshot was taken yet.
+pub type SnapshotId = u32;
+
+/// A value (balance or total supply) as it was at the time the snapshot `snapshot_id` was taken.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct SnapshotCheckpoint {
+    pub snapshot_id: SnapshotId,
+    pub value: Balance,
+}
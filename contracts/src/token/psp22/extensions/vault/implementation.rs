@@ -3,16 +3,20 @@
 use ink::env::call::{build_call, ExecutionInput};
 use ink::env::DefaultEnvironment;
 use ink::primitives::AccountId;
+use ink::storage::Mapping;
 use ink::ToAccountId;
 use pendzl::math::{errors::MathError, operations::*};
 use pendzl::traits::{Balance, DefaultEnv, StorageFieldGetter};
 
-use super::{Deposit, PSP22VaultInternal, PSP22VaultStorage, Withdraw};
+use super::{
+    Deposit, DepositCapUpdated, PSP22VaultInternal, PSP22VaultStorage,
+    ReceiverCapUpdated, VaultFeesUpdated, Withdraw, VAULT_FEE_DENOMINATOR,
+};
 use crate::token::psp22::implementation::PSP22Data;
 use crate::token::psp22::{PSP22Error, PSP22};
 use crate::token::psp22::{PSP22Internal, PSP22Ref, PSP22Storage};
 
-use ink::prelude::{string::ToString, vec::*};
+use ink::prelude::vec::*;
 
 use pendzl::math::operations::Rounding;
 
@@ -23,6 +27,19 @@ pub struct PSP22VaultData {
     pub asset: PSP22Ref,
     #[lazy]
     pub underlying_decimals: u8,
+    #[lazy]
+    pub deposit_fee_bps: u16,
+    #[lazy]
+    pub withdraw_fee_bps: u16,
+    #[lazy]
+    pub fee_recipient: Option<AccountId>,
+    #[lazy]
+    pub reserved_assets: Balance,
+    #[lazy]
+    pub deposit_cap: Option<Balance>,
+    #[lazy]
+    pub default_receiver_cap: Option<Balance>,
+    pub receiver_caps: Mapping<AccountId, Balance>,
 }
 
 impl PSP22VaultData {
@@ -67,6 +84,58 @@ impl PSP22VaultStorage for PSP22VaultData {
     fn underlying_decimals(&self) -> u8 {
         self.underlying_decimals.get().unwrap()
     }
+    fn deposit_fee_bps(&self) -> u16 {
+        self.deposit_fee_bps.get_or_default()
+    }
+    fn withdraw_fee_bps(&self) -> u16 {
+        self.withdraw_fee_bps.get_or_default()
+    }
+    fn fee_recipient(&self) -> Option<AccountId> {
+        self.fee_recipient.get_or_default()
+    }
+    fn set_fees(
+        &mut self,
+        deposit_fee_bps: &u16,
+        withdraw_fee_bps: &u16,
+        fee_recipient: &Option<AccountId>,
+    ) {
+        self.deposit_fee_bps.set(deposit_fee_bps);
+        self.withdraw_fee_bps.set(withdraw_fee_bps);
+        self.fee_recipient.set(fee_recipient);
+    }
+    fn reserved_assets(&self) -> Balance {
+        self.reserved_assets.get_or_default()
+    }
+    fn set_reserved_assets(&mut self, reserved_assets: &Balance) {
+        self.reserved_assets.set(reserved_assets);
+    }
+    fn deposit_cap(&self) -> Option<Balance> {
+        self.deposit_cap.get_or_default()
+    }
+    fn set_deposit_cap(&mut self, cap: &Option<Balance>) {
+        self.deposit_cap.set(cap);
+    }
+    fn default_receiver_cap(&self) -> Option<Balance> {
+        self.default_receiver_cap.get_or_default()
+    }
+    fn set_default_receiver_cap(&mut self, cap: &Option<Balance>) {
+        self.default_receiver_cap.set(cap);
+    }
+    fn receiver_cap(&self, receiver: &AccountId) -> Option<Balance> {
+        self.receiver_caps.get(receiver)
+    }
+    fn set_receiver_cap(
+        &mut self,
+        receiver: &AccountId,
+        cap: &Option<Balance>,
+    ) {
+        match cap {
+            Some(cap) => {
+                self.receiver_caps.insert(receiver, cap);
+            }
+            None => self.receiver_caps.remove(receiver),
+        }
+    }
 }
 
 pub trait PSP22VaultInternalDefaultImpl:
@@ -104,7 +173,26 @@ where
     }
 
     fn _total_assets_default_impl(&self) -> Balance {
-        self._asset().balance_of(Self::env().account_id())
+        self._idle_assets().saturating_add(self._deployed_assets())
+    }
+
+    fn _idle_assets_default_impl(&self) -> Balance {
+        self._asset()
+            .balance_of(Self::env().account_id())
+            .saturating_sub(self._reserved_assets())
+    }
+
+    fn _deployed_assets_default_impl(&self) -> Balance {
+        0
+    }
+
+    fn _reserved_assets_default_impl(&self) -> Balance {
+        self.data::<PSP22VaultData>().reserved_assets()
+    }
+
+    fn _set_reserved_assets_default_impl(&mut self, reserved_assets: &Balance) {
+        self.data::<PSP22VaultData>()
+            .set_reserved_assets(reserved_assets);
     }
 
     fn _convert_to_shares_default_impl(
@@ -143,18 +231,68 @@ where
         )
     }
 
-    fn _max_deposit_default_impl(&self, _to: &AccountId) -> Balance {
-        u128::MAX
+    fn _max_deposit_default_impl(&self, to: &AccountId) -> Balance {
+        let remaining = match self._deposit_cap() {
+            Some(cap) => cap.saturating_sub(self._total_assets()),
+            None => u128::MAX,
+        };
+        match self._receiver_cap(to) {
+            Some(cap) => {
+                let held = self
+                    ._convert_to_assets(&self._balance_of(to), Rounding::Up)
+                    .unwrap_or(u128::MAX);
+                remaining.min(cap.saturating_sub(held))
+            }
+            None => remaining,
+        }
+    }
+
+    fn _max_mint_default_impl(&self, to: &AccountId) -> Balance {
+        let max_deposit = self._max_deposit(to);
+        if max_deposit == u128::MAX {
+            return u128::MAX;
+        }
+        self._preview_deposit(&max_deposit).unwrap_or_default()
+    }
+
+    fn _deposit_cap_default_impl(&self) -> Option<Balance> {
+        self.data::<PSP22VaultData>().deposit_cap()
     }
 
-    fn _max_mint_default_impl(&self, _to: &AccountId) -> Balance {
-        u128::MAX
+    fn _receiver_cap_default_impl(
+        &self,
+        receiver: &AccountId,
+    ) -> Option<Balance> {
+        let data = self.data::<PSP22VaultData>();
+        data.receiver_cap(receiver)
+            .or_else(|| data.default_receiver_cap())
+    }
+
+    fn _set_deposit_cap_default_impl(&mut self, cap: &Option<Balance>) {
+        self.data::<PSP22VaultData>().set_deposit_cap(cap);
+        Self::env().emit_event(DepositCapUpdated { cap: *cap });
+    }
+
+    fn _set_receiver_cap_default_impl(
+        &mut self,
+        receiver: &Option<AccountId>,
+        cap: &Option<Balance>,
+    ) {
+        match receiver {
+            Some(receiver) => self
+                .data::<PSP22VaultData>()
+                .set_receiver_cap(receiver, cap),
+            None => self.data::<PSP22VaultData>().set_default_receiver_cap(cap),
+        }
+        Self::env().emit_event(ReceiverCapUpdated {
+            receiver: *receiver,
+            cap: *cap,
+        });
     }
 
     fn _max_withdraw_default_impl(&self, owner: &AccountId) -> Balance {
         let owner_balance = self._balance_of(&owner);
-        self._convert_to_assets(&owner_balance, Rounding::Down)
-            .unwrap()
+        self._preview_redeem(&owner_balance).unwrap_or_default()
     }
     fn _max_redeem_default_impl(&self, owner: &AccountId) -> Balance {
         self._balance_of(&owner)
@@ -163,6 +301,8 @@ where
         &self,
         assets: &Balance,
     ) -> Result<Balance, MathError> {
+        let fee = self._fee_on_total(assets, &self._deposit_fee_bps())?;
+        let assets = assets.checked_sub(fee).ok_or(MathError::Underflow)?;
         self._convert_to_shares(&assets, Rounding::Down)
     }
 
@@ -170,13 +310,17 @@ where
         &self,
         shares: &Balance,
     ) -> Result<Balance, MathError> {
-        self._convert_to_assets(&shares, Rounding::Up)
+        let assets = self._convert_to_assets(shares, Rounding::Up)?;
+        let fee = self._fee_on_raw(&assets, &self._deposit_fee_bps())?;
+        assets.checked_add(fee).ok_or(MathError::Overflow)
     }
 
     fn _preview_withdraw_default_impl(
         &self,
         assets: &Balance,
     ) -> Result<Balance, MathError> {
+        let fee = self._fee_on_raw(assets, &self._withdraw_fee_bps())?;
+        let assets = assets.checked_add(fee).ok_or(MathError::Overflow)?;
         self._convert_to_shares(&assets, Rounding::Up)
     }
 
@@ -184,7 +328,73 @@ where
         &self,
         shares: &Balance,
     ) -> Result<Balance, MathError> {
-        self._convert_to_assets(&shares, Rounding::Down)
+        let assets = self._convert_to_assets(shares, Rounding::Down)?;
+        let fee = self._fee_on_total(&assets, &self._withdraw_fee_bps())?;
+        assets.checked_sub(fee).ok_or(MathError::Underflow)
+    }
+
+    fn _deposit_fee_bps_default_impl(&self) -> u16 {
+        self.data::<PSP22VaultData>().deposit_fee_bps()
+    }
+
+    fn _withdraw_fee_bps_default_impl(&self) -> u16 {
+        self.data::<PSP22VaultData>().withdraw_fee_bps()
+    }
+
+    fn _vault_fee_recipient_default_impl(&self) -> Option<AccountId> {
+        self.data::<PSP22VaultData>().fee_recipient()
+    }
+
+    fn _set_vault_fees_default_impl(
+        &mut self,
+        deposit_fee_bps: &u16,
+        withdraw_fee_bps: &u16,
+        fee_recipient: &Option<AccountId>,
+    ) -> Result<(), PSP22Error> {
+        if *deposit_fee_bps > VAULT_FEE_DENOMINATOR
+            || *withdraw_fee_bps > VAULT_FEE_DENOMINATOR
+        {
+            return Err(PSP22Error::InvalidFee);
+        }
+        self.data::<PSP22VaultData>().set_fees(
+            deposit_fee_bps,
+            withdraw_fee_bps,
+            fee_recipient,
+        );
+        Self::env().emit_event(VaultFeesUpdated {
+            deposit_fee_bps: *deposit_fee_bps,
+            withdraw_fee_bps: *withdraw_fee_bps,
+            fee_recipient: *fee_recipient,
+        });
+        Ok(())
+    }
+
+    fn _fee_on_raw_default_impl(
+        &self,
+        assets: &Balance,
+        fee_bps: &u16,
+    ) -> Result<Balance, MathError> {
+        mul_div(
+            *assets,
+            *fee_bps as u128,
+            VAULT_FEE_DENOMINATOR as u128,
+            Rounding::Up,
+        )
+    }
+
+    fn _fee_on_total_default_impl(
+        &self,
+        assets: &Balance,
+        fee_bps: &u16,
+    ) -> Result<Balance, MathError> {
+        mul_div(
+            *assets,
+            *fee_bps as u128,
+            (*fee_bps as u128)
+                .checked_add(VAULT_FEE_DENOMINATOR as u128)
+                .ok_or(MathError::Overflow)?,
+            Rounding::Up,
+        )
     }
 
     fn _deposit_default_impl(
@@ -207,6 +417,14 @@ where
         )?;
         self._mint_to(receiver, shares)?;
 
+        if let Some(fee_recipient) = self._vault_fee_recipient() {
+            let fee = self._fee_on_total(assets, &self._deposit_fee_bps())?;
+            if fee > 0 && fee_recipient != Self::env().account_id() {
+                self._asset()
+                    .transfer(fee_recipient, fee, Vec::<u8>::new())?;
+            }
+        }
+
         Self::env().emit_event(Deposit {
             sender: *caller,
             owner: *receiver,
@@ -226,13 +444,21 @@ where
         shares: &Balance,
     ) -> Result<(), PSP22Error> {
         if caller != owner {
-            self._decrease_allowance_from_to(owner, caller, shares)?;
+            self._spend_allowance(owner, caller, shares)?;
         }
 
         self._burn_from(owner, shares)?;
         self._asset()
             .transfer(*receiver, *assets, Vec::<u8>::new())?;
 
+        if let Some(fee_recipient) = self._vault_fee_recipient() {
+            let fee = self._fee_on_raw(assets, &self._withdraw_fee_bps())?;
+            if fee > 0 && fee_recipient != Self::env().account_id() {
+                self._asset()
+                    .transfer(fee_recipient, fee, Vec::<u8>::new())?;
+            }
+        }
+
         Self::env().emit_event(Withdraw {
             sender: *caller,
             receiver: *receiver,
@@ -321,7 +547,7 @@ pub trait PSP22VaultDefaultImpl:
         receiver: AccountId,
     ) -> Result<Balance, PSP22Error> {
         if assets > self._max_deposit(&receiver) {
-            return Err(PSP22Error::Custom("V:MaxDeposit".to_string()));
+            return Err(PSP22Error::MaxDepositExceeded);
         }
         let shares = self._preview_deposit(&assets)?;
         ink::env::debug_println!(
@@ -339,7 +565,7 @@ pub trait PSP22VaultDefaultImpl:
         receiver: AccountId,
     ) -> Result<Balance, PSP22Error> {
         if shares > self._max_mint(&receiver) {
-            return Err(PSP22Error::Custom("V:MaxMint".to_string()));
+            return Err(PSP22Error::MaxMintExceeded);
         }
         let assets = self._preview_mint(&shares)?;
         self._deposit(&Self::env().caller(), &receiver, &assets, &shares)?;
@@ -353,7 +579,7 @@ pub trait PSP22VaultDefaultImpl:
         owner: AccountId,
     ) -> Result<Balance, PSP22Error> {
         if assets > self._max_withdraw(&owner) {
-            return Err(PSP22Error::Custom("V:MaxWithdraw".to_string()));
+            return Err(PSP22Error::MaxWithdrawExceeded);
         }
         let shares = self._preview_withdraw(&assets)?;
         self._withdraw(
@@ -373,7 +599,7 @@ pub trait PSP22VaultDefaultImpl:
         owner: AccountId,
     ) -> Result<Balance, PSP22Error> {
         if shares > self._max_redeem(&owner) {
-            return Err(PSP22Error::Custom("V:MaxRedeem".to_string()));
+            return Err(PSP22Error::MaxRedeemExceeded);
         }
         let assets = self._preview_redeem(&shares)?;
         self._withdraw(
@@ -386,3 +612,33 @@ pub trait PSP22VaultDefaultImpl:
         Ok(assets)
     }
 }
+
+pub trait PSP22VaultCapsDefaultImpl: PSP22VaultInternal {
+    fn deposit_cap_default_impl(&self) -> Option<Balance> {
+        self._deposit_cap()
+    }
+
+    fn receiver_cap_default_impl(
+        &self,
+        receiver: AccountId,
+    ) -> Option<Balance> {
+        self._receiver_cap(&receiver)
+    }
+
+    fn set_deposit_cap_default_impl(
+        &mut self,
+        cap: Option<Balance>,
+    ) -> Result<(), PSP22Error> {
+        self._set_deposit_cap(&cap);
+        Ok(())
+    }
+
+    fn set_receiver_cap_default_impl(
+        &mut self,
+        receiver: Option<AccountId>,
+        cap: Option<Balance>,
+    ) -> Result<(), PSP22Error> {
+        self._set_receiver_cap(&receiver, &cap);
+        Ok(())
+    }
+}
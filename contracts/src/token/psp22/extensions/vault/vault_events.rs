@@ -23,3 +23,26 @@ pub struct Withdraw {
     pub assets: Balance,
     pub shares: Balance,
 }
+
+/// Emitted when the deposit and withdrawal fees or their recipient are changed.
+#[ink::event]
+pub struct VaultFeesUpdated {
+    pub deposit_fee_bps: u16,
+    pub withdraw_fee_bps: u16,
+    #[ink(topic)]
+    pub fee_recipient: Option<AccountId>,
+}
+
+/// Emitted when the cap of the Vault total assets is changed.
+#[ink::event]
+pub struct DepositCapUpdated {
+    pub cap: Option<Balance>,
+}
+
+/// Emitted when the cap of the assets held by `receiver` is changed (`None` - the default cap of all receivers).
+#[ink::event]
+pub struct ReceiverCapUpdated {
+    #[ink(topic)]
+    pub receiver: Option<AccountId>,
+    pub cap: Option<Balance>,
+}
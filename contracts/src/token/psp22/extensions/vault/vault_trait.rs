@@ -1,6 +1,9 @@
 // Copyright (c) 2024 C Forge. All Rights Reserved.
 // SPDX-License-Identifier: MIT
 
+/// Denominator of the vault deposit and withdrawal fees - the fees are expressed in basis points.
+pub const VAULT_FEE_DENOMINATOR: u16 = 10_000;
+
 /// Trait based on the ERC-4626 "Tokenized Vault Standard", as defined in
 /// https://eips.ethereum.org/EIPS/eip-4626.
 #[ink::trait_definition]
@@ -157,6 +160,10 @@ pub trait PSP22Vault {
     /// - MUST revert if all of assets cannot be deposited.
     ///
     /// NOTE: most implementations will require pre-approval of the Vault with the Vault’s underlying asset token.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MaxDepositExceeded` if `assets` exceed `max_deposit` of the receiver.
     #[ink(message)]
     fn deposit(
         &mut self,
@@ -172,6 +179,10 @@ pub trait PSP22Vault {
     /// - MUST revert if all of shares cannot be minted.
     ///
     /// NOTE: most implementations will require pre-approval of the Vault with the Vault’s underlying asset token.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MaxMintExceeded` if `shares` exceed `max_mint` of the receiver.
     #[ink(message)]
     fn mint(
         &mut self,
@@ -188,6 +199,10 @@ pub trait PSP22Vault {
     ///
     /// Note that some implementations will require pre-requesting to the Vault before a withdrawal may be performed.
     /// Those methods should be performed separately.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MaxWithdrawExceeded` if `assets` exceed `max_withdraw` of the owner.
     #[ink(message)]
     fn withdraw(
         &mut self,
@@ -209,6 +224,10 @@ pub trait PSP22Vault {
     /// compliance as it provides a mechanism for share redemption, aligning with the standard's emphasis on flexibility
     /// and efficiency in tokenized vault interactions. The implementation should ensure accurate and secure handling
     /// of shares and underlying assets, respecting all applicable limits and conditions as outlined in the standard.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MaxRedeemExceeded` if `shares` exceed `max_redeem` of the owner.
     #[ink(message)]
     fn redeem(
         &mut self,
@@ -218,6 +237,41 @@ pub trait PSP22Vault {
     ) -> Result<Balance, PSP22Error>;
 }
 
+/// trait extending PSP22Vault with setters of the deposit caps.
+///
+/// The Vault total assets can be capped (TVL cap) as well as the assets held by each receiver.
+/// `max_deposit` and `max_mint` return the capacity remaining under both caps.
+/// The caps are stored in `PSP22VaultData` so they are respected also without this trait.
+#[ink::trait_definition]
+pub trait PSP22VaultCaps {
+    /// Returns the cap of the Vault total assets (`None` - no cap).
+    #[ink(message)]
+    fn deposit_cap(&self) -> Option<Balance>;
+
+    /// Returns the cap of the assets held by `receiver` (`None` - no cap).
+    #[ink(message)]
+    fn receiver_cap(&self, receiver: AccountId) -> Option<Balance>;
+
+    /// Sets the cap of the Vault total assets (`None` - no cap).
+    ///
+    /// On success a `DepositCapUpdated` event is emitted.
+    #[ink(message)]
+    fn set_deposit_cap(&mut self, cap: Option<Balance>) -> Result<(), PSP22Error>;
+
+    /// Sets the cap of the assets held by `receiver`.
+    ///
+    /// If `receiver` is `None` sets the default cap of all receivers without their own cap.
+    /// If `receiver` is `Some` and `cap` is `None`, the receiver's own cap is removed (the default cap applies).
+    ///
+    /// On success a `ReceiverCapUpdated` event is emitted.
+    #[ink(message)]
+    fn set_receiver_cap(
+        &mut self,
+        receiver: Option<AccountId>,
+        cap: Option<Balance>,
+    ) -> Result<(), PSP22Error>;
+}
+
 /// trait that is derived by Pendzl Pausable implementation macro assuming StorageFieldGetter<PSP22VaultStorage> is implemented
 ///
 /// functions of this trait are recomended to use while writing ink::messages
@@ -236,7 +290,26 @@ pub trait PSP22VaultInternal {
     /// returns reference to asset that can be deposited and withdrawn
     fn _asset(&self) -> PSP22Ref;
 
+    /// Returns the amount of assets managed by the Vault - the idle and the deployed assets.
     fn _total_assets(&self) -> Balance;
+
+    /// Returns the amount of assets held by the Vault itself without the reserved ones.
+    fn _idle_assets(&self) -> Balance;
+
+    /// Returns the amount of assets deployed by the Vault outside of it (ex. in strategies).
+    ///
+    /// - Returns 0 by default. Overridden by the PSP22VaultStrategies implementation.
+    fn _deployed_assets(&self) -> Balance;
+
+    /// Returns the amount of assets held by the Vault that don't back the shares
+    /// (ex. assets of pending deposit requests or claimable redeem requests).
+    ///
+    /// - Reserved assets are excluded from `_total_assets`.
+    fn _reserved_assets(&self) -> Balance;
+
+    /// Sets the amount of reserved assets (see `_reserved_assets`).
+    fn _set_reserved_assets(&mut self, reserved_assets: &Balance);
+
     /// Internal conversion function from assets to shares with support for rounding direction.
     ///
     /// - Performs multiplication and division for asset to share conversion with specified rounding.
@@ -256,11 +329,35 @@ pub trait PSP22VaultInternal {
     ) -> Result<Balance, MathError>;
 
     /// doc @ PSP22Vault::max_deposit
+    ///
+    /// - Returns the capacity remaining under the deposit cap and the cap of the receiver.
     fn _max_deposit(&self, to: &AccountId) -> Balance;
 
     /// doc @ PSP22Vault::max_mint
+    ///
+    /// - Returns the shares that `_max_deposit` assets are worth.
     fn _max_mint(&self, to: &AccountId) -> Balance;
 
+    /// doc @ PSP22VaultCaps::deposit_cap
+    fn _deposit_cap(&self) -> Option<Balance>;
+
+    /// doc @ PSP22VaultCaps::receiver_cap
+    fn _receiver_cap(&self, receiver: &AccountId) -> Option<Balance>;
+
+    /// Sets the cap of the Vault total assets.
+    ///
+    /// On success emits `DepositCapUpdated` event.
+    fn _set_deposit_cap(&mut self, cap: &Option<Balance>);
+
+    /// Sets the cap of the assets held by `receiver` (`None` - the default cap).
+    ///
+    /// On success emits `ReceiverCapUpdated` event.
+    fn _set_receiver_cap(
+        &mut self,
+        receiver: &Option<AccountId>,
+        cap: &Option<Balance>,
+    );
+
     /// doc @ PSP22Vault::max_withdraw
     fn _max_withdraw(&self, owner: &AccountId) -> Balance;
     /// doc @ PSP22Vault::max_redeem
@@ -279,9 +376,53 @@ pub trait PSP22VaultInternal {
     /// doc @ PSP22Vault::preview_redeem
     fn _preview_redeem(&self, shares: &Balance) -> Result<Balance, MathError>;
 
+    /// Returns the fee charged on deposits and mints in basis points.
+    fn _deposit_fee_bps(&self) -> u16;
+
+    /// Returns the fee charged on withdrawals and redeems in basis points.
+    fn _withdraw_fee_bps(&self) -> u16;
+
+    /// Returns the recipient of the deposit and withdrawal fees.
+    ///
+    /// - If `None` the fees stay in the Vault (accrue to the shares holders).
+    fn _vault_fee_recipient(&self) -> Option<AccountId>;
+
+    /// Sets the deposit and withdrawal fees (in basis points) and their recipient.
+    ///
+    /// On success emits `VaultFeesUpdated` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidFee` if any of the fees exceeds `VAULT_FEE_DENOMINATOR`.
+    fn _set_vault_fees(
+        &mut self,
+        deposit_fee_bps: &u16,
+        withdraw_fee_bps: &u16,
+        fee_recipient: &Option<AccountId>,
+    ) -> Result<(), PSP22Error>;
+
+    /// Calculates the fee that should be added to `assets` that don't include the fee.
+    ///
+    /// - Rounds up in favor of the Vault.
+    fn _fee_on_raw(
+        &self,
+        assets: &Balance,
+        fee_bps: &u16,
+    ) -> Result<Balance, MathError>;
+
+    /// Calculates the fee part of `assets` that already include the fee.
+    ///
+    /// - Rounds up in favor of the Vault.
+    fn _fee_on_total(
+        &self,
+        assets: &Balance,
+        fee_bps: &u16,
+    ) -> Result<Balance, MathError>;
+
     /// Common workflow for deposit/mint operations.
     ///
     /// - Handles transfer of assets from caller to contract, followed by minting of shares to the receiver.
+    /// - Transfers the deposit fee included in `assets` to the fee recipient (if set).
     /// - Ensures safety against reentrancy attacks when dealing with ERC777 tokens.
     fn _deposit(
         &mut self,
@@ -294,6 +435,7 @@ pub trait PSP22VaultInternal {
     /// Common workflow for withdraw/redeem operations.
     ///
     /// - Verifies allowances, burns shares from the owner, and handles transfer of assets to the receiver.
+    /// - Transfers the withdrawal fee charged on top of `assets` to the fee recipient (if set).
     /// - Ensures safety against reentrancy attacks when dealing with ERC777 tokens.
     fn _withdraw(
         &mut self,
@@ -311,4 +453,33 @@ pub trait PSP22VaultStorage {
     fn asset(&self) -> PSP22Ref;
 
     fn underlying_decimals(&self) -> u8;
+
+    fn deposit_fee_bps(&self) -> u16;
+
+    fn withdraw_fee_bps(&self) -> u16;
+
+    fn fee_recipient(&self) -> Option<AccountId>;
+
+    fn set_fees(
+        &mut self,
+        deposit_fee_bps: &u16,
+        withdraw_fee_bps: &u16,
+        fee_recipient: &Option<AccountId>,
+    );
+
+    fn reserved_assets(&self) -> Balance;
+
+    fn set_reserved_assets(&mut self, reserved_assets: &Balance);
+
+    fn deposit_cap(&self) -> Option<Balance>;
+
+    fn set_deposit_cap(&mut self, cap: &Option<Balance>);
+
+    fn default_receiver_cap(&self) -> Option<Balance>;
+
+    fn set_default_receiver_cap(&mut self, cap: &Option<Balance>);
+
+    fn receiver_cap(&self, receiver: &AccountId) -> Option<Balance>;
+
+    fn set_receiver_cap(&mut self, receiver: &AccountId, cap: &Option<Balance>);
 }
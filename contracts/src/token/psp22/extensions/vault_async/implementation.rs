@@ -0,0 +1,536 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{prelude::vec::Vec, primitives::AccountId, storage::Mapping};
+use pendzl::{
+    math::{
+        errors::MathError,
+        operations::{mul_div, Rounding},
+    },
+    traits::{Balance, DefaultEnv, StorageFieldGetter},
+};
+
+use super::{
+    DepositClaimable, DepositRequest, PSP22VaultAsyncInternal,
+    PSP22VaultAsyncStorage, RedeemClaimable, RedeemRequest,
+};
+use crate::token::psp22::{
+    vault::{Deposit, PSP22VaultInternal, Withdraw},
+    PSP22Error, PSP22Internal, PSP22,
+};
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22VaultAsyncData {
+    pub pending_deposits: Mapping<AccountId, Balance>,
+    pub claimable_deposits: Mapping<AccountId, (Balance, Balance)>,
+    pub pending_redeems: Mapping<AccountId, Balance>,
+    pub claimable_redeems: Mapping<AccountId, (Balance, Balance)>,
+}
+
+impl PSP22VaultAsyncStorage for PSP22VaultAsyncData {
+    fn pending_deposit(&self, controller: &AccountId) -> Balance {
+        self.pending_deposits.get(controller).unwrap_or_default()
+    }
+
+    fn set_pending_deposit(
+        &mut self,
+        controller: &AccountId,
+        assets: &Balance,
+    ) {
+        if *assets == 0 {
+            self.pending_deposits.remove(controller);
+        } else {
+            self.pending_deposits.insert(controller, assets);
+        }
+    }
+
+    fn claimable_deposit(&self, controller: &AccountId) -> (Balance, Balance) {
+        self.claimable_deposits.get(controller).unwrap_or_default()
+    }
+
+    fn set_claimable_deposit(
+        &mut self,
+        controller: &AccountId,
+        assets: &Balance,
+        shares: &Balance,
+    ) {
+        if *assets == 0 && *shares == 0 {
+            self.claimable_deposits.remove(controller);
+        } else {
+            self.claimable_deposits
+                .insert(controller, &(*assets, *shares));
+        }
+    }
+
+    fn pending_redeem(&self, controller: &AccountId) -> Balance {
+        self.pending_redeems.get(controller).unwrap_or_default()
+    }
+
+    fn set_pending_redeem(&mut self, controller: &AccountId, shares: &Balance) {
+        if *shares == 0 {
+            self.pending_redeems.remove(controller);
+        } else {
+            self.pending_redeems.insert(controller, shares);
+        }
+    }
+
+    fn claimable_redeem(&self, controller: &AccountId) -> (Balance, Balance) {
+        self.claimable_redeems.get(controller).unwrap_or_default()
+    }
+
+    fn set_claimable_redeem(
+        &mut self,
+        controller: &AccountId,
+        assets: &Balance,
+        shares: &Balance,
+    ) {
+        if *assets == 0 && *shares == 0 {
+            self.claimable_redeems.remove(controller);
+        } else {
+            self.claimable_redeems
+                .insert(controller, &(*assets, *shares));
+        }
+    }
+}
+
+pub trait PSP22VaultAsyncDefaultImpl:
+    PSP22VaultAsyncInternal + DefaultEnv
+{
+    fn request_deposit_default_impl(
+        &mut self,
+        assets: Balance,
+        controller: AccountId,
+        owner: AccountId,
+    ) -> Result<(), PSP22Error> {
+        self._request_deposit(
+            &Self::env().caller(),
+            &assets,
+            &controller,
+            &owner,
+        )
+    }
+
+    fn request_redeem_default_impl(
+        &mut self,
+        shares: Balance,
+        controller: AccountId,
+        owner: AccountId,
+    ) -> Result<(), PSP22Error> {
+        self._request_redeem(
+            &Self::env().caller(),
+            &shares,
+            &controller,
+            &owner,
+        )
+    }
+
+    fn pending_deposit_request_default_impl(
+        &self,
+        controller: AccountId,
+    ) -> Balance {
+        self._pending_deposit_request(&controller)
+    }
+
+    fn claimable_deposit_request_default_impl(
+        &self,
+        controller: AccountId,
+    ) -> Balance {
+        self._claimable_deposit_request(&controller).0
+    }
+
+    fn pending_redeem_request_default_impl(
+        &self,
+        controller: AccountId,
+    ) -> Balance {
+        self._pending_redeem_request(&controller)
+    }
+
+    fn claimable_redeem_request_default_impl(
+        &self,
+        controller: AccountId,
+    ) -> Balance {
+        self._claimable_redeem_request(&controller).1
+    }
+
+    fn fulfill_deposit_default_impl(
+        &mut self,
+        controller: AccountId,
+        assets: Balance,
+    ) -> Result<Balance, PSP22Error> {
+        self._fulfill_deposit(&controller, &assets)
+    }
+
+    fn fulfill_redeem_default_impl(
+        &mut self,
+        controller: AccountId,
+        shares: Balance,
+    ) -> Result<Balance, PSP22Error> {
+        self._fulfill_redeem(&controller, &shares)
+    }
+}
+
+pub trait PSP22VaultAsyncInternalDefaultImpl:
+    StorageFieldGetter<PSP22VaultAsyncData>
+    + PSP22Internal
+    + PSP22VaultInternal
+    + PSP22VaultAsyncInternal
+where
+    PSP22VaultAsyncData: PSP22VaultAsyncStorage,
+{
+    fn _is_async_deposit_default_impl(&self) -> bool {
+        true
+    }
+
+    fn _is_async_redeem_default_impl(&self) -> bool {
+        true
+    }
+
+    fn _pending_deposit_request_default_impl(
+        &self,
+        controller: &AccountId,
+    ) -> Balance {
+        self.data().pending_deposit(controller)
+    }
+
+    fn _claimable_deposit_request_default_impl(
+        &self,
+        controller: &AccountId,
+    ) -> (Balance, Balance) {
+        self.data().claimable_deposit(controller)
+    }
+
+    fn _pending_redeem_request_default_impl(
+        &self,
+        controller: &AccountId,
+    ) -> Balance {
+        self.data().pending_redeem(controller)
+    }
+
+    fn _claimable_redeem_request_default_impl(
+        &self,
+        controller: &AccountId,
+    ) -> (Balance, Balance) {
+        self.data().claimable_redeem(controller)
+    }
+
+    fn _request_deposit_default_impl(
+        &mut self,
+        sender: &AccountId,
+        assets: &Balance,
+        controller: &AccountId,
+        owner: &AccountId,
+    ) -> Result<(), PSP22Error> {
+        if sender != owner {
+            return Err(PSP22Error::InvalidRequestCaller);
+        }
+        let pending = self
+            .data()
+            .pending_deposit(controller)
+            .checked_add(*assets)
+            .ok_or(MathError::Overflow)?;
+        // the deposit caps apply to the pending assets of the controller
+        if pending > self._max_deposit(controller) {
+            return Err(PSP22Error::MaxDepositExceeded);
+        }
+        self._asset().transfer_from(
+            *owner,
+            Self::env().account_id(),
+            *assets,
+            Vec::<u8>::new(),
+        )?;
+
+        self.data().set_pending_deposit(controller, &pending);
+        let reserved_assets = self
+            ._reserved_assets()
+            .checked_add(*assets)
+            .ok_or(MathError::Overflow)?;
+        self._set_reserved_assets(&reserved_assets);
+
+        Self::env().emit_event(DepositRequest {
+            controller: *controller,
+            owner: *owner,
+            sender: *sender,
+            assets: *assets,
+        });
+        Ok(())
+    }
+
+    fn _request_redeem_default_impl(
+        &mut self,
+        sender: &AccountId,
+        shares: &Balance,
+        controller: &AccountId,
+        owner: &AccountId,
+    ) -> Result<(), PSP22Error> {
+        if sender != owner {
+            self._spend_allowance(owner, sender, shares)?;
+        }
+        self._transfer(owner, &Self::env().account_id(), shares)?;
+
+        let pending = self
+            .data()
+            .pending_redeem(controller)
+            .checked_add(*shares)
+            .ok_or(MathError::Overflow)?;
+        self.data().set_pending_redeem(controller, &pending);
+
+        Self::env().emit_event(RedeemRequest {
+            controller: *controller,
+            owner: *owner,
+            sender: *sender,
+            shares: *shares,
+        });
+        Ok(())
+    }
+
+    fn _fulfill_deposit_default_impl(
+        &mut self,
+        controller: &AccountId,
+        assets: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        let pending = self.data().pending_deposit(controller);
+        if *assets > pending {
+            return Err(PSP22Error::InsufficientPendingRequest);
+        }
+        // the assets are still reserved so the price doesn't include them
+        let shares = self._convert_to_shares(assets, Rounding::Down)?;
+
+        self.data()
+            .set_pending_deposit(controller, &(pending - *assets));
+        let reserved_assets = self
+            ._reserved_assets()
+            .checked_sub(*assets)
+            .ok_or(MathError::Underflow)?;
+        self._set_reserved_assets(&reserved_assets);
+        self._mint_to(&Self::env().account_id(), &shares)?;
+
+        let (claimable_assets, claimable_shares) =
+            self.data().claimable_deposit(controller);
+        self.data().set_claimable_deposit(
+            controller,
+            &claimable_assets
+                .checked_add(*assets)
+                .ok_or(MathError::Overflow)?,
+            &claimable_shares
+                .checked_add(shares)
+                .ok_or(MathError::Overflow)?,
+        );
+
+        Self::env().emit_event(DepositClaimable {
+            controller: *controller,
+            assets: *assets,
+            shares,
+        });
+        Ok(shares)
+    }
+
+    fn _fulfill_redeem_default_impl(
+        &mut self,
+        controller: &AccountId,
+        shares: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        let pending = self.data().pending_redeem(controller);
+        if *shares > pending {
+            return Err(PSP22Error::InsufficientPendingRequest);
+        }
+        // the withdraw fee is charged at the fulfillment, as in `_preview_redeem`
+        let assets = self._convert_to_assets(shares, Rounding::Down)?;
+        let fee = self._fee_on_total(&assets, &self._withdraw_fee_bps())?;
+        // the Vault must hold the assets to reserve them - the ones deployed outside of it (ex. in strategies) don't count
+        if assets > self._idle_assets() {
+            return Err(PSP22Error::InsufficientBalance);
+        }
+        let assets = assets.checked_sub(fee).ok_or(MathError::Underflow)?;
+
+        self.data()
+            .set_pending_redeem(controller, &(pending - *shares));
+        self._burn_from(&Self::env().account_id(), shares)?;
+        let reserved_assets = self
+            ._reserved_assets()
+            .checked_add(assets)
+            .ok_or(MathError::Overflow)?;
+        self._set_reserved_assets(&reserved_assets);
+
+        if let Some(fee_recipient) = self._vault_fee_recipient() {
+            if fee > 0 && fee_recipient != Self::env().account_id() {
+                self._asset()
+                    .transfer(fee_recipient, fee, Vec::<u8>::new())?;
+            }
+        }
+
+        let (claimable_assets, claimable_shares) =
+            self.data().claimable_redeem(controller);
+        self.data().set_claimable_redeem(
+            controller,
+            &claimable_assets
+                .checked_add(assets)
+                .ok_or(MathError::Overflow)?,
+            &claimable_shares
+                .checked_add(*shares)
+                .ok_or(MathError::Overflow)?,
+        );
+
+        Self::env().emit_event(RedeemClaimable {
+            controller: *controller,
+            assets,
+            shares: *shares,
+        });
+        Ok(assets)
+    }
+
+    fn _claim_deposit_default_impl(
+        &mut self,
+        controller: &AccountId,
+        receiver: &AccountId,
+        assets: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        let (claimable_assets, claimable_shares) =
+            self.data().claimable_deposit(controller);
+        if *assets > claimable_assets {
+            return Err(PSP22Error::InsufficientClaimableRequest);
+        }
+        let shares = if *assets == claimable_assets {
+            claimable_shares
+        } else {
+            mul_div(
+                *assets,
+                claimable_shares,
+                claimable_assets,
+                Rounding::Down,
+            )?
+        };
+
+        self.data().set_claimable_deposit(
+            controller,
+            &(claimable_assets - *assets),
+            &(claimable_shares - shares),
+        );
+        self._transfer(&Self::env().account_id(), receiver, &shares)?;
+
+        Self::env().emit_event(Deposit {
+            sender: *controller,
+            owner: *receiver,
+            assets: *assets,
+            shares,
+        });
+        Ok(shares)
+    }
+
+    fn _claim_mint_default_impl(
+        &mut self,
+        controller: &AccountId,
+        receiver: &AccountId,
+        shares: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        let (claimable_assets, claimable_shares) =
+            self.data().claimable_deposit(controller);
+        if *shares > claimable_shares {
+            return Err(PSP22Error::InsufficientClaimableRequest);
+        }
+        let assets = if *shares == claimable_shares {
+            claimable_assets
+        } else {
+            mul_div(*shares, claimable_assets, claimable_shares, Rounding::Up)?
+        };
+
+        self.data().set_claimable_deposit(
+            controller,
+            &(claimable_assets - assets),
+            &(claimable_shares - *shares),
+        );
+        self._transfer(&Self::env().account_id(), receiver, shares)?;
+
+        Self::env().emit_event(Deposit {
+            sender: *controller,
+            owner: *receiver,
+            assets,
+            shares: *shares,
+        });
+        Ok(assets)
+    }
+
+    fn _claim_withdraw_default_impl(
+        &mut self,
+        controller: &AccountId,
+        receiver: &AccountId,
+        assets: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        let (claimable_assets, claimable_shares) =
+            self.data().claimable_redeem(controller);
+        if *assets > claimable_assets {
+            return Err(PSP22Error::InsufficientClaimableRequest);
+        }
+        let shares = if *assets == claimable_assets {
+            claimable_shares
+        } else {
+            mul_div(*assets, claimable_shares, claimable_assets, Rounding::Up)?
+        };
+
+        self.data().set_claimable_redeem(
+            controller,
+            &(claimable_assets - *assets),
+            &(claimable_shares - shares),
+        );
+        let reserved_assets = self
+            ._reserved_assets()
+            .checked_sub(*assets)
+            .ok_or(MathError::Underflow)?;
+        self._set_reserved_assets(&reserved_assets);
+        self._asset()
+            .transfer(*receiver, *assets, Vec::<u8>::new())?;
+
+        Self::env().emit_event(Withdraw {
+            sender: *controller,
+            receiver: *receiver,
+            owner: *controller,
+            assets: *assets,
+            shares,
+        });
+        Ok(shares)
+    }
+
+    fn _claim_redeem_default_impl(
+        &mut self,
+        controller: &AccountId,
+        receiver: &AccountId,
+        shares: &Balance,
+    ) -> Result<Balance, PSP22Error> {
+        let (claimable_assets, claimable_shares) =
+            self.data().claimable_redeem(controller);
+        if *shares > claimable_shares {
+            return Err(PSP22Error::InsufficientClaimableRequest);
+        }
+        let assets = if *shares == claimable_shares {
+            claimable_assets
+        } else {
+            mul_div(
+                *shares,
+                claimable_assets,
+                claimable_shares,
+                Rounding::Down,
+            )?
+        };
+
+        self.data().set_claimable_redeem(
+            controller,
+            &(claimable_assets - assets),
+            &(claimable_shares - *shares),
+        );
+        let reserved_assets = self
+            ._reserved_assets()
+            .checked_sub(assets)
+            .ok_or(MathError::Underflow)?;
+        self._set_reserved_assets(&reserved_assets);
+        self._asset()
+            .transfer(*receiver, assets, Vec::<u8>::new())?;
+
+        Self::env().emit_event(Withdraw {
+            sender: *controller,
+            receiver: *receiver,
+            owner: *controller,
+            assets,
+            shares: *shares,
+        });
+        Ok(assets)
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+/// Emitted when `owner` requests a deposit of `assets` on behalf of `controller`.
+#[ink::event]
+#[derive(Debug)]
+pub struct DepositRequest {
+    /// The account that controls the request.
+    #[ink(topic)]
+    pub controller: AccountId,
+    /// The account whose assets were locked in the request.
+    #[ink(topic)]
+    pub owner: AccountId,
+    /// The account that made the request.
+    pub sender: AccountId,
+    /// The amount of requested assets.
+    pub assets: Balance,
+}
+
+/// Emitted when `owner` requests a redeem of `shares` on behalf of `controller`.
+#[ink::event]
+#[derive(Debug)]
+pub struct RedeemRequest {
+    /// The account that controls the request.
+    #[ink(topic)]
+    pub controller: AccountId,
+    /// The account whose shares were locked in the request.
+    #[ink(topic)]
+    pub owner: AccountId,
+    /// The account that made the request.
+    pub sender: AccountId,
+    /// The amount of requested shares.
+    pub shares: Balance,
+}
+
+/// Emitted when `assets` of the `controller`'s pending deposit request become claimable as `shares`.
+#[ink::event]
+#[derive(Debug)]
+pub struct DepositClaimable {
+    /// The account that controls the request.
+    #[ink(topic)]
+    pub controller: AccountId,
+    /// The amount of fulfilled assets.
+    pub assets: Balance,
+    /// The amount of shares the assets were converted to.
+    pub shares: Balance,
+}
+
+/// Emitted when `shares` of the `controller`'s pending redeem request become claimable as `assets`.
+#[ink::event]
+#[derive(Debug)]
+pub struct RedeemClaimable {
+    /// The account that controls the request.
+    #[ink(topic)]
+    pub controller: AccountId,
+    /// The amount of assets the shares were converted to.
+    pub assets: Balance,
+    /// The amount of fulfilled shares.
+    pub shares: Balance,
+}
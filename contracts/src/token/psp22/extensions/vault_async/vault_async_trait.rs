@@ -0,0 +1,301 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22VaultAsyncRef = contract_ref!(PSP22VaultAsync, DefaultEnvironment);
+
+/// trait extending PSP22Vault with asynchronous deposit and redeem requests,
+/// based on the ERC-7540 "Asynchronous ERC-4626 Tokenized Vaults", as defined in
+/// https://eips.ethereum.org/EIPS/eip-7540.
+///
+/// Requests are aggregated per controller. A request locks the owner's assets (or shares) in the Vault
+/// and becomes pending. The vault operator fulfills pending requests with `fulfill_deposit` and `fulfill_redeem`,
+/// converting them at the price of the fulfillment, after which they are claimable by the controller.
+///
+/// For the asynchronous side the `PSP22Vault` messages claim the caller's claimable requests:
+/// `deposit` and `mint` claim the shares of a deposit request, `withdraw` and `redeem` claim the assets
+/// of a redeem request (`owner` must be the caller). `max_*` messages return the claimable amounts
+/// and `preview_*` messages MUST NOT be used.
+#[ink::trait_definition]
+pub trait PSP22VaultAsync {
+    /// Requests a deposit of `assets` on behalf of `controller`.
+    ///
+    /// Transfers `assets` from `owner` to the Vault. The caller must be the `owner`.
+    ///
+    /// On success a `DepositRequest` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidRequestCaller` if the caller is not the `owner`.
+    ///
+    /// Returns `MaxDepositExceeded` if the pending assets of `controller` would exceed the deposit caps
+    /// (`PSP22VaultInternal::_max_deposit` of `controller`). Pending requests of other controllers
+    /// are not counted against the total deposit cap until they are fulfilled.
+    ///
+    /// Returns with error if the transfer of assets fails.
+    #[ink(message)]
+    fn request_deposit(
+        &mut self,
+        assets: Balance,
+        controller: AccountId,
+        owner: AccountId,
+    ) -> Result<(), PSP22Error>;
+
+    /// Requests a redeem of `shares` on behalf of `controller`.
+    ///
+    /// Transfers `shares` from `owner` to the Vault. If the caller is not the `owner`
+    /// the caller's allowance of the `owner`'s shares is spent.
+    ///
+    /// On success a `RedeemRequest` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientAllowance` if the caller is not the `owner` and has not enough allowance.
+    ///
+    /// Returns `InsufficientBalance` if the `owner` has not enough shares.
+    #[ink(message)]
+    fn request_redeem(
+        &mut self,
+        shares: Balance,
+        controller: AccountId,
+        owner: AccountId,
+    ) -> Result<(), PSP22Error>;
+
+    /// Returns the amount of assets in the pending deposit request of `controller`.
+    #[ink(message)]
+    fn pending_deposit_request(&self, controller: AccountId) -> Balance;
+
+    /// Returns the amount of assets in the claimable deposit request of `controller`.
+    #[ink(message)]
+    fn claimable_deposit_request(&self, controller: AccountId) -> Balance;
+
+    /// Returns the amount of shares in the pending redeem request of `controller`.
+    #[ink(message)]
+    fn pending_redeem_request(&self, controller: AccountId) -> Balance;
+
+    /// Returns the amount of shares in the claimable redeem request of `controller`.
+    #[ink(message)]
+    fn claimable_redeem_request(&self, controller: AccountId) -> Balance;
+
+    /// Fulfills `assets` of the pending deposit request of `controller` - mints the shares they are worth
+    /// to the Vault, to be claimed by the `controller`.
+    ///
+    /// Returns the amount of minted shares.
+    ///
+    /// On success a `DepositClaimable` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientPendingRequest` if `assets` exceed the pending deposit request.
+    #[ink(message)]
+    fn fulfill_deposit(
+        &mut self,
+        controller: AccountId,
+        assets: Balance,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Fulfills `shares` of the pending redeem request of `controller` - burns them and reserves
+    /// the assets they are worth, to be claimed by the `controller`.
+    ///
+    /// The withdraw fee is deducted from the assets at the fulfillment and transferred to the fee recipient
+    /// (if set), the claims are not charged again.
+    ///
+    /// Returns the amount of reserved assets.
+    ///
+    /// On success a `RedeemClaimable` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientPendingRequest` if `shares` exceed the pending redeem request.
+    ///
+    /// Returns `InsufficientBalance` if the idle assets of the Vault don't cover the reserved assets.
+    /// Assets deployed outside of the Vault (ex. in strategies) have to be pulled back first.
+    #[ink(message)]
+    fn fulfill_redeem(
+        &mut self,
+        controller: AccountId,
+        shares: Balance,
+    ) -> Result<Balance, PSP22Error>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22VaultAsyncInternal and PSP22VaultAsync implementation can be derived.
+pub trait PSP22VaultAsyncStorage {
+    /// Returns the amount of assets in the pending deposit request of `controller`.
+    fn pending_deposit(&self, controller: &AccountId) -> Balance;
+
+    /// Sets the amount of assets in the pending deposit request of `controller`.
+    fn set_pending_deposit(&mut self, controller: &AccountId, assets: &Balance);
+
+    /// Returns the amounts of assets and shares in the claimable deposit request of `controller`.
+    fn claimable_deposit(&self, controller: &AccountId) -> (Balance, Balance);
+
+    /// Sets the amounts of assets and shares in the claimable deposit request of `controller`.
+    fn set_claimable_deposit(
+        &mut self,
+        controller: &AccountId,
+        assets: &Balance,
+        shares: &Balance,
+    );
+
+    /// Returns the amount of shares in the pending redeem request of `controller`.
+    fn pending_redeem(&self, controller: &AccountId) -> Balance;
+
+    /// Sets the amount of shares in the pending redeem request of `controller`.
+    fn set_pending_redeem(&mut self, controller: &AccountId, shares: &Balance);
+
+    /// Returns the amounts of assets and shares in the claimable redeem request of `controller`.
+    fn claimable_redeem(&self, controller: &AccountId) -> (Balance, Balance);
+
+    /// Sets the amounts of assets and shares in the claimable redeem request of `controller`.
+    fn set_claimable_redeem(
+        &mut self,
+        controller: &AccountId,
+        assets: &Balance,
+        shares: &Balance,
+    );
+}
+
+/// trait that is derived by Pendzl PSP22VaultAsync implementation macro assuming StorageFieldGetter<PSP22VaultAsyncStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22VaultAsyncInternal {
+    /// Returns true if deposits are asynchronous - `deposit` and `mint` claim deposit requests.
+    ///
+    /// - Override to return false to keep synchronous deposits.
+    fn _is_async_deposit(&self) -> bool;
+
+    /// Returns true if redeems are asynchronous - `withdraw` and `redeem` claim redeem requests.
+    ///
+    /// - Override to return false to keep synchronous redeems.
+    fn _is_async_redeem(&self) -> bool;
+
+    /// doc @ PSP22VaultAsync::pending_deposit_request
+    fn _pending_deposit_request(&self, controller: &AccountId) -> Balance;
+
+    /// Returns the amounts of assets and shares in the claimable deposit request of `controller`.
+    fn _claimable_deposit_request(
+        &self,
+        controller: &AccountId,
+    ) -> (Balance, Balance);
+
+    /// doc @ PSP22VaultAsync::pending_redeem_request
+    fn _pending_redeem_request(&self, controller: &AccountId) -> Balance;
+
+    /// Returns the amounts of assets and shares in the claimable redeem request of `controller`.
+    fn _claimable_redeem_request(
+        &self,
+        controller: &AccountId,
+    ) -> (Balance, Balance);
+
+    /// Transfers `assets` from `owner` to the Vault and adds them to the pending deposit request of `controller`.
+    ///
+    /// The assets are reserved (excluded from `_total_assets`) until the request is fulfilled.
+    ///
+    /// On success emits `DepositRequest` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidRequestCaller` if `sender` is not the `owner`.
+    ///
+    /// Returns with error if the transfer of assets fails.
+    fn _request_deposit(
+        &mut self,
+        sender: &AccountId,
+        assets: &Balance,
+        controller: &AccountId,
+        owner: &AccountId,
+    ) -> Result<(), PSP22Error>;
+
+    /// Transfers `shares` from `owner` to the Vault and adds them to the pending redeem request of `controller`.
+    ///
+    /// If `sender` is not the `owner` spends the `sender`'s allowance of the `owner`'s shares.
+    ///
+    /// On success emits `RedeemRequest` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientAllowance` if `sender` has not enough allowance.
+    ///
+    /// Returns `InsufficientBalance` if `owner` has not enough shares.
+    fn _request_redeem(
+        &mut self,
+        sender: &AccountId,
+        shares: &Balance,
+        controller: &AccountId,
+        owner: &AccountId,
+    ) -> Result<(), PSP22Error>;
+
+    /// doc @ PSP22VaultAsync::fulfill_deposit
+    fn _fulfill_deposit(
+        &mut self,
+        controller: &AccountId,
+        assets: &Balance,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// doc @ PSP22VaultAsync::fulfill_redeem
+    fn _fulfill_redeem(
+        &mut self,
+        controller: &AccountId,
+        shares: &Balance,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Claims `assets` of the claimable deposit request of `controller` - transfers the corresponding
+    /// shares (rounded down) to `receiver`.
+    ///
+    /// Returns the amount of transferred shares. On success emits `Deposit` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientClaimableRequest` if `assets` exceed the claimable deposit request.
+    fn _claim_deposit(
+        &mut self,
+        controller: &AccountId,
+        receiver: &AccountId,
+        assets: &Balance,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Claims `shares` of the claimable deposit request of `controller` - transfers them to `receiver`.
+    ///
+    /// Returns the amount of claimed assets (rounded up). On success emits `Deposit` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientClaimableRequest` if `shares` exceed the claimable deposit request.
+    fn _claim_mint(
+        &mut self,
+        controller: &AccountId,
+        receiver: &AccountId,
+        shares: &Balance,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Claims `assets` of the claimable redeem request of `controller` - transfers them to `receiver`.
+    ///
+    /// Returns the amount of claimed shares (rounded up). On success emits `Withdraw` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientClaimableRequest` if `assets` exceed the claimable redeem request.
+    fn _claim_withdraw(
+        &mut self,
+        controller: &AccountId,
+        receiver: &AccountId,
+        assets: &Balance,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Claims `shares` of the claimable redeem request of `controller` - transfers the corresponding
+    /// assets (rounded down) to `receiver`.
+    ///
+    /// Returns the amount of transferred assets. On success emits `Withdraw` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientClaimableRequest` if `shares` exceed the claimable redeem request.
+    fn _claim_redeem(
+        &mut self,
+        controller: &AccountId,
+        receiver: &AccountId,
+        shares: &Balance,
+    ) -> Result<Balance, PSP22Error>;
+}
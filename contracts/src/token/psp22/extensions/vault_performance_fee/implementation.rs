@@ -0,0 +1,192 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::primitives::AccountId;
+use pendzl::{
+    math::{
+        errors::MathError,
+        operations::{mul_div, Rounding},
+    },
+    traits::{Balance, StorageFieldGetter},
+};
+
+use super::{
+    PSP22VaultPerformanceFeeInternal, PSP22VaultPerformanceFeeStorage,
+    PerformanceFeeTaken, PerformanceFeeUpdated, SHARE_PRICE_PRECISION,
+};
+use crate::token::psp22::{
+    vault::{PSP22VaultInternal, VAULT_FEE_DENOMINATOR},
+    PSP22Error, PSP22Internal,
+};
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22VaultPerformanceFeeData {
+    #[lazy]
+    pub high_water_mark: u128,
+    #[lazy]
+    pub fee_bps: u16,
+    #[lazy]
+    pub fee_recipient: Option<AccountId>,
+}
+
+impl PSP22VaultPerformanceFeeStorage for PSP22VaultPerformanceFeeData {
+    fn high_water_mark(&self) -> u128 {
+        self.high_water_mark.get().unwrap_or(SHARE_PRICE_PRECISION)
+    }
+
+    fn set_high_water_mark(&mut self, high_water_mark: &u128) {
+        self.high_water_mark.set(high_water_mark);
+    }
+
+    fn fee_bps(&self) -> u16 {
+        self.fee_bps.get_or_default()
+    }
+
+    fn fee_recipient(&self) -> Option<AccountId> {
+        self.fee_recipient.get_or_default()
+    }
+
+    fn set_fee(&mut self, fee_bps: &u16, fee_recipient: &Option<AccountId>) {
+        self.fee_bps.set(fee_bps);
+        self.fee_recipient.set(fee_recipient);
+    }
+}
+
+pub trait PSP22VaultPerformanceFeeDefaultImpl:
+    PSP22VaultPerformanceFeeInternal
+{
+    fn harvest_default_impl(&mut self) -> Result<Balance, PSP22Error> {
+        self._harvest()
+    }
+
+    fn share_price_default_impl(&self) -> Result<u128, MathError> {
+        self._share_price()
+    }
+
+    fn high_water_mark_default_impl(&self) -> u128 {
+        self._high_water_mark()
+    }
+
+    fn performance_fee_default_impl(&self) -> u16 {
+        self._performance_fee()
+    }
+
+    fn performance_fee_recipient_default_impl(&self) -> Option<AccountId> {
+        self._performance_fee_recipient()
+    }
+}
+
+pub trait PSP22VaultPerformanceFeeInternalDefaultImpl:
+    StorageFieldGetter<PSP22VaultPerformanceFeeData>
+    + PSP22Internal
+    + PSP22VaultInternal
+    + PSP22VaultPerformanceFeeInternal
+where
+    PSP22VaultPerformanceFeeData: PSP22VaultPerformanceFeeStorage,
+{
+    fn _share_price_default_impl(&self) -> Result<u128, MathError> {
+        let decimals_offset = 10_u128
+            .checked_pow(self._decimals_offset() as u32)
+            .ok_or(MathError::Overflow)?;
+        let price_unit = SHARE_PRICE_PRECISION
+            .checked_mul(decimals_offset)
+            .ok_or(MathError::Overflow)?;
+        self._convert_to_assets(&price_unit, Rounding::Down)
+    }
+
+    fn _high_water_mark_default_impl(&self) -> u128 {
+        self.data().high_water_mark()
+    }
+
+    fn _performance_fee_default_impl(&self) -> u16 {
+        self.data().fee_bps()
+    }
+
+    fn _performance_fee_recipient_default_impl(&self) -> Option<AccountId> {
+        self.data().fee_recipient()
+    }
+
+    fn _set_performance_fee_default_impl(
+        &mut self,
+        fee_bps: &u16,
+        fee_recipient: &Option<AccountId>,
+    ) -> Result<(), PSP22Error> {
+        if *fee_bps > VAULT_FEE_DENOMINATOR {
+            return Err(PSP22Error::InvalidFee);
+        }
+        self.data().set_fee(fee_bps, fee_recipient);
+        Self::env().emit_event(PerformanceFeeUpdated {
+            fee_bps: *fee_bps,
+            recipient: *fee_recipient,
+        });
+        Ok(())
+    }
+
+    fn _harvest_default_impl(&mut self) -> Result<Balance, PSP22Error> {
+        let price = self._share_price()?;
+        let high_water_mark = self._high_water_mark();
+        if price <= high_water_mark {
+            return Ok(0);
+        }
+
+        let mut taken = None;
+        if let Some(recipient) = self._performance_fee_recipient() {
+            let decimals_offset = 10_u128
+                .checked_pow(self._decimals_offset() as u32)
+                .ok_or(MathError::Overflow)?;
+            let price_unit = SHARE_PRICE_PRECISION
+                .checked_mul(decimals_offset)
+                .ok_or(MathError::Overflow)?;
+            let total_supply = self._total_supply();
+
+            let profit = mul_div(
+                price - high_water_mark,
+                total_supply,
+                price_unit,
+                Rounding::Down,
+            )?;
+            let fee_assets = mul_div(
+                profit,
+                self._performance_fee() as u128,
+                VAULT_FEE_DENOMINATOR as u128,
+                Rounding::Down,
+            )?;
+            // shares that are worth `fee_assets` after they are minted
+            let shares = if fee_assets > 0 {
+                mul_div(
+                    fee_assets,
+                    total_supply
+                        .checked_add(decimals_offset)
+                        .ok_or(MathError::Overflow)?,
+                    self._total_assets()
+                        .checked_add(1)
+                        .ok_or(MathError::Overflow)?
+                        .checked_sub(fee_assets)
+                        .ok_or(MathError::Underflow)?,
+                    Rounding::Down,
+                )?
+            } else {
+                0
+            };
+            if shares > 0 {
+                self._mint_to(&recipient, &shares)?;
+                taken = Some((recipient, profit, fee_assets, shares));
+            }
+        }
+
+        let new_high_water_mark = self._share_price()?;
+        self.data().set_high_water_mark(&new_high_water_mark);
+
+        let Some((recipient, profit, fee_assets, shares)) = taken else {
+            return Ok(0);
+        };
+        Self::env().emit_event(PerformanceFeeTaken {
+            recipient,
+            profit,
+            fee_assets,
+            shares,
+            high_water_mark: new_high_water_mark,
+        });
+        Ok(shares)
+    }
+}
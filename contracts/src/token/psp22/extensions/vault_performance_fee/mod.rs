@@ -0,0 +1,14 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+pub use crate::token::psp22::PSP22Error;
+pub use ink::primitives::AccountId;
+pub use pendzl::{math::errors::MathError, traits::Balance};
+
+include!("vault_performance_fee_events.rs");
+include!("vault_performance_fee_trait.rs");
+
+#[cfg(feature = "psp22_vault_performance_fee_impl")]
+mod implementation;
+
+#[cfg(feature = "psp22_vault_performance_fee_impl")]
+pub use implementation::*;
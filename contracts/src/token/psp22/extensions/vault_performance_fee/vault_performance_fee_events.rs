@@ -0,0 +1,30 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+/// Emitted when `harvest` mints `shares` of performance fee to the `recipient`.
+#[ink::event]
+#[derive(Debug)]
+pub struct PerformanceFeeTaken {
+    /// The account that received the fee shares.
+    #[ink(topic)]
+    pub recipient: AccountId,
+    /// The profit (in assets) above the high-water mark.
+    pub profit: Balance,
+    /// The part of the profit (in assets) taken as the fee.
+    pub fee_assets: Balance,
+    /// The amount of shares minted to the `recipient`.
+    pub shares: Balance,
+    /// The new high-water mark share price.
+    pub high_water_mark: u128,
+}
+
+/// Emitted when the performance fee or its recipient is changed.
+#[ink::event]
+#[derive(Debug)]
+pub struct PerformanceFeeUpdated {
+    /// The new performance fee in basis points.
+    pub fee_bps: u16,
+    /// The new fee recipient.
+    #[ink(topic)]
+    pub recipient: Option<AccountId>,
+}
@@ -0,0 +1,112 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use ink::{contract_ref, env::DefaultEnvironment};
+
+/// Precision of the share price - the price is the amount of assets worth
+/// `SHARE_PRICE_PRECISION * 10^decimals_offset` shares.
+pub const SHARE_PRICE_PRECISION: u128 = 1_000_000_000_000_000_000;
+
+pub type PSP22VaultPerformanceFeeRef =
+    contract_ref!(PSP22VaultPerformanceFee, DefaultEnvironment);
+
+/// trait extending PSP22Vault with a performance fee charged on the profit above a high-water mark.
+///
+/// The vault records the highest share price at which the fee was settled (the high-water mark).
+/// Each `harvest` compares the current share price with the mark and mints the fee recipient
+/// shares worth `performance_fee` of the profit above it, so the fee is charged only once on each gain
+/// and never on recovering previous losses.
+#[ink::trait_definition]
+pub trait PSP22VaultPerformanceFee {
+    /// Settles the performance fee on the profit made since the last harvest.
+    ///
+    /// If the share price is above the high-water mark mints fee shares to the fee recipient
+    /// and moves the high-water mark to the resulting share price.
+    /// Anyone can call it. Returns the amount of minted fee shares.
+    ///
+    /// On success a `PerformanceFeeTaken` event is emitted (if any shares were minted).
+    ///
+    /// # Errors
+    ///
+    /// Returns with error if the share price calculation overflows.
+    #[ink(message)]
+    fn harvest(&mut self) -> Result<Balance, PSP22Error>;
+
+    /// Returns the current share price (see `SHARE_PRICE_PRECISION`).
+    #[ink(message)]
+    fn share_price(&self) -> Result<u128, MathError>;
+
+    /// Returns the high-water mark share price.
+    #[ink(message)]
+    fn high_water_mark(&self) -> u128;
+
+    /// Returns the performance fee in basis points.
+    #[ink(message)]
+    fn performance_fee(&self) -> u16;
+
+    /// Returns the recipient of the performance fee, if any.
+    #[ink(message)]
+    fn performance_fee_recipient(&self) -> Option<AccountId>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22VaultPerformanceFeeInternal and PSP22VaultPerformanceFee implementation can be derived.
+pub trait PSP22VaultPerformanceFeeStorage {
+    /// Returns the high-water mark share price (`SHARE_PRICE_PRECISION` if it was never set).
+    fn high_water_mark(&self) -> u128;
+
+    /// Sets the high-water mark share price.
+    fn set_high_water_mark(&mut self, high_water_mark: &u128);
+
+    /// Returns the performance fee in basis points.
+    fn fee_bps(&self) -> u16;
+
+    /// Returns the recipient of the performance fee.
+    fn fee_recipient(&self) -> Option<AccountId>;
+
+    /// Sets the performance fee and its recipient.
+    fn set_fee(&mut self, fee_bps: &u16, fee_recipient: &Option<AccountId>);
+}
+
+/// trait that is derived by Pendzl PSP22VaultPerformanceFee implementation macro assuming StorageFieldGetter<PSP22VaultPerformanceFeeStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22VaultPerformanceFeeInternal {
+    /// Returns the amount of assets worth `SHARE_PRICE_PRECISION * 10^decimals_offset` shares.
+    fn _share_price(&self) -> Result<u128, MathError>;
+
+    /// Returns the high-water mark share price.
+    fn _high_water_mark(&self) -> u128;
+
+    /// Returns the performance fee in basis points.
+    fn _performance_fee(&self) -> u16;
+
+    /// Returns the recipient of the performance fee.
+    fn _performance_fee_recipient(&self) -> Option<AccountId>;
+
+    /// Sets the performance fee (in basis points) and its recipient.
+    ///
+    /// The profit made before the change is not affected - consider calling `_harvest` first.
+    ///
+    /// On success emits a `PerformanceFeeUpdated` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidFee` if `fee_bps` exceeds `VAULT_FEE_DENOMINATOR`.
+    fn _set_performance_fee(
+        &mut self,
+        fee_bps: &u16,
+        fee_recipient: &Option<AccountId>,
+    ) -> Result<(), PSP22Error>;
+
+    /// Mints the fee recipient shares worth the performance fee of the profit above
+    /// the high-water mark and moves the mark to the resulting share price.
+    ///
+    /// Returns the amount of minted shares.
+    /// On success emits a `PerformanceFeeTaken` event (if any shares were minted).
+    ///
+    /// # Errors
+    ///
+    /// Returns with error if the share price calculation or minting fails.
+    fn _harvest(&mut self) -> Result<Balance, PSP22Error>;
+}
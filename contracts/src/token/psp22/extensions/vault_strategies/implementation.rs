@@ -0,0 +1,267 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{prelude::vec::Vec, primitives::AccountId};
+use pendzl::{
+    math::operations::{mul_div, Rounding},
+    traits::{Balance, StorageFieldGetter},
+};
+
+use super::{
+    PSP22VaultStrategiesInternal, PSP22VaultStrategiesStorage, Strategy,
+    StrategyAdded, StrategyAllocated, StrategyDeallocated, StrategyRemoved,
+    StrategyWeightUpdated, VaultStrategy, VaultStrategyRef,
+    STRATEGY_WEIGHT_DENOMINATOR,
+};
+use crate::token::psp22::{vault::PSP22VaultInternal, PSP22Error, PSP22};
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22VaultStrategiesData {
+    #[lazy]
+    pub strategies: Vec<Strategy>,
+}
+
+impl PSP22VaultStrategiesStorage for PSP22VaultStrategiesData {
+    fn strategies(&self) -> Vec<Strategy> {
+        self.strategies.get_or_default()
+    }
+
+    fn set_strategies(&mut self, strategies: &[Strategy]) {
+        self.strategies.set(&strategies.to_vec());
+    }
+}
+
+pub trait PSP22VaultStrategiesDefaultImpl:
+    PSP22VaultStrategiesInternal + PSP22VaultInternal
+{
+    fn strategies_default_impl(&self) -> Vec<Strategy> {
+        self._strategies()
+    }
+
+    fn idle_assets_default_impl(&self) -> Balance {
+        self._idle_assets()
+    }
+
+    fn deployed_assets_default_impl(&self) -> Balance {
+        self._deployed_assets()
+    }
+
+    fn add_strategy_default_impl(
+        &mut self,
+        strategy: AccountId,
+        weight: u16,
+    ) -> Result<(), PSP22Error> {
+        self._add_strategy(&strategy, &weight)
+    }
+
+    fn remove_strategy_default_impl(
+        &mut self,
+        strategy: AccountId,
+    ) -> Result<(), PSP22Error> {
+        self._remove_strategy(&strategy)
+    }
+
+    fn force_remove_strategy_default_impl(
+        &mut self,
+        strategy: AccountId,
+    ) -> Result<(), PSP22Error> {
+        self._force_remove_strategy(&strategy)
+    }
+
+    fn set_strategy_weight_default_impl(
+        &mut self,
+        strategy: AccountId,
+        weight: u16,
+    ) -> Result<(), PSP22Error> {
+        self._set_strategy_weight(&strategy, &weight)
+    }
+
+    fn rebalance_default_impl(&mut self) -> Result<(), PSP22Error> {
+        self._rebalance()
+    }
+}
+
+pub trait PSP22VaultStrategiesInternalDefaultImpl:
+    StorageFieldGetter<PSP22VaultStrategiesData>
+    + PSP22VaultInternal
+    + PSP22VaultStrategiesInternal
+where
+    PSP22VaultStrategiesData: PSP22VaultStrategiesStorage,
+{
+    fn _strategies_default_impl(&self) -> Vec<Strategy> {
+        self.data().strategies()
+    }
+
+    fn _strategy_assets_default_impl(&self, strategy: &AccountId) -> Balance {
+        let strategy: VaultStrategyRef = (*strategy).into();
+        strategy.total_assets()
+    }
+
+    fn _strategies_assets_default_impl(&self) -> Balance {
+        self._strategies().iter().fold(0, |deployed, strategy| {
+            deployed.saturating_add(self._strategy_assets(&strategy.account))
+        })
+    }
+
+    fn _add_strategy_default_impl(
+        &mut self,
+        strategy: &AccountId,
+        weight: &u16,
+    ) -> Result<(), PSP22Error> {
+        let mut strategies = self._strategies();
+        if strategies.iter().any(|s| s.account == *strategy) {
+            return Err(PSP22Error::StrategyAlreadyAdded);
+        }
+        strategies.push(Strategy {
+            account: *strategy,
+            weight: *weight,
+        });
+        ensure_weights(&strategies)?;
+        self.data().set_strategies(&strategies);
+        Self::env().emit_event(StrategyAdded {
+            strategy: *strategy,
+            weight: *weight,
+        });
+        Ok(())
+    }
+
+    fn _remove_strategy_default_impl(
+        &mut self,
+        strategy: &AccountId,
+    ) -> Result<(), PSP22Error> {
+        if !self._strategies().iter().any(|s| s.account == *strategy) {
+            return Err(PSP22Error::StrategyNotFound);
+        }
+        let assets = self._strategy_assets(strategy);
+        if assets > 0 {
+            self._deallocate(strategy, &assets)?;
+        }
+        self._force_remove_strategy(strategy)
+    }
+
+    fn _force_remove_strategy_default_impl(
+        &mut self,
+        strategy: &AccountId,
+    ) -> Result<(), PSP22Error> {
+        let mut strategies = self._strategies();
+        let index = strategies
+            .iter()
+            .position(|s| s.account == *strategy)
+            .ok_or(PSP22Error::StrategyNotFound)?;
+        strategies.remove(index);
+        self.data().set_strategies(&strategies);
+        Self::env().emit_event(StrategyRemoved {
+            strategy: *strategy,
+        });
+        Ok(())
+    }
+
+    fn _set_strategy_weight_default_impl(
+        &mut self,
+        strategy: &AccountId,
+        weight: &u16,
+    ) -> Result<(), PSP22Error> {
+        let mut strategies = self._strategies();
+        let entry = strategies
+            .iter_mut()
+            .find(|s| s.account == *strategy)
+            .ok_or(PSP22Error::StrategyNotFound)?;
+        entry.weight = *weight;
+        ensure_weights(&strategies)?;
+        self.data().set_strategies(&strategies);
+        Self::env().emit_event(StrategyWeightUpdated {
+            strategy: *strategy,
+            weight: *weight,
+        });
+        Ok(())
+    }
+
+    fn _allocate_default_impl(
+        &mut self,
+        strategy: &AccountId,
+        assets: &Balance,
+    ) -> Result<(), PSP22Error> {
+        self._asset()
+            .transfer(*strategy, *assets, Vec::<u8>::new())?;
+        let mut strategy_ref: VaultStrategyRef = (*strategy).into();
+        strategy_ref.deposit(*assets)?;
+        Self::env().emit_event(StrategyAllocated {
+            strategy: *strategy,
+            assets: *assets,
+        });
+        Ok(())
+    }
+
+    fn _deallocate_default_impl(
+        &mut self,
+        strategy: &AccountId,
+        assets: &Balance,
+    ) -> Result<(), PSP22Error> {
+        let mut strategy_ref: VaultStrategyRef = (*strategy).into();
+        strategy_ref.withdraw(*assets)?;
+        Self::env().emit_event(StrategyDeallocated {
+            strategy: *strategy,
+            assets: *assets,
+        });
+        Ok(())
+    }
+
+    fn _rebalance_default_impl(&mut self) -> Result<(), PSP22Error> {
+        let total_assets = self._total_assets();
+        let mut deficits = Vec::new();
+        for strategy in self._strategies() {
+            let target = mul_div(
+                total_assets,
+                strategy.weight as u128,
+                STRATEGY_WEIGHT_DENOMINATOR as u128,
+                Rounding::Down,
+            )?;
+            let current = self._strategy_assets(&strategy.account);
+            if current > target {
+                self._deallocate(&strategy.account, &(current - target))?;
+            } else if current < target {
+                deficits.push((strategy.account, target - current));
+            }
+        }
+        // deployed after all of the excess assets are back in the Vault
+        for (strategy, deficit) in deficits {
+            let assets = deficit.min(self._idle_assets());
+            if assets > 0 {
+                self._allocate(&strategy, &assets)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn _ensure_idle_assets_default_impl(
+        &mut self,
+        assets: &Balance,
+    ) -> Result<(), PSP22Error> {
+        let mut idle = self._idle_assets();
+        if idle >= *assets {
+            return Ok(());
+        }
+        for strategy in self._strategies() {
+            let pulled =
+                (*assets - idle).min(self._strategy_assets(&strategy.account));
+            if pulled > 0 {
+                self._deallocate(&strategy.account, &pulled)?;
+                idle = self._idle_assets();
+            }
+            if idle >= *assets {
+                return Ok(());
+            }
+        }
+        Err(PSP22Error::InsufficientBalance)
+    }
+}
+
+fn ensure_weights(strategies: &[Strategy]) -> Result<(), PSP22Error> {
+    let total_weight = strategies
+        .iter()
+        .fold(0_u32, |total, s| total + s.weight as u32);
+    if total_weight > STRATEGY_WEIGHT_DENOMINATOR as u32 {
+        return Err(PSP22Error::InvalidStrategyWeight);
+    }
+    Ok(())
+}
@@ -0,0 +1,15 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+pub use crate::token::psp22::PSP22Error;
+pub use ink::{prelude::vec::Vec, primitives::AccountId};
+pub use pendzl::traits::Balance;
+
+include!("vault_strategies_types.rs");
+include!("vault_strategies_events.rs");
+include!("vault_strategies_trait.rs");
+
+#[cfg(feature = "psp22_vault_strategies_impl")]
+mod implementation;
+
+#[cfg(feature = "psp22_vault_strategies_impl")]
+pub use implementation::*;
@@ -0,0 +1,46 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+/// Emitted when a `strategy` is added to the Vault.
+#[ink::event]
+#[derive(Debug)]
+pub struct StrategyAdded {
+    #[ink(topic)]
+    pub strategy: AccountId,
+    pub weight: u16,
+}
+
+/// Emitted when a `strategy` is removed from the Vault.
+#[ink::event]
+#[derive(Debug)]
+pub struct StrategyRemoved {
+    #[ink(topic)]
+    pub strategy: AccountId,
+}
+
+/// Emitted when the allocation weight of a `strategy` is changed.
+#[ink::event]
+#[derive(Debug)]
+pub struct StrategyWeightUpdated {
+    #[ink(topic)]
+    pub strategy: AccountId,
+    pub weight: u16,
+}
+
+/// Emitted when `assets` are moved from the Vault to a `strategy`.
+#[ink::event]
+#[derive(Debug)]
+pub struct StrategyAllocated {
+    #[ink(topic)]
+    pub strategy: AccountId,
+    pub assets: Balance,
+}
+
+/// Emitted when `assets` are moved from a `strategy` back to the Vault.
+#[ink::event]
+#[derive(Debug)]
+pub struct StrategyDeallocated {
+    #[ink(topic)]
+    pub strategy: AccountId,
+    pub assets: Balance,
+}
@@ -0,0 +1,249 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use ink::{contract_ref, env::DefaultEnvironment};
+
+/// Denominator of the strategy allocation weights - the weight of 10 000 allocates all of the Vault total assets.
+pub const STRATEGY_WEIGHT_DENOMINATOR: u16 = 10_000;
+
+pub type VaultStrategyRef = contract_ref!(VaultStrategy, DefaultEnvironment);
+
+pub type PSP22VaultStrategiesRef =
+    contract_ref!(PSP22VaultStrategies, DefaultEnvironment);
+
+/// trait that must be implemented by a strategy contract the Vault deploys its assets to.
+///
+/// The strategy manages the assets on behalf of the calling Vault.
+///
+/// The Vault trusts its strategies: `total_assets` is self-reported and counted in `total_assets` of the Vault,
+/// so it sets the price of the Vault shares. A strategy over-reporting its assets lets the shares be redeemed
+/// for more than they are worth, a strategy failing to `withdraw` blocks its removal and the withdrawals relying on it.
+/// Only strategies trusted by the Vault owner should be added - a broken one can be dropped with `force_remove_strategy`.
+#[ink::trait_definition]
+pub trait VaultStrategy {
+    /// Invests `assets` the Vault has just transferred to the strategy.
+    ///
+    /// # Errors
+    ///
+    /// Returns with error if the assets can't be invested.
+    #[ink(message)]
+    fn deposit(&mut self, assets: Balance) -> Result<(), PSP22Error>;
+
+    /// Transfers `assets` back to the Vault (the caller).
+    ///
+    /// # Errors
+    ///
+    /// Returns with error if the strategy can't free `assets`.
+    #[ink(message)]
+    fn withdraw(&mut self, assets: Balance) -> Result<(), PSP22Error>;
+
+    /// Returns the amount of assets managed by the strategy on behalf of the Vault, including the yield.
+    ///
+    /// - MUST NOT revert.
+    #[ink(message)]
+    fn total_assets(&self) -> Balance;
+}
+
+/// trait extending PSP22Vault with deploying the idle assets to strategy contracts.
+///
+/// Each strategy has an allocation weight - the target share of the Vault total assets deployed to it.
+/// The weights sum up to at most `STRATEGY_WEIGHT_DENOMINATOR`, the rest of the assets stays idle.
+/// `total_assets` of the Vault includes the assets deployed to the strategies and
+/// withdrawals pull the missing assets from the strategies if the idle assets are short.
+#[ink::trait_definition]
+pub trait PSP22VaultStrategies {
+    /// Returns the registered strategies with their allocation weights.
+    #[ink(message)]
+    fn strategies(&self) -> Vec<Strategy>;
+
+    /// Returns the amount of assets held by the Vault that are not deployed to strategies.
+    #[ink(message)]
+    fn idle_assets(&self) -> Balance;
+
+    /// Returns the amount of assets deployed to the strategies.
+    #[ink(message)]
+    fn deployed_assets(&self) -> Balance;
+
+    /// Registers `strategy` with allocation `weight`. The assets are moved on `rebalance`.
+    ///
+    /// On success a `StrategyAdded` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyAlreadyAdded` if the strategy is already registered.
+    /// Returns `InvalidStrategyWeight` if the weights would sum up to more than `STRATEGY_WEIGHT_DENOMINATOR`.
+    #[ink(message)]
+    fn add_strategy(
+        &mut self,
+        strategy: AccountId,
+        weight: u16,
+    ) -> Result<(), PSP22Error>;
+
+    /// Withdraws all assets from `strategy` and removes it.
+    ///
+    /// On success a `StrategyRemoved` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyNotFound` if the strategy is not registered.
+    /// Returns with error if the strategy fails to withdraw the assets.
+    #[ink(message)]
+    fn remove_strategy(&mut self, strategy: AccountId) -> Result<(), PSP22Error>;
+
+    /// Removes `strategy` without withdrawing its assets - for a strategy that fails to `withdraw`.
+    /// The assets deployed to it are no longer counted in `total_assets` of the Vault - they are written off.
+    ///
+    /// On success a `StrategyRemoved` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyNotFound` if the strategy is not registered.
+    #[ink(message)]
+    fn force_remove_strategy(
+        &mut self,
+        strategy: AccountId,
+    ) -> Result<(), PSP22Error>;
+
+    /// Sets the allocation `weight` of `strategy`. The assets are moved on `rebalance`.
+    ///
+    /// On success a `StrategyWeightUpdated` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyNotFound` if the strategy is not registered.
+    /// Returns `InvalidStrategyWeight` if the weights would sum up to more than `STRATEGY_WEIGHT_DENOMINATOR`.
+    #[ink(message)]
+    fn set_strategy_weight(
+        &mut self,
+        strategy: AccountId,
+        weight: u16,
+    ) -> Result<(), PSP22Error>;
+
+    /// Moves the assets between the Vault and the strategies to match the allocation weights.
+    ///
+    /// On success `StrategyDeallocated` and `StrategyAllocated` events are emitted for each move.
+    ///
+    /// # Errors
+    ///
+    /// Returns with error if any of the strategies fails to deposit or withdraw.
+    #[ink(message)]
+    fn rebalance(&mut self) -> Result<(), PSP22Error>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22VaultStrategiesInternal and PSP22VaultStrategies implementation can be derived.
+pub trait PSP22VaultStrategiesStorage {
+    /// Returns the registered strategies.
+    fn strategies(&self) -> Vec<Strategy>;
+
+    /// Sets the registered strategies.
+    fn set_strategies(&mut self, strategies: &[Strategy]);
+}
+
+/// trait that is derived by Pendzl PSP22VaultStrategies implementation macro assuming StorageFieldGetter<PSP22VaultStrategiesStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22VaultStrategiesInternal {
+    /// Returns the registered strategies with their allocation weights.
+    fn _strategies(&self) -> Vec<Strategy>;
+
+    /// Returns the amount of assets managed by `strategy` on behalf of the Vault.
+    fn _strategy_assets(&self, strategy: &AccountId) -> Balance;
+
+    /// Returns the amount of assets deployed to all of the strategies.
+    fn _strategies_assets(&self) -> Balance;
+
+    /// Registers `strategy` with allocation `weight`.
+    ///
+    /// On success emits a `StrategyAdded` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyAlreadyAdded` if the strategy is already registered.
+    /// Returns `InvalidStrategyWeight` if the weights would sum up to more than `STRATEGY_WEIGHT_DENOMINATOR`.
+    fn _add_strategy(
+        &mut self,
+        strategy: &AccountId,
+        weight: &u16,
+    ) -> Result<(), PSP22Error>;
+
+    /// Withdraws all assets from `strategy` and removes it.
+    ///
+    /// On success emits a `StrategyRemoved` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyNotFound` if the strategy is not registered.
+    /// Returns with error if the strategy fails to withdraw the assets.
+    fn _remove_strategy(&mut self, strategy: &AccountId) -> Result<(), PSP22Error>;
+
+    /// Removes `strategy` without withdrawing its assets.
+    ///
+    /// On success emits a `StrategyRemoved` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyNotFound` if the strategy is not registered.
+    fn _force_remove_strategy(
+        &mut self,
+        strategy: &AccountId,
+    ) -> Result<(), PSP22Error>;
+
+    /// Sets the allocation `weight` of `strategy`.
+    ///
+    /// On success emits a `StrategyWeightUpdated` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StrategyNotFound` if the strategy is not registered.
+    /// Returns `InvalidStrategyWeight` if the weights would sum up to more than `STRATEGY_WEIGHT_DENOMINATOR`.
+    fn _set_strategy_weight(
+        &mut self,
+        strategy: &AccountId,
+        weight: &u16,
+    ) -> Result<(), PSP22Error>;
+
+    /// Transfers `assets` to `strategy` and calls its `deposit`.
+    ///
+    /// On success emits a `StrategyAllocated` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns with error if the transfer or the strategy `deposit` fails.
+    fn _allocate(
+        &mut self,
+        strategy: &AccountId,
+        assets: &Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Calls `withdraw` of `strategy` to get `assets` back.
+    ///
+    /// On success emits a `StrategyDeallocated` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns with error if the strategy `withdraw` fails.
+    fn _deallocate(
+        &mut self,
+        strategy: &AccountId,
+        assets: &Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Moves the assets between the Vault and the strategies to match the allocation weights.
+    /// First the excess assets are withdrawn from the strategies, then the idle assets are deployed.
+    ///
+    /// # Errors
+    ///
+    /// Returns with error if any of the strategies fails to deposit or withdraw.
+    fn _rebalance(&mut self) -> Result<(), PSP22Error>;
+
+    /// Withdraws assets from the strategies (in the order of registration)
+    /// until the Vault holds at least `assets` idle assets.
+    ///
+    /// Used before each withdrawal from the Vault.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientBalance` if the strategies can't cover the missing assets.
+    fn _ensure_idle_assets(&mut self, assets: &Balance) -> Result<(), PSP22Error>;
+}
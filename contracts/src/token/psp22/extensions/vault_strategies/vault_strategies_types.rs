@@ -0,0 +1,14 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+/// Strategy contract registered in the Vault with its allocation `weight`
+/// (in basis points of the Vault total assets, see `STRATEGY_WEIGHT_DENOMINATOR`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Strategy {
+    pub account: AccountId,
+    pub weight: u16,
+}
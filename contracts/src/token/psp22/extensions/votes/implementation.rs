@@ -0,0 +1,355 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+use ink::{env::hash::Blake2x256, primitives::AccountId, storage::Mapping};
+use pendzl::{
+    math::errors::MathError,
+    traits::{Balance, DefaultEnv, StorageFieldGetter, Timestamp},
+};
+
+use super::{
+    DelegateChanged, DelegateVotesChanged, DelegationMessage,
+    PSP22VotesInternal, PSP22VotesStorage, PermitSignature, VotesCheckpoint,
+    VOTES_DOMAIN_NAME, VOTES_DOMAIN_VERSION,
+};
+use crate::token::psp22::{PSP22Error, PSP22Internal};
+
+/// Key of a vote checkpoint - the account (`None` - total supply) and the checkpoint index.
+pub type CheckpointKey = (Option<AccountId>, u32);
+
+#[derive(Default, Debug)]
+#[pendzl::storage_item]
+pub struct PSP22VotesData {
+    pub delegates: Mapping<AccountId, AccountId>,
+    pub checkpoints: Mapping<CheckpointKey, VotesCheckpoint>,
+    pub checkpoints_len: Mapping<Option<AccountId>, u32>,
+    pub nonces: Mapping<AccountId, u64>,
+}
+
+impl PSP22VotesData {
+    fn checkpoint(
+        &self,
+        account: Option<&AccountId>,
+        index: u32,
+    ) -> VotesCheckpoint {
+        self.checkpoints
+            .get((account.copied(), index))
+            .unwrap_or_default()
+    }
+
+    fn checkpoints_len(&self, account: Option<&AccountId>) -> u32 {
+        self.checkpoints_len
+            .get(account.copied())
+            .unwrap_or_default()
+    }
+}
+
+impl PSP22VotesStorage for PSP22VotesData {
+    fn delegate_of(&self, account: &AccountId) -> Option<AccountId> {
+        self.delegates.get(account)
+    }
+
+    fn set_delegate(&mut self, account: &AccountId, delegatee: &AccountId) {
+        self.delegates.insert(account, delegatee);
+    }
+
+    fn latest_votes(&self, account: Option<&AccountId>) -> Balance {
+        match self.checkpoints_len(account).checked_sub(1) {
+            Some(last) => self.checkpoint(account, last).votes,
+            None => 0,
+        }
+    }
+
+    fn votes_at(
+        &self,
+        account: Option<&AccountId>,
+        timestamp: Timestamp,
+    ) -> Balance {
+        // binary search for the first checkpoint after `timestamp`
+        let (mut low, mut high) = (0, self.checkpoints_len(account));
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.checkpoint(account, mid).timestamp > timestamp {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        match low.checked_sub(1) {
+            Some(index) => self.checkpoint(account, index).votes,
+            None => 0,
+        }
+    }
+
+    fn push_votes(
+        &mut self,
+        account: Option<&AccountId>,
+        timestamp: Timestamp,
+        votes: &Balance,
+    ) -> Result<(), PSP22Error> {
+        let len = self.checkpoints_len(account);
+        let checkpoint = VotesCheckpoint {
+            timestamp,
+            votes: *votes,
+        };
+        match len.checked_sub(1) {
+            Some(last)
+                if self.checkpoint(account, last).timestamp == timestamp =>
+            {
+                self.checkpoints
+                    .insert((account.copied(), last), &checkpoint);
+            }
+            _ => {
+                self.checkpoints
+                    .insert((account.copied(), len), &checkpoint);
+                self.checkpoints_len.insert(
+                    account.copied(),
+                    &len.checked_add(1).ok_or(MathError::Overflow)?,
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn nonce(&self, account: &AccountId) -> u64 {
+        self.nonces.get(account).unwrap_or_default()
+    }
+
+    fn use_nonce(&mut self, account: &AccountId) -> Result<u64, PSP22Error> {
+        let nonce = self.nonce(account);
+        self.nonces
+            .insert(account, &nonce.checked_add(1).ok_or(MathError::Overflow)?);
+        Ok(nonce)
+    }
+}
+
+pub trait PSP22VotesDefaultImpl: DefaultEnv + PSP22VotesInternal {
+    fn delegates_default_impl(&self, account: AccountId) -> Option<AccountId> {
+        self._delegates(&account)
+    }
+
+    fn get_votes_default_impl(&self, account: AccountId) -> Balance {
+        self._get_votes(&account)
+    }
+
+    fn get_past_votes_default_impl(
+        &self,
+        account: AccountId,
+        timestamp: Timestamp,
+    ) -> Result<Balance, PSP22Error> {
+        self._get_past_votes(&account, &timestamp)
+    }
+
+    fn get_past_total_supply_default_impl(
+        &self,
+        timestamp: Timestamp,
+    ) -> Result<Balance, PSP22Error> {
+        self._get_past_total_supply(&timestamp)
+    }
+
+    fn delegate_default_impl(
+        &mut self,
+        delegatee: AccountId,
+    ) -> Result<(), PSP22Error> {
+        self._delegate(&Self::env().caller(), &delegatee)
+    }
+
+    fn delegate_by_sig_default_impl(
+        &mut self,
+        delegator: AccountId,
+        delegatee: AccountId,
+        nonce: u64,
+        expiry: Timestamp,
+        signature: PermitSignature,
+    ) -> Result<(), PSP22Error> {
+        self._delegate_by_sig(
+            &delegator, &delegatee, &nonce, &expiry, &signature,
+        )
+    }
+
+    fn delegation_nonces_default_impl(&self, account: AccountId) -> u64 {
+        self._delegation_nonces(&account)
+    }
+
+    fn domain_separator_default_impl(&self) -> [u8; 32] {
+        self._domain_separator()
+    }
+}
+
+pub trait PSP22VotesInternalDefaultImpl:
+    StorageFieldGetter<PSP22VotesData> + PSP22Internal + PSP22VotesInternal
+where
+    PSP22VotesData: PSP22VotesStorage,
+{
+    fn _delegates_default_impl(
+        &self,
+        account: &AccountId,
+    ) -> Option<AccountId> {
+        self.data().delegate_of(account)
+    }
+
+    fn _get_votes_default_impl(&self, account: &AccountId) -> Balance {
+        self.data().latest_votes(Some(account))
+    }
+
+    fn _get_past_votes_default_impl(
+        &self,
+        account: &AccountId,
+        timestamp: &Timestamp,
+    ) -> Result<Balance, PSP22Error> {
+        if *timestamp >= Self::env().block_timestamp() {
+            return Err(PSP22Error::FutureLookup);
+        }
+        Ok(self.data().votes_at(Some(account), *timestamp))
+    }
+
+    fn _get_past_total_supply_default_impl(
+        &self,
+        timestamp: &Timestamp,
+    ) -> Result<Balance, PSP22Error> {
+        if *timestamp >= Self::env().block_timestamp() {
+            return Err(PSP22Error::FutureLookup);
+        }
+        Ok(self.data().votes_at(None, *timestamp))
+    }
+
+    fn _delegate_default_impl(
+        &mut self,
+        delegator: &AccountId,
+        delegatee: &AccountId,
+    ) -> Result<(), PSP22Error> {
+        let old_delegate = self.data().delegate_of(delegator);
+        self.data().set_delegate(delegator, delegatee);
+
+        Self::env().emit_event(DelegateChanged {
+            delegator: *delegator,
+            from_delegate: old_delegate,
+            to_delegate: Some(*delegatee),
+        });
+
+        let balance = self._balance_of(delegator);
+        self._move_delegate_votes(
+            old_delegate.as_ref(),
+            Some(delegatee),
+            &balance,
+        )
+    }
+
+    fn _delegate_by_sig_default_impl(
+        &mut self,
+        delegator: &AccountId,
+        delegatee: &AccountId,
+        nonce: &u64,
+        expiry: &Timestamp,
+        signature: &PermitSignature,
+    ) -> Result<(), PSP22Error> {
+        if Self::env().block_timestamp() > *expiry {
+            return Err(PSP22Error::PermitExpired);
+        }
+
+        let message = DelegationMessage {
+            domain_separator: self._domain_separator(),
+            delegator: *delegator,
+            delegatee: *delegatee,
+            nonce: *nonce,
+            expiry: *expiry,
+        };
+        if !signature.verify(&scale::Encode::encode(&message), delegator) {
+            return Err(PSP22Error::PermitInvalidSignature);
+        }
+        if self.data().use_nonce(delegator)? != *nonce {
+            return Err(PSP22Error::PermitInvalidSignature);
+        }
+
+        self._delegate(delegator, delegatee)
+    }
+
+    fn _delegation_nonces_default_impl(&self, account: &AccountId) -> u64 {
+        self.data().nonce(account)
+    }
+
+    fn _domain_separator_default_impl(&self) -> [u8; 32] {
+        let mut domain_separator = [0_u8; 32];
+        ink::env::hash_encoded::<Blake2x256, _>(
+            &(
+                VOTES_DOMAIN_NAME,
+                VOTES_DOMAIN_VERSION,
+                Self::env().account_id(),
+            ),
+            &mut domain_separator,
+        );
+        domain_separator
+    }
+
+    fn _transfer_voting_units_default_impl(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        if let Some(from) = from {
+            if self._balance_of(from) < *amount {
+                // let `_update` fail with `InsufficientBalance`
+                return Ok(());
+            }
+        }
+        if from.is_none() || to.is_none() {
+            let total_supply = self.data().latest_votes(None);
+            let new_total_supply = if from.is_none() {
+                total_supply
+                    .checked_add(*amount)
+                    .ok_or(MathError::Overflow)?
+            } else {
+                total_supply
+                    .checked_sub(*amount)
+                    .ok_or(MathError::Underflow)?
+            };
+            let now = Self::env().block_timestamp();
+            self.data().push_votes(None, now, &new_total_supply)?;
+        }
+
+        let from_delegate = from.and_then(|from| self.data().delegate_of(from));
+        let to_delegate = to.and_then(|to| self.data().delegate_of(to));
+        self._move_delegate_votes(
+            from_delegate.as_ref(),
+            to_delegate.as_ref(),
+            amount,
+        )
+    }
+
+    fn _move_delegate_votes_default_impl(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        if from == to || *amount == 0 {
+            return Ok(());
+        }
+        let now = Self::env().block_timestamp();
+        if let Some(from) = from {
+            let previous_votes = self.data().latest_votes(Some(from));
+            let new_votes = previous_votes
+                .checked_sub(*amount)
+                .ok_or(MathError::Underflow)?;
+            self.data().push_votes(Some(from), now, &new_votes)?;
+            Self::env().emit_event(DelegateVotesChanged {
+                delegate: *from,
+                previous_votes,
+                new_votes,
+            });
+        }
+        if let Some(to) = to {
+            let previous_votes = self.data().latest_votes(Some(to));
+            let new_votes = previous_votes
+                .checked_add(*amount)
+                .ok_or(MathError::Overflow)?;
+            self.data().push_votes(Some(to), now, &new_votes)?;
+            Self::env().emit_event(DelegateVotesChanged {
+                delegate: *to,
+                previous_votes,
+                new_votes,
+            });
+        }
+        Ok(())
+    }
+}
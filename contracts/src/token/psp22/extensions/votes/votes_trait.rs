@@ -0,0 +1,200 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22VotesRef = contract_ref!(PSP22Votes, DefaultEnvironment);
+
+/// trait extending PSP22 with delegation and checkpointed voting power.
+///
+/// Each token is one voting unit. Voting units are counted only once delegated -
+/// an account has to delegate to itself to use its own voting power.
+#[ink::trait_definition]
+pub trait PSP22Votes {
+    /// Returns the account `account` delegated its votes to.
+    #[ink(message)]
+    fn delegates(&self, account: AccountId) -> Option<AccountId>;
+
+    /// Returns the current voting power of `account`.
+    #[ink(message)]
+    fn get_votes(&self, account: AccountId) -> Balance;
+
+    /// Returns the voting power of `account` at `timestamp`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FutureLookup` if `timestamp` is not in the past.
+    #[ink(message)]
+    fn get_past_votes(
+        &self,
+        account: AccountId,
+        timestamp: Timestamp,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Returns the total supply at `timestamp`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FutureLookup` if `timestamp` is not in the past.
+    #[ink(message)]
+    fn get_past_total_supply(
+        &self,
+        timestamp: Timestamp,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Delegates the votes of the caller to `delegatee`.
+    ///
+    /// On success a `DelegateChanged` event is emitted, as well as `DelegateVotesChanged` events
+    /// for the delegates whose voting power changed.
+    #[ink(message)]
+    fn delegate(&mut self, delegatee: AccountId) -> Result<(), PSP22Error>;
+
+    /// Delegates the votes of `delegator` to `delegatee`, given `delegator`'s signature
+    /// of the corresponding `DelegationMessage`.
+    ///
+    /// On success the delegation nonce of `delegator` is increased.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PermitExpired` if `expiry` is in the past.
+    /// Returns `PermitInvalidSignature` if `signature` is not a valid signature of `delegator`
+    /// or `nonce` is not the current delegation nonce of `delegator`.
+    #[ink(message)]
+    fn delegate_by_sig(
+        &mut self,
+        delegator: AccountId,
+        delegatee: AccountId,
+        nonce: u64,
+        expiry: Timestamp,
+        signature: PermitSignature,
+    ) -> Result<(), PSP22Error>;
+
+    /// Returns the current delegation nonce of `account`. It must be included in the next `DelegationMessage`.
+    #[ink(message)]
+    fn delegation_nonces(&self, account: AccountId) -> u64;
+
+    /// Returns the domain separator that must be included in every `DelegationMessage`.
+    #[ink(message)]
+    fn domain_separator(&self) -> [u8; 32];
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// so the Pendzl PSP22VotesInternal and PSP22Votes implementation can be derived.
+///
+/// Checkpoints of `None` account track the total supply.
+pub trait PSP22VotesStorage {
+    /// Returns the delegate of `account`.
+    fn delegate_of(&self, account: &AccountId) -> Option<AccountId>;
+
+    /// Sets `delegatee` as the delegate of `account`.
+    fn set_delegate(&mut self, account: &AccountId, delegatee: &AccountId);
+
+    /// Returns the latest checkpointed votes of `account`.
+    fn latest_votes(&self, account: Option<&AccountId>) -> Balance;
+
+    /// Returns the votes of `account` at `timestamp`.
+    fn votes_at(
+        &self,
+        account: Option<&AccountId>,
+        timestamp: Timestamp,
+    ) -> Balance;
+
+    /// Records `votes` as the votes of `account` from `timestamp` on.
+    fn push_votes(
+        &mut self,
+        account: Option<&AccountId>,
+        timestamp: Timestamp,
+        votes: &Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Returns the current delegation nonce of `account`.
+    fn nonce(&self, account: &AccountId) -> u64;
+
+    /// Increases the delegation nonce of `account` by one and returns the used nonce.
+    fn use_nonce(&mut self, account: &AccountId) -> Result<u64, PSP22Error>;
+}
+
+/// trait that is derived by Pendzl PSP22Votes implementation macro assuming StorageFieldGetter<PSP22VotesStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22VotesInternal {
+    /// Returns the account `account` delegated its votes to.
+    fn _delegates(&self, account: &AccountId) -> Option<AccountId>;
+
+    /// Returns the current voting power of `account`.
+    fn _get_votes(&self, account: &AccountId) -> Balance;
+
+    /// Returns the voting power of `account` at `timestamp`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FutureLookup` if `timestamp` is not in the past.
+    fn _get_past_votes(
+        &self,
+        account: &AccountId,
+        timestamp: &Timestamp,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Returns the total supply at `timestamp`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FutureLookup` if `timestamp` is not in the past.
+    fn _get_past_total_supply(
+        &self,
+        timestamp: &Timestamp,
+    ) -> Result<Balance, PSP22Error>;
+
+    /// Delegates the votes of `delegator` to `delegatee` moving the balance of `delegator`
+    /// between the delegates.
+    ///
+    /// On success emits `DelegateChanged` event.
+    fn _delegate(
+        &mut self,
+        delegator: &AccountId,
+        delegatee: &AccountId,
+    ) -> Result<(), PSP22Error>;
+
+    /// Verifies the `signature` of `delegator` and delegates its votes to `delegatee`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PermitExpired` if `expiry` is in the past.
+    /// Returns `PermitInvalidSignature` if `signature` is not a valid signature of `delegator`
+    /// or `nonce` is not the current delegation nonce of `delegator`.
+    fn _delegate_by_sig(
+        &mut self,
+        delegator: &AccountId,
+        delegatee: &AccountId,
+        nonce: &u64,
+        expiry: &Timestamp,
+        signature: &PermitSignature,
+    ) -> Result<(), PSP22Error>;
+
+    /// Returns the current delegation nonce of `account`.
+    fn _delegation_nonces(&self, account: &AccountId) -> u64;
+
+    /// Returns the domain separator of the contract.
+    fn _domain_separator(&self) -> [u8; 32];
+
+    /// Moves the voting units of `amount` tokens transferred from `from` to `to`.
+    /// On mint and burn the total supply checkpoint is updated.
+    ///
+    /// Called by the `PSP22Internal::_update` derived by the `implementation` macro.
+    fn _transfer_voting_units(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Moves `amount` of votes from delegate `from` to delegate `to`.
+    ///
+    /// On success emits `DelegateVotesChanged` event for each of the delegates.
+    fn _move_delegate_votes(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+}
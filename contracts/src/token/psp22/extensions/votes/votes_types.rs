@@ -0,0 +1,35 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+/// Name of the domain used to compute the `PSP22Votes` domain separator.
+pub const VOTES_DOMAIN_NAME: &[u8] = b"PSP22Votes";
+/// Version of the domain used to compute the `PSP22Votes` domain separator.
+pub const VOTES_DOMAIN_VERSION: &[u8] = b"1";
+
+/// Voting power of an account (or the total supply) from `timestamp` on.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct VotesCheckpoint {
+    pub timestamp: Timestamp,
+    pub votes: Balance,
+}
+
+/// The message that is signed by the delegator to delegate its votes.
+/// The signed bytes are the SCALE encoding of this struct.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct DelegationMessage {
+    /// Domain separator of the token contract, see `PSP22Votes::domain_separator`.
+    pub domain_separator: [u8; 32],
+    /// The account delegating its votes.
+    pub delegator: AccountId,
+    /// The account receiving the votes.
+    pub delegatee: AccountId,
+    /// The current delegation nonce of the delegator, see `PSP22Votes::delegation_nonces`.
+    pub nonce: u64,
+    /// The timestamp after which the signature can not be used.
+    pub expiry: Timestamp,
+}
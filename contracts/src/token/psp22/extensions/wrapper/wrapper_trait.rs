@@ -0,0 +1,91 @@
+// Copyright (c) 2024 C Forge. All Rights Reserved.
+// SPDX-License-Identifier: MIT
+
+use ink::{contract_ref, env::DefaultEnvironment};
+
+pub type PSP22WrapperRef = contract_ref!(PSP22Wrapper, DefaultEnvironment);
+
+/// trait extending PSP22 with wrapping of an underlying PSP22 token one-to-one.
+///
+/// Useful to add features (ex. PSP22Votes or PSP22Permit) to already deployed tokens.
+#[ink::trait_definition]
+pub trait PSP22Wrapper {
+    /// Returns the address of the underlying token that is wrapped.
+    #[ink(message)]
+    fn underlying(&self) -> AccountId;
+
+    /// Transfers `amount` of underlying tokens from the caller to the contract and mints
+    /// the same `amount` of wrapped tokens to `account`.
+    ///
+    /// The caller must have approved the contract to spend at least `amount` of underlying tokens.
+    ///
+    /// On success a `Transfer` event is emitted (minting).
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidDepositRecipient` error if `account` is the wrapper contract itself.
+    ///
+    /// Returns with error if the transfer of underlying tokens fails.
+    #[ink(message)]
+    fn deposit_for(
+        &mut self,
+        account: AccountId,
+        amount: Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Burns `amount` of wrapped tokens from the caller and transfers the same `amount`
+    /// of underlying tokens to `account`.
+    ///
+    /// On success a `Transfer` event is emitted (burning).
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientBalance` error if the caller does not have enough wrapped tokens.
+    ///
+    /// Returns with error if the transfer of underlying tokens fails.
+    #[ink(message)]
+    fn withdraw_to(
+        &mut self,
+        account: AccountId,
+        amount: Balance,
+    ) -> Result<(), PSP22Error>;
+}
+
+/// trait that must be implemented by exactly one storage field of a contract storage
+/// together with PSP22Storage so the Pendzl PSP22WrapperInternal and PSP22Wrapper implementation can be derived.
+pub trait PSP22WrapperStorage {
+    /// Returns reference to the underlying token.
+    fn underlying(&self) -> PSP22Ref;
+}
+
+/// trait that is derived by Pendzl PSP22Wrapper implementation macro assuming StorageFieldGetter<PSP22WrapperStorage> is implemented
+///
+/// functions of this trait are recomended to use while writing ink::messages
+pub trait PSP22WrapperInternal {
+    /// Returns reference to the underlying token.
+    fn _underlying(&self) -> PSP22Ref;
+
+    /// Transfers `amount` of underlying tokens from `caller` to the contract and mints
+    /// the same `amount` of wrapped tokens to `account`.
+    fn _deposit_for(
+        &mut self,
+        caller: &AccountId,
+        account: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Burns `amount` of wrapped tokens from `caller` and transfers the same `amount`
+    /// of underlying tokens to `account`.
+    fn _withdraw_to(
+        &mut self,
+        caller: &AccountId,
+        account: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Mints wrapped tokens to `account` to cover the surplus of underlying tokens held by the contract
+    /// (ex. underlying tokens that were mistakenly transferred directly to the contract).
+    ///
+    /// Returns the amount of minted tokens.
+    fn _recover(&mut self, account: &AccountId) -> Result<Balance, PSP22Error>;
+}
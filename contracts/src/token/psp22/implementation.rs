@@ -2,9 +2,16 @@
 // SPDX-License-Identifier: MIT
 
 use super::{
-    Approval, Balance, PSP22Error, PSP22Internal, PSP22Storage, Transfer,
+    Approval, Balance, PSP22Error, PSP22Internal, PSP22ReceiverError,
+    PSP22Storage, Transfer,
+};
+use ink::env::call::{build_call, ExecutionInput, Selector};
+use ink::env::{CallFlags, DefaultEnvironment};
+use ink::storage::Mapping;
+use ink::{
+    prelude::{string::String, vec::Vec},
+    primitives::AccountId,
 };
-use ink::{prelude::vec::Vec, primitives::AccountId, storage::Mapping};
 use pendzl::math::errors::MathError;
 use pendzl::traits::{DefaultEnv, StorageFieldGetter};
 
@@ -133,9 +140,10 @@ pub trait PSP22DefaultImpl: DefaultEnv + PSP22Internal {
         &mut self,
         to: AccountId,
         value: Balance,
-        _data: Vec<u8>,
+        data: Vec<u8>,
     ) -> Result<(), PSP22Error> {
         let from = Self::env().caller();
+        self._do_safe_transfer_check(&from, &to, &from, &value, &data)?;
         self._update(Some(&from), Some(&to), &value)?;
         Ok(())
     }
@@ -145,10 +153,11 @@ pub trait PSP22DefaultImpl: DefaultEnv + PSP22Internal {
         from: AccountId,
         to: AccountId,
         value: Balance,
-        _data: Vec<u8>,
+        data: Vec<u8>,
     ) -> Result<(), PSP22Error> {
         let caller = Self::env().caller();
-        self._decrease_allowance_from_to(&from, &caller, &value)?;
+        self._spend_allowance(&from, &caller, &value)?;
+        self._do_safe_transfer_check(&from, &to, &caller, &value, &data)?;
         self._update(Some(&from), Some(&to), &value)?;
         Ok(())
     }
@@ -182,9 +191,10 @@ pub trait PSP22DefaultImpl: DefaultEnv + PSP22Internal {
     }
 }
 
-pub trait PSP22InternalDefaultImpl: StorageFieldGetter<PSP22Data>
-where
-    PSP22Data: PSP22Storage,
+/// Default implementation of `PSP22Internal` over the `S` storage field,
+/// ex. `PSP22RebasingData` instead of `PSP22Data` in contracts implementing `PSP22Rebasing`.
+pub trait PSP22InternalDefaultImpl<S: PSP22Storage = PSP22Data>:
+    StorageFieldGetter<S>
 {
     fn _total_supply_default_impl(&self) -> Balance {
         self.data().total_supply()
@@ -208,15 +218,21 @@ where
         to: Option<&AccountId>,
         amount: &Balance,
     ) -> Result<(), PSP22Error> {
+        // balances are updated before the total supply so storages deriving
+        // balances from the total supply (e.g. rebasing) use the current ratio.
         if let Some(from) = from {
             self.data().decrease_balance_of(from, amount)?;
-        } else {
-            self.data().increase_total_supply(amount)?;
         }
 
         if let Some(to) = to {
             self.data().increase_balance_of(to, amount)?;
-        } else {
+        }
+
+        if from.is_none() {
+            self.data().increase_total_supply(amount)?;
+        }
+
+        if to.is_none() {
             self.data().decrease_total_supply(amount)?;
         }
 
@@ -283,6 +299,15 @@ where
         });
         Ok(())
     }
+    fn _spend_allowance_default_impl(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        self._decrease_allowance_from_to_default_impl(owner, spender, amount)
+    }
+
     fn _increase_allowance_from_to_default_impl(
         &mut self,
         owner: &AccountId,
@@ -298,4 +323,42 @@ where
         });
         Ok(())
     }
+
+    fn _do_safe_transfer_check_default_impl(
+        &mut self,
+        from: &AccountId,
+        to: &AccountId,
+        operator: &AccountId,
+        value: &Balance,
+        data: &[u8],
+    ) -> Result<(), PSP22Error> {
+        if to == operator || !Self::env().is_contract(to) {
+            return Ok(());
+        }
+        let call = build_call::<DefaultEnvironment>()
+            .call(*to)
+            .call_flags(CallFlags::ALLOW_REENTRY)
+            .exec_input(
+                ExecutionInput::new(Selector::new(ink::selector_bytes!(
+                    "PSP22Receiver::before_received"
+                )))
+                .push_arg(*from)
+                .push_arg(*operator)
+                .push_arg(*value)
+                .push_arg(data.to_vec()),
+            )
+            .returns::<Result<(), PSP22ReceiverError>>();
+
+        match call.try_invoke() {
+            Ok(Ok(Ok(()))) => Ok(()),
+            // `to` doesn't implement PSP22Receiver - the transfer is accepted
+            Ok(Err(ink::LangError::CouldNotReadInput)) => Ok(()),
+            Ok(Ok(Err(PSP22ReceiverError::TransferRejected(reason)))) => {
+                Err(PSP22Error::SafeTransferCheckFailed(reason))
+            }
+            _ => Err(PSP22Error::SafeTransferCheckFailed(String::from(
+                "Error during call to receiver",
+            ))),
+        }
+    }
 }
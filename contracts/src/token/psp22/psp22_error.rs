@@ -24,6 +24,66 @@ pub enum PSP22Error {
     PermitInvalidSignature,
     /// Returned if permit deadline is expired
     PermitExpired,
+    /// Returned if minting would make the total supply exceed the cap
+    CapExceeded,
+    /// Returned if votes are queried for a timestamp that is not in the past
+    FutureLookup,
+    /// Returned if the call to the flash loan receiver failed
+    FlashBorrowerCallFailed,
+    /// Returned if the fee exceeds 100%
+    InvalidFee,
+    /// Returned if an account taking part in the operation is restricted
+    AccountRestricted,
+    /// Returned if a rebase would set the total supply to zero while there are shares or non zero without shares
+    InvalidRebase,
+    /// Returned if the operation would move locked tokens
+    InsufficientUnlockedBalance,
+    /// Returned if the lock amount is zero or its release time is not in the future
+    InvalidLock,
+    /// Returned if the account already has the maximum number of locks
+    TooManyLocks,
+    /// Returned if the allowance is expired or the expiry is not in the future
+    AllowanceExpired,
+    /// Returned if the caller's mint allowance is lower than the minted amount
+    MintAllowanceExceeded,
+    /// Returned if the transfer of the native currency fails
+    NativeTransferFailed,
+    /// Returned if the native currency transferred with the call differs from the expected amount
+    InvalidAmountPaid,
+    /// Returned if the operation requires a non-zero total supply (ex. distributing dividends)
+    ZeroTotalSupply,
+    /// Returned if the caller can't make or claim the vault request on behalf of the owner or controller
+    InvalidRequestCaller,
+    /// Returned if the amount exceeds the pending vault request
+    InsufficientPendingRequest,
+    /// Returned if the amount exceeds the claimable vault request
+    InsufficientClaimableRequest,
+    /// Returned if the deposited assets exceed `max_deposit` of the vault receiver
+    MaxDepositExceeded,
+    /// Returned if the minted shares exceed `max_mint` of the vault receiver
+    MaxMintExceeded,
+    /// Returned if the withdrawn assets exceed `max_withdraw` of the vault owner
+    MaxWithdrawExceeded,
+    /// Returned if the redeemed shares exceed `max_redeem` of the vault owner
+    MaxRedeemExceeded,
+    /// Returned if the strategy is already registered in the vault
+    StrategyAlreadyAdded,
+    /// Returned if the strategy is not registered in the vault
+    StrategyNotFound,
+    /// Returned if the strategy allocation weights sum up to more than 100%
+    InvalidStrategyWeight,
+    /// Returned if the flash loan amount exceeds `max_flash_loan`
+    MaxFlashLoanExceeded,
+    /// Returned if the wrapped tokens would be minted to the wrapper contract itself
+    InvalidDepositRecipient,
+}
+
+/// The PSP22Receiver error type. Returned by `PSP22Receiver::before_received` to reject a transfer.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PSP22ReceiverError {
+    /// Returned if the receiver doesn't accept the transfer.
+    TransferRejected(String),
 }
 
 /// Compatibility with pendzl::math::errors::MathError.
@@ -8,6 +8,7 @@ use ink::{
     primitives::AccountId,
 };
 pub type PSP22Ref = contract_ref!(PSP22, DefaultEnvironment);
+pub type PSP22ReceiverRef = contract_ref!(PSP22Receiver, DefaultEnvironment);
 
 pub use pendzl::traits::Balance;
 
@@ -17,8 +18,10 @@ pub use pendzl::traits::Balance;
 /// !!! Note
 /// Pendzl implementation allows to use zero address as a valid address
 /// and doen't revert ZeroAddress errors.
-/// Pendzl implementation doesn't check if the recipient is a contract
-/// and doesn't revert SafeTransferCheckFailed
+/// With the `PSP22(safe_transfer)` flag Pendzl implementation calls `PSP22Receiver::before_received`
+/// on `transfer` and `transfer_from` if the recipient is a contract (other than the caller) and reverts
+/// SafeTransferCheckFailed if the call fails or the recipient rejects the transfer.
+/// Contracts that don't implement `PSP22Receiver` (unknown selector) accept all transfers.
 #[ink::trait_definition]
 pub trait PSP22 {
     /// Returns the total token supply.
@@ -67,6 +70,7 @@ pub trait PSP22 {
     /// the the account Balance of `from`.
     /// Returns with error `ZeroSenderAddress` if sender's address is zero.
     /// Returns with error `ZeroRecipientAddress` if recipient's address is zero.
+    /// Returns with error `SafeTransferCheckFailed` if the recipient is a contract and rejected the transfer.
     #[ink(message)]
     fn transfer_from(
         &mut self,
@@ -124,6 +128,28 @@ pub trait PSP22 {
     ) -> Result<(), PSP22Error>;
 }
 
+/// trait that should be implemented by contracts that want to receive PSP22 tokens
+/// via `transfer` or `transfer_from` called by someone else.
+#[ink::trait_definition]
+pub trait PSP22Receiver {
+    /// Called by the token contract before `value` tokens are transferred from `from`
+    /// to the receiver by `operator` (the caller of `transfer` or `transfer_from`).
+    /// `data` is the additional data passed to `transfer` or `transfer_from`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TransferRejected` if the receiver doesn't want to accept the tokens.
+    /// The token contract then reverts with `SafeTransferCheckFailed`.
+    #[ink(message)]
+    fn before_received(
+        &mut self,
+        from: AccountId,
+        operator: AccountId,
+        value: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22ReceiverError>;
+}
+
 /// trait that must be implemented by exactly one storage field of a contract storage
 /// so the Pendzl PSP22Internal and PSP22 implementation can be derived.
 pub trait PSP22Storage {
@@ -271,6 +297,23 @@ pub trait PSP22Internal {
         amount: &Balance,
     ) -> Result<(), PSP22Error>;
 
+    /// Decreases an allowance of `spender` to spend tokens of `owner` by `amount` spent by `spender`,
+    /// ex. in `transfer_from`.
+    ///
+    /// On success emits `Approval` event.
+    ///
+    /// With the `PSP22(infinite_allowance)` implementation macro arg an allowance of `Balance::MAX`
+    /// is not decreased.
+    ///
+    /// # Errors
+    /// - Returns `InsufficientAllowance` if the current allowance is smaller than `amount`.
+    fn _spend_allowance(
+        &mut self,
+        owner: &AccountId,
+        spender: &AccountId,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+
     /// Increases an allowance of `spender` to spend tokens of `owner` by `amount`.
     ///
     /// On success emits `Approval` event.
@@ -280,4 +323,22 @@ pub trait PSP22Internal {
         spender: &AccountId,
         amount: &Balance,
     ) -> Result<(), PSP22Error>;
+
+    /// Calls `PSP22Receiver::before_received` on `to` if it is a contract
+    /// other than the `operator` (a contract pulling tokens to itself is assumed to handle them).
+    /// A contract that doesn't implement `PSP22Receiver` accepts the transfer.
+    ///
+    /// Called by `transfer` and `transfer_from`. The generated implementation does nothing
+    /// unless the `safe_transfer` flag is set, ex. `#[pendzl::implementation(PSP22(safe_transfer))]`.
+    ///
+    /// # Errors
+    /// Returns `SafeTransferCheckFailed` if the call fails or `to` rejects the transfer.
+    fn _do_safe_transfer_check(
+        &mut self,
+        from: &AccountId,
+        to: &AccountId,
+        operator: &AccountId,
+        value: &Balance,
+        data: &[u8],
+    ) -> Result<(), PSP22Error>;
 }
@@ -6,18 +6,22 @@
 /// Owner is allowed to mint and burn PSP22 tokens.
 // ########################################################
 // inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// PSP22Mintable and PSP22Burnable traits' default implementations (PSP22MintableDefaultImpl & PSP22BurnableDefaultImpl)
+// guarded by Ownable - `mint` and `burn` start with `_only_owner` check
 // and Ownable trait's default implementation (OwnableDefaultImpl & OwnableInternalDefaultImpl)
 // which reduces the amount of boilerplate code required to implement trait messages drastically
-#[pendzl::implementation(PSP22, Ownable)]
+#[pendzl::implementation(
+    PSP22,
+    PSP22Mintable(guard = Ownable),
+    PSP22Burnable(guard = Ownable),
+    Ownable
+)]
 #[ink::contract]
 pub mod ownable {
-    use pendzl::contracts::psp22::{
-        burnable::PSP22Burnable, mintable::PSP22Mintable,
-    };
-
+    use pendzl::contracts::psp22::*;
     #[ink(storage)]
     // derive explained below
-    #[derive(StorageFieldGetter)]
+    #[derive(Default, StorageFieldGetter)]
     pub struct Contract {
         // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
         #[storage_field]
@@ -42,35 +46,6 @@ pub mod ownable {
         }
     }
 
-    // implement PSP22Burnable for Contract
-    impl PSP22Burnable for Contract {
-        #[ink(message)]
-        fn burn(
-            &mut self,
-            account: AccountId,
-            amount: Balance,
-        ) -> Result<(), PSP22Error> {
-            // use _only_owner to ensure only the owner can burn from OwnableInternal (implemented by OwnableDefaultImpl)
-            self._only_owner()?;
-            // use _update to update the balance from PSP22Internal (implemented by PSP22InternalDefaultImpl)
-            self._update(Some(&account), None, &amount)
-        }
-    }
-
-    impl PSP22Mintable for Contract {
-        #[ink(message)]
-        fn mint(
-            &mut self,
-            account: AccountId,
-            amount: Balance,
-        ) -> Result<(), PSP22Error> {
-            // use _only_owner to ensure only the owner can burn from OwnableInternal (implemented by OwnableDefaultImpl)
-            self._only_owner()?;
-            // use _update to update the balance from PSP22Internal (implemented by PSP22InternalDefaultImpl)
-            self._update(None, Some(&account), &amount)
-        }
-    }
-
     #[cfg(all(test, feature = "e2e-tests"))]
     pub mod tests {
         #[rustfmt::skip]
@@ -6,7 +6,8 @@
 // ########################################################
 // inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
 // which reduces the amount of boilerplate code required to implement trait messages drastically
-#[pendzl::implementation(PSP22)]
+// safe_transfer calls PSP22Receiver::before_received on the recipient contracts of transfer and transfer_from
+#[pendzl::implementation(PSP22(safe_transfer))]
 #[ink::contract]
 pub mod my_psp22 {
     use ink::prelude::string::String;
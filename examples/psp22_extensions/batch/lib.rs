@@ -0,0 +1,196 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with batch extension - allows to transfer to and approve many accounts in a single call.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22Batch trait's default implementation (PSP22BatchDefaultImpl & PSP22BatchInternalDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(PSP22, PSP22Batch)]
+#[ink::contract]
+pub mod my_psp22_batch {
+    use pendzl::contracts::psp22::*;
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+
+            // mint total_supply to the caller using _mint_to from PSP22Internal (implemented by PSP22DefaultImpl)
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+
+            instance
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob, Charlie};
+        use test_helpers::balance_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn batch_transfer_works(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_batch",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let batch_transfer_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.batch_transfer(
+                        vec![
+                            (account_id(Bob), 100),
+                            (account_id(Charlie), 200),
+                        ],
+                        vec![],
+                    ),
+                )
+                .submit()
+                .await
+                .expect("batch transfer failed")
+                .return_value();
+
+            assert_eq!(batch_transfer_tx, Ok(()));
+            assert_eq!(balance_of!(client, contract, Alice), 700);
+            assert_eq!(balance_of!(client, contract, Bob), 100);
+            assert_eq!(balance_of!(client, contract, Charlie), 200);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn batch_transfer_fails_if_any_leg_fails(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_batch",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let batch_transfer_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.batch_transfer(
+                        vec![
+                            (account_id(Bob), 600),
+                            (account_id(Charlie), 600),
+                        ],
+                        vec![],
+                    ),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(batch_transfer_tx, Err(PSP22Error::InsufficientBalance));
+            assert_eq!(balance_of!(client, contract, Alice), 1000);
+            assert_eq!(balance_of!(client, contract, Bob), 0);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn batch_transfer_from_decreases_allowance_once(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_batch",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let batch_approve_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.batch_approve(vec![
+                        (account_id(Bob), 300),
+                        (account_id(Charlie), 50),
+                    ]),
+                )
+                .submit()
+                .await
+                .expect("batch approve failed")
+                .return_value();
+
+            assert_eq!(batch_approve_tx, Ok(()));
+
+            let batch_transfer_from_tx = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.batch_transfer_from(
+                        account_id(Alice),
+                        vec![
+                            (account_id(Bob), 100),
+                            (account_id(Charlie), 200),
+                        ],
+                    ),
+                )
+                .submit()
+                .await
+                .expect("batch transfer from failed")
+                .return_value();
+
+            assert_eq!(batch_transfer_from_tx, Ok(()));
+            assert_eq!(balance_of!(client, contract, Alice), 700);
+            assert_eq!(balance_of!(client, contract, Bob), 100);
+            assert_eq!(balance_of!(client, contract, Charlie), 200);
+
+            let allowance = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.allowance(account_id(Alice), account_id(Bob)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(allowance, 0);
+
+            Ok(())
+        }
+    }
+}
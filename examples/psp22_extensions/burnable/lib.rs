@@ -6,9 +6,9 @@
 /// Anyone can burn tokens from anyone's account.
 // ########################################################
 // inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
-// and PSP22Burnable trait's default implementation (PSP22BurnableDefaultImpl)
+// and PSP22Burnable trait's default implementation (PSP22BurnableDefaultImpl) callable by anyone (guard = None)
 // which reduces the amount of boilerplate code required to implement trait messages drastically
-#[pendzl::implementation(PSP22, PSP22Burnable)]
+#[pendzl::implementation(PSP22, PSP22Burnable(guard = None))]
 #[ink::contract]
 pub mod my_psp22_burnable {
     use pendzl::contracts::psp22::*;
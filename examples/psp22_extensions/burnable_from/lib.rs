@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract.
+/// Anyone can burn own tokens or tokens of others up to the allowance given by them.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// PSP22BurnableFrom trait's default implementation (PSP22BurnableFromDefaultImpl)
+// and PSP22BurnableSelf trait's default implementation (PSP22BurnableSelfDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(PSP22, PSP22BurnableFrom, PSP22BurnableSelf)]
+#[ink::contract]
+pub mod my_psp22_burnable_from {
+    use pendzl::contracts::psp22::*;
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+
+            // mint total_supply to the caller using _mint_to from PSP22Internal (implemented by PSP22DefaultImpl)
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+
+            instance
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob};
+        use test_helpers::balance_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn can_burn_own_tokens(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(100);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_burnable_from",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let result = client
+                .call(&ink_e2e::alice(), &contract.burn_self(10))
+                .submit()
+                .await?
+                .return_value();
+
+            assert_eq!(result, Ok(()));
+            assert_eq!(balance_of!(client, contract, Alice), 90);
+
+            let total_supply = client
+                .call(&ink_e2e::alice(), &contract.total_supply())
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(total_supply, 90);
+
+            let result = client
+                .call(&ink_e2e::bob(), &contract.burn_self(1))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(result, Err(PSP22Error::InsufficientBalance));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn burn_from_spends_allowance(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(100);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_burnable_from",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let result = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.burn_from(account_id(Alice), 10),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(result, Err(PSP22Error::InsufficientAllowance));
+
+            client
+                .call(&ink_e2e::alice(), &contract.approve(account_id(Bob), 30))
+                .submit()
+                .await
+                .expect("approve failed");
+
+            let result = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.burn_from(account_id(Alice), 10),
+                )
+                .submit()
+                .await?
+                .return_value();
+
+            assert_eq!(result, Ok(()));
+            assert_eq!(balance_of!(client, contract, Alice), 90);
+
+            let allowance = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.allowance(account_id(Alice), account_id(Bob)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(allowance, 20);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with mintable and capped extensions - anyone can mint tokens as long as the total supply does not exceed the cap.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// PSP22Mintable trait's default implementation (PSP22MintableDefaultImpl) callable by anyone (guard = None)
+// and PSP22Capped trait's default implementation (PSP22CappedDefaultImpl & PSP22CappedInternalDefaultImpl)
+// PSP22Capped also injects the cap check into PSP22Internal::_update
+#[pendzl::implementation(PSP22, PSP22Mintable(guard = None), PSP22Capped)]
+#[ink::contract]
+pub mod my_psp22_capped {
+    use pendzl::contracts::psp22::*;
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+        #[storage_field]
+        // PSP22CappedData is a struct that implements PSP22CappedStorage - required by PSP22CappedInternalDefaultImpl trait
+        capped: PSP22CappedData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(cap: Balance, initial_supply: Balance) -> Self {
+            let mut instance = Self {
+                psp22: Default::default(),
+                capped: PSP22CappedData::new(cap),
+            };
+
+            // mint initial_supply to the caller using _mint_to from PSP22Internal (implemented by PSP22DefaultImpl)
+            // the cap is already set, so it will fail if initial_supply exceeds the cap
+            instance
+                ._mint_to(&Self::env().caller(), &initial_supply)
+                .expect("Should mint");
+
+            instance
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::Bob;
+        use test_helpers::balance_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn returns_cap(client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000, 100);
+            let contract = client
+                .instantiate(
+                    "my_psp22_capped",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let cap = client
+                .call(&ink_e2e::alice(), &contract.cap())
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(cap, 1000);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn can_mint_up_to_cap(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000, 100);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_capped",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let mint_tx = client
+                .call(&ink_e2e::alice(), &contract.mint(account_id(Bob), 900))
+                .submit()
+                .await
+                .expect("mint failed")
+                .return_value();
+
+            assert_eq!(mint_tx, Ok(()));
+            assert_eq!(balance_of!(client, contract, Bob), 900);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn can_not_mint_over_cap(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000, 100);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_capped",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let mint_tx = client
+                .call(&ink_e2e::alice(), &contract.mint(account_id(Bob), 901))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(mint_tx, Err(PSP22Error::CapExceeded));
+            assert_eq!(balance_of!(client, contract, Bob), 0);
+
+            Ok(())
+        }
+    }
+}
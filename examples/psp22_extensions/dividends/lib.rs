@@ -0,0 +1,240 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract distributing dividends paid in the native currency or PSP22 tokens to its holders.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22Dividends trait's default implementation (PSP22DividendsDefaultImpl & PSP22DividendsInternalDefaultImpl)
+// PSP22Dividends also injects the update of dividends corrections into PSP22Internal::_update
+#[pendzl::implementation(PSP22, PSP22Dividends)]
+#[ink::contract]
+pub mod my_psp22_dividends {
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+        #[storage_field]
+        // PSP22DividendsData is a struct that implements PSP22DividendsStorage - required by PSP22DividendsInternalDefaultImpl trait
+        dividends: PSP22DividendsData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance: Contract = Default::default();
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+            instance
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob};
+        use my_psp22::my_psp22::{Contract as Asset, ContractRef as AssetRef};
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn distributes_native_dividends_proportionally(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(100);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_dividends",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 25, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed");
+
+            let distribute_tx = client
+                .call(
+                    &ink_e2e::charlie(),
+                    &contract.distribute_dividends(None, 1_000),
+                )
+                .value(1_000)
+                .submit()
+                .await
+                .expect("distribute dividends failed")
+                .return_value();
+
+            assert_eq!(distribute_tx, Ok(()));
+
+            // tokens transferred after the distribution don't carry the dividends
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 25, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed");
+
+            let withdrawable_alice = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract
+                        .withdrawable_dividends_of(account_id(Alice), None),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            let withdrawable_bob = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.withdrawable_dividends_of(account_id(Bob), None),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(withdrawable_alice, 750);
+            assert_eq!(withdrawable_bob, 250);
+
+            let withdraw_tx = client
+                .call(&ink_e2e::bob(), &contract.withdraw_dividends(None))
+                .submit()
+                .await
+                .expect("withdraw dividends failed")
+                .return_value();
+
+            assert_eq!(withdraw_tx, Ok(250));
+
+            let withdrawable_bob = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.withdrawable_dividends_of(account_id(Bob), None),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            let withdrawn_bob = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.withdrawn_dividends_of(account_id(Bob), None),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(withdrawable_bob, 0);
+            assert_eq!(withdrawn_bob, 250);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn native_distribution_requires_exact_value(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(100);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_dividends",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let distribute_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.distribute_dividends(None, 1_000),
+                )
+                .value(999)
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(distribute_tx, Err(PSP22Error::InvalidAmountPaid));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn distribution_above_the_supported_ratio_fails(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            // u128::MAX / DIVIDENDS_MAGNITUDE is ~3.4 * 10^20 per token
+            let amount: Balance = 1_000_000_000_000_000_000_000;
+            let mut asset_constructor = AssetRef::new(amount);
+            let asset = client
+                .instantiate(
+                    "my_psp22",
+                    &ink_e2e::alice(),
+                    &mut asset_constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate asset failed");
+            let mut asset_call = asset.call_builder::<Asset>();
+
+            let mut constructor = ContractRef::new(1);
+            let contract = client
+                .instantiate(
+                    "my_psp22_dividends",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed");
+            let mut contract_call = contract.call_builder::<Contract>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &asset_call.approve(contract.account_id, amount),
+                )
+                .submit()
+                .await
+                .expect("approve failed");
+
+            let distribute_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract_call
+                        .distribute_dividends(Some(asset.account_id), amount),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(
+                distribute_tx,
+                Err(PSP22Error::Custom("M::Overflow".into()))
+            );
+
+            Ok(())
+        }
+    }
+}
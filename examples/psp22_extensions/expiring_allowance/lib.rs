@@ -0,0 +1,222 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with expiring allowance extension - allowances can be set to lapse at a given timestamp.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22ExpiringAllowance trait's default implementation (PSP22ExpiringAllowanceDefaultImpl & PSP22ExpiringAllowanceInternalDefaultImpl)
+// PSP22ExpiringAllowance also injects the expiry checks into PSP22Internal::_allowance, _approve, _increase_allowance_from_to and _spend_allowance
+#[pendzl::implementation(PSP22, PSP22ExpiringAllowance)]
+#[ink::contract]
+pub mod my_psp22_expiring_allowance {
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+        #[storage_field]
+        // PSP22ExpiringAllowanceData is a struct that implements PSP22ExpiringAllowanceStorage - required by PSP22ExpiringAllowanceInternalDefaultImpl trait
+        expiring_allowance: PSP22ExpiringAllowanceData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance: Contract = Default::default();
+
+            // mint total_supply to the caller using _mint_to from PSP22Internal (implemented by PSP22DefaultImpl)
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+
+            instance
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob, Charlie};
+        use test_helpers::balance_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        // far in the future, the allowance doesn't expire during the test
+        const EXPIRES_AT: Timestamp = u64::MAX;
+
+        #[ink_e2e::test]
+        async fn allowance_with_expiry_can_be_spent(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1_000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_expiring_allowance",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let approve_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.approve_with_expiry(
+                        account_id(Bob),
+                        500,
+                        EXPIRES_AT,
+                    ),
+                )
+                .submit()
+                .await
+                .expect("approve with expiry failed")
+                .return_value();
+
+            assert_eq!(approve_tx, Ok(()));
+
+            let expiry = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract
+                        .allowance_expiry(account_id(Alice), account_id(Bob)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(expiry, Some(EXPIRES_AT));
+
+            let transfer_from_tx = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.transfer_from(
+                        account_id(Alice),
+                        account_id(Charlie),
+                        100,
+                        vec![],
+                    ),
+                )
+                .submit()
+                .await
+                .expect("transfer from failed")
+                .return_value();
+
+            assert_eq!(transfer_from_tx, Ok(()));
+            assert_eq!(balance_of!(client, contract, Charlie), 100);
+
+            let allowance = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.allowance(account_id(Alice), account_id(Bob)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(allowance, 400);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn approve_removes_expiry(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1_000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_expiring_allowance",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let approve_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.approve_with_expiry(
+                        account_id(Bob),
+                        500,
+                        EXPIRES_AT,
+                    ),
+                )
+                .submit()
+                .await
+                .expect("approve with expiry failed")
+                .return_value();
+
+            assert_eq!(approve_tx, Ok(()));
+
+            let approve_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.approve(account_id(Bob), 300),
+                )
+                .submit()
+                .await
+                .expect("approve failed")
+                .return_value();
+
+            assert_eq!(approve_tx, Ok(()));
+
+            let expiry = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract
+                        .allowance_expiry(account_id(Alice), account_id(Bob)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(expiry, None);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn can_not_approve_with_past_expiry(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1_000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_expiring_allowance",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let approve_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.approve_with_expiry(account_id(Bob), 500, 0),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(approve_tx, Err(PSP22Error::AllowanceExpired));
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,206 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with fee extension - every transfer is charged with a fee sent to the treasury.
+/// Only the owner can change the fee, the treasury and the exempt accounts.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// PSP22Fee trait's default implementation (PSP22FeeDefaultImpl & PSP22FeeInternalDefaultImpl) guarded by Ownable
+// and Ownable trait's default implementation (OwnableDefaultImpl & OwnableInternalDefaultImpl)
+// PSP22Fee also injects the fee split into PSP22Internal::_update
+#[pendzl::implementation(PSP22, PSP22Fee(guard = Ownable), Ownable)]
+#[ink::contract]
+pub mod my_psp22_fee {
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+        #[storage_field]
+        // PSP22FeeData is a struct that implements PSP22FeeStorage - required by PSP22FeeInternalDefaultImpl trait
+        fee: PSP22FeeData,
+        #[storage_field]
+        // OwnableData is a struct that implements OwnableStorage - required by OwnableInternalDefaultImpl trait
+        ownable: OwnableData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(
+            total_supply: Balance,
+            fee_bps: u16,
+            treasury: AccountId,
+        ) -> Self {
+            let mut instance = Self {
+                psp22: Default::default(),
+                fee: PSP22FeeData::new(fee_bps, Some(treasury)),
+                ownable: Default::default(),
+            };
+
+            instance._update_owner(&Some(Self::env().caller()));
+            // mint total_supply to the caller using _mint_to from PSP22Internal (implemented by PSP22DefaultImpl)
+            // mints are not charged
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+
+            instance
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob, Charlie};
+        use test_helpers::balance_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn transfer_is_charged(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor =
+                ContractRef::new(10_000, 100, account_id(Charlie));
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_fee",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let transfer_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 1_000, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed")
+                .return_value();
+
+            assert_eq!(transfer_tx, Ok(()));
+            assert_eq!(balance_of!(client, contract, Alice), 9_000);
+            assert_eq!(balance_of!(client, contract, Bob), 990);
+            assert_eq!(balance_of!(client, contract, Charlie), 10);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn exempt_transfer_is_not_charged(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor =
+                ContractRef::new(10_000, 100, account_id(Charlie));
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_fee",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let set_fee_exempt_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.set_fee_exempt(account_id(Alice), true),
+                )
+                .submit()
+                .await
+                .expect("set fee exempt failed")
+                .return_value();
+
+            assert_eq!(set_fee_exempt_tx, Ok(()));
+
+            let transfer_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 1_000, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed")
+                .return_value();
+
+            assert_eq!(transfer_tx, Ok(()));
+            assert_eq!(balance_of!(client, contract, Bob), 1_000);
+            assert_eq!(balance_of!(client, contract, Charlie), 0);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn only_owner_can_set_fee(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor =
+                ContractRef::new(10_000, 100, account_id(Charlie));
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_fee",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let set_fee_tx = client
+                .call(&ink_e2e::bob(), &contract.set_fee_bps(500))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(
+                set_fee_tx,
+                Err(PSP22Error::Custom("O::CallerIsNotOwner".into()))
+            );
+
+            let set_fee_tx = client
+                .call(&ink_e2e::alice(), &contract.set_fee_bps(10_001))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(set_fee_tx, Err(PSP22Error::InvalidFee));
+
+            let set_fee_tx = client
+                .call(&ink_e2e::alice(), &contract.set_fee_bps(500))
+                .submit()
+                .await
+                .expect("set fee failed")
+                .return_value();
+
+            assert_eq!(set_fee_tx, Ok(()));
+
+            let fee_bps = client
+                .call(&ink_e2e::alice(), &contract.fee_bps())
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(fee_bps, 500);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A contract receiving flash loans from PSP22 tokens implementing PSP22FlashLender.
+/// Optionally it deposits the assets it holds to the lender (when it's a PSP22Vault) while holding the loan.
+// ########################################################
+#[ink::contract]
+pub mod my_psp22_flash_borrower {
+    use ink::{contract_ref, env::DefaultEnvironment, prelude::vec::Vec};
+    use pendzl::{
+        contracts::psp22::{
+            flash_lender::*, vault::PSP22Vault, PSP22Ref, PSP22,
+        },
+        math::errors::MathError,
+    };
+
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct Contract {
+        deposit_to_lender: bool,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        #[ink(constructor)]
+        pub fn new_vault_depositor() -> Self {
+            Self {
+                deposit_to_lender: true,
+            }
+        }
+    }
+
+    impl PSP22FlashBorrower for Contract {
+        #[ink(message)]
+        fn on_flashloan(
+            &mut self,
+            _initiator: AccountId,
+            amount: Balance,
+            fee: Balance,
+            _data: Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            // the caller is the token that is being loaned
+            let lender = Self::env().caller();
+
+            // here the loaned tokens can be used (ex. for an arbitrage)
+            if self.deposit_to_lender {
+                let mut vault: contract_ref!(PSP22Vault, DefaultEnvironment) =
+                    lender.into();
+                let mut asset: PSP22Ref = vault.asset().into();
+                let assets = asset.balance_of(Self::env().account_id());
+                asset.approve(lender, assets)?;
+                vault.deposit(assets, Self::env().account_id())?;
+            }
+
+            // approve the lender to burn the loan and take the fee
+            let repayment =
+                amount.checked_add(fee).ok_or(MathError::Overflow)?;
+            let mut token: PSP22Ref = lender.into();
+            token.approve(lender, repayment)
+        }
+    }
+}
@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with flash lender extension - anyone can flash loan the tokens paying 1% fee.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22FlashLender trait's default implementation (PSP22FlashLenderDefaultImpl & PSP22FlashLenderInternalDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(PSP22, PSP22FlashLender)]
+#[ink::contract]
+pub mod my_psp22_flash_lender {
+    use pendzl::contracts::psp22::*;
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+
+            // mint total_supply to the caller using _mint_to from PSP22Internal (implemented by PSP22DefaultImpl)
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+
+            instance
+        }
+    }
+
+    // override the default (zero) fee with 1% of the loaned amount
+    #[overrider(PSP22FlashLenderInternal)]
+    fn _flash_fee(&self, amount: &Balance) -> Result<Balance, PSP22Error> {
+        Ok(amount / 100)
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::Bob;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn returns_max_flash_loan_and_fee(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let contract = client
+                .instantiate(
+                    "my_psp22_flash_lender",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let max_flash_loan = client
+                .call(&ink_e2e::alice(), &contract.max_flash_loan())
+                .dry_run()
+                .await?
+                .return_value();
+            let flash_fee = client
+                .call(&ink_e2e::alice(), &contract.flash_fee(1000))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(max_flash_loan, Balance::MAX - 1000);
+            assert_eq!(flash_fee, Ok(10));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn flash_loan_to_non_borrower_fails(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_flash_lender",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let flash_loan = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.flash_loan(account_id(Bob), 100, vec![]),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(flash_loan, Err(PSP22Error::FlashBorrowerCallFailed));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn flash_loan_above_max_fails(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_flash_lender",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let flash_loan = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.flash_loan(
+                        account_id(Bob),
+                        Balance::MAX - 999,
+                        vec![],
+                    ),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(flash_loan, Err(PSP22Error::MaxFlashLoanExceeded));
+
+            Ok(())
+        }
+    }
+}
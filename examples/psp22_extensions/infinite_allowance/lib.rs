@@ -0,0 +1,283 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract where an allowance of Balance::MAX is unlimited and is not decreased by transfer_from.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// infinite_allowance injects the unlimited allowance check into PSP22Internal::_spend_allowance
+#[pendzl::implementation(PSP22(infinite_allowance))]
+#[ink::contract]
+pub mod my_psp22_infinite_allowance {
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance: Contract = Default::default();
+
+            // mint total_supply to the caller using _mint_to from PSP22Internal (implemented by PSP22DefaultImpl)
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+
+            instance
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob, Charlie};
+        use test_helpers::balance_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn unlimited_allowance_is_not_decreased(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1_000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_infinite_allowance",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let approve_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.approve(account_id(Bob), Balance::MAX),
+                )
+                .submit()
+                .await
+                .expect("approve failed")
+                .return_value();
+
+            assert_eq!(approve_tx, Ok(()));
+
+            let transfer_from_tx = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.transfer_from(
+                        account_id(Alice),
+                        account_id(Charlie),
+                        100,
+                        vec![],
+                    ),
+                )
+                .submit()
+                .await
+                .expect("transfer from failed")
+                .return_value();
+
+            assert_eq!(transfer_from_tx, Ok(()));
+            assert_eq!(balance_of!(client, contract, Charlie), 100);
+
+            let allowance = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.allowance(account_id(Alice), account_id(Bob)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(allowance, Balance::MAX);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn limited_allowance_is_decreased(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1_000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_infinite_allowance",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let approve_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.approve(account_id(Bob), 500),
+                )
+                .submit()
+                .await
+                .expect("approve failed")
+                .return_value();
+
+            assert_eq!(approve_tx, Ok(()));
+
+            let transfer_from_tx = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.transfer_from(
+                        account_id(Alice),
+                        account_id(Charlie),
+                        100,
+                        vec![],
+                    ),
+                )
+                .submit()
+                .await
+                .expect("transfer from failed")
+                .return_value();
+
+            assert_eq!(transfer_from_tx, Ok(()));
+
+            let allowance = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.allowance(account_id(Alice), account_id(Bob)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(allowance, 400);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn owner_can_decrease_unlimited_allowance(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1_000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_infinite_allowance",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let approve_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.approve(account_id(Bob), Balance::MAX),
+                )
+                .submit()
+                .await
+                .expect("approve failed")
+                .return_value();
+
+            assert_eq!(approve_tx, Ok(()));
+
+            let decrease_allowance_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.decrease_allowance(account_id(Bob), 1),
+                )
+                .submit()
+                .await
+                .expect("decrease allowance failed")
+                .return_value();
+
+            assert_eq!(decrease_allowance_tx, Ok(()));
+
+            let allowance = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.allowance(account_id(Alice), account_id(Bob)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(allowance, Balance::MAX - 1);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn owner_spending_own_unlimited_allowance_is_not_decreased(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1_000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_infinite_allowance",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let approve_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.approve(account_id(Alice), Balance::MAX),
+                )
+                .submit()
+                .await
+                .expect("approve failed")
+                .return_value();
+
+            assert_eq!(approve_tx, Ok(()));
+
+            let transfer_from_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer_from(
+                        account_id(Alice),
+                        account_id(Charlie),
+                        100,
+                        vec![],
+                    ),
+                )
+                .submit()
+                .await
+                .expect("transfer from failed")
+                .return_value();
+
+            assert_eq!(transfer_from_tx, Ok(()));
+            assert_eq!(balance_of!(client, contract, Charlie), 100);
+
+            let allowance = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.allowance(account_id(Alice), account_id(Alice)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(allowance, Balance::MAX);
+
+            Ok(())
+        }
+    }
+}
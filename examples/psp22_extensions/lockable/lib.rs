@@ -0,0 +1,297 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with lockable extension - parts of the balances can be locked until a release time.
+/// Only the owner can lock tokens.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// PSP22Lockable trait's default implementation (PSP22LockableDefaultImpl & PSP22LockableInternalDefaultImpl) guarded by Ownable
+// and Ownable trait's default implementation (OwnableDefaultImpl & OwnableInternalDefaultImpl)
+// PSP22Lockable also injects the lock check into PSP22Internal::_update
+#[pendzl::implementation(PSP22, PSP22Lockable(guard = Ownable), Ownable)]
+#[ink::contract]
+pub mod my_psp22_lockable {
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+        #[storage_field]
+        // PSP22LockableData is a struct that implements PSP22LockableStorage - required by PSP22LockableInternalDefaultImpl trait
+        lockable: PSP22LockableData,
+        #[storage_field]
+        // OwnableData is a struct that implements OwnableStorage - required by OwnableInternalDefaultImpl trait
+        ownable: OwnableData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance: Contract = Default::default();
+
+            instance._update_owner(&Some(Self::env().caller()));
+            // mint total_supply to the caller using _mint_to from PSP22Internal (implemented by PSP22DefaultImpl)
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+
+            instance
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob};
+        use test_helpers::balance_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        // far in the future, the lock never expires during the test
+        const UNTIL: Timestamp = u64::MAX;
+
+        #[ink_e2e::test]
+        async fn locked_tokens_can_not_be_transferred(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1_000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_lockable",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let lock_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.lock(account_id(Alice), 600, UNTIL),
+                )
+                .submit()
+                .await
+                .expect("lock failed")
+                .return_value();
+
+            assert_eq!(lock_tx, Ok(()));
+
+            let locked_balance = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.locked_balance_of(account_id(Alice)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(locked_balance, 600);
+
+            let transfer_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 401, vec![]),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(
+                transfer_tx,
+                Err(PSP22Error::InsufficientUnlockedBalance)
+            );
+
+            let transfer_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 400, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed")
+                .return_value();
+
+            assert_eq!(transfer_tx, Ok(()));
+            assert_eq!(balance_of!(client, contract, Alice), 600);
+            assert_eq!(balance_of!(client, contract, Bob), 400);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn only_owner_can_lock(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1_000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_lockable",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let lock_tx = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.lock(account_id(Alice), 600, UNTIL),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(
+                lock_tx,
+                Err(PSP22Error::Custom("O::CallerIsNotOwner".into()))
+            );
+
+            let lock_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.lock(account_id(Alice), 600, 0),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(lock_tx, Err(PSP22Error::InvalidLock));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn lock_can_not_exceed_balance(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1_000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_lockable",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.lock(account_id(Alice), 600, UNTIL),
+                )
+                .submit()
+                .await
+                .expect("lock failed");
+
+            let lock_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.lock(account_id(Alice), 401, UNTIL),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(lock_tx, Err(PSP22Error::InsufficientUnlockedBalance));
+
+            let lock_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.lock(account_id(Bob), 1, UNTIL),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(lock_tx, Err(PSP22Error::InsufficientUnlockedBalance));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn locks_are_merged_and_capped(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1_000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_lockable",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            for i in 0..MAX_LOCKS as u64 {
+                client
+                    .call(
+                        &ink_e2e::alice(),
+                        &contract.lock(account_id(Alice), 10, UNTIL - i),
+                    )
+                    .submit()
+                    .await
+                    .expect("lock failed");
+            }
+
+            let lock_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.lock(
+                        account_id(Alice),
+                        10,
+                        UNTIL - MAX_LOCKS as u64,
+                    ),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(lock_tx, Err(PSP22Error::TooManyLocks));
+
+            // a lock with the same release time is merged into the existing one
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.lock(account_id(Alice), 10, UNTIL),
+                )
+                .submit()
+                .await
+                .expect("lock failed");
+
+            let locks = client
+                .call(&ink_e2e::alice(), &contract.locks_of(account_id(Alice)))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(locks.len(), MAX_LOCKS as usize);
+            assert_eq!(
+                locks[0],
+                Lock {
+                    amount: 20,
+                    until: UNTIL
+                }
+            );
+
+            Ok(())
+        }
+    }
+}
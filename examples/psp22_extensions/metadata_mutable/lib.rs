@@ -0,0 +1,175 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with metadata that can be changed by the owner.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// PSP22Metadata trait's default implementation (PSP22MetadataDefaultImpl)
+// PSP22MetadataMutable trait's default implementation (PSP22MetadataMutableDefaultImpl & PSP22MetadataMutableInternalDefaultImpl) guarded by Ownable
+// and Ownable trait's default implementation (OwnableDefaultImpl & OwnableInternalDefaultImpl)
+#[pendzl::implementation(
+    PSP22,
+    PSP22Metadata,
+    PSP22MetadataMutable(guard = Ownable),
+    Ownable
+)]
+#[ink::contract]
+pub mod my_psp22_metadata_mutable {
+    use ink::prelude::string::String;
+    use pendzl::contracts::psp22::*;
+
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+        #[storage_field]
+        // PSP22MetadataData is a struct that implements PSP22MetadataStorage and PSP22MetadataMutableStorage
+        // required by PSP22MetadataDefaultImpl and PSP22MetadataMutableInternalDefaultImpl traits
+        metadata: PSP22MetadataData,
+        #[storage_field]
+        // OwnableData is a struct that implements OwnableStorage - required by OwnableInternalDefaultImpl trait
+        ownable: OwnableData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(
+            total_supply: Balance,
+            name: Option<String>,
+            symbol: Option<String>,
+            decimals: u8,
+        ) -> Self {
+            let mut instance = Self {
+                psp22: Default::default(),
+                metadata: PSP22MetadataData::new(name, symbol, decimals),
+                ownable: Default::default(),
+            };
+
+            instance._update_owner(&Some(Self::env().caller()));
+            // mint total_supply to the caller using _mint_to from PSP22Internal (implemented by PSP22DefaultImpl)
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+
+            instance
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn owner_can_change_metadata(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(
+                1000,
+                Some(String::from("TOKEN")),
+                Some(String::from("TKN")),
+                18,
+            );
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_metadata_mutable",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let set_name_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.set_token_name(Some(String::from("NEW TOKEN"))),
+                )
+                .submit()
+                .await
+                .expect("set token name failed")
+                .return_value();
+
+            assert_eq!(set_name_tx, Ok(()));
+
+            let set_symbol_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.set_token_symbol(Some(String::from("NTKN"))),
+                )
+                .submit()
+                .await
+                .expect("set token symbol failed")
+                .return_value();
+
+            assert_eq!(set_symbol_tx, Ok(()));
+
+            let token_name = client
+                .call(&ink_e2e::alice(), &contract.token_name())
+                .dry_run()
+                .await?
+                .return_value();
+
+            let token_symbol = client
+                .call(&ink_e2e::alice(), &contract.token_symbol())
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(token_name, Some(String::from("NEW TOKEN")));
+            assert_eq!(token_symbol, Some(String::from("NTKN")));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn only_owner_can_change_metadata(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(
+                1000,
+                Some(String::from("TOKEN")),
+                Some(String::from("TKN")),
+                18,
+            );
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_metadata_mutable",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let set_name_tx = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.set_token_name(Some(String::from("NEW TOKEN"))),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(
+                set_name_tx,
+                Err(PSP22Error::Custom("O::CallerIsNotOwner".into()))
+            );
+
+            Ok(())
+        }
+    }
+}
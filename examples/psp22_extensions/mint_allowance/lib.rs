@@ -0,0 +1,162 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract where every minter can mint up to its mint allowance set by the owner.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// PSP22Mintable trait's default implementation (PSP22MintableDefaultImpl) callable by anyone (guard = None) within the mint allowance
+// PSP22MintAllowance trait's default implementation (PSP22MintAllowanceDefaultImpl & PSP22MintAllowanceInternalDefaultImpl) guarded by Ownable
+// and Ownable trait's default implementation (OwnableDefaultImpl & OwnableInternalDefaultImpl)
+// PSP22MintAllowance also injects the mint allowance check into PSP22Mintable::mint
+#[pendzl::implementation(
+    PSP22,
+    PSP22Mintable(guard = None),
+    PSP22MintAllowance(guard = Ownable),
+    Ownable
+)]
+#[ink::contract]
+pub mod my_psp22_mint_allowance {
+    use pendzl::contracts::psp22::*;
+
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+        #[storage_field]
+        // PSP22MintAllowanceData is a struct that implements PSP22MintAllowanceStorage - required by PSP22MintAllowanceInternalDefaultImpl trait
+        mint_allowance: PSP22MintAllowanceData,
+        #[storage_field]
+        // OwnableData is a struct that implements OwnableStorage - required by OwnableInternalDefaultImpl trait
+        ownable: OwnableData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance: Contract = Default::default();
+            instance._update_owner(&Some(Self::env().caller()));
+            instance
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Bob, Charlie};
+        use test_helpers::balance_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn minter_can_mint_up_to_allowance(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new();
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_mint_allowance",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let set_allowance_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.set_minter_allowance(account_id(Bob), 100),
+                )
+                .submit()
+                .await
+                .expect("set minter allowance failed")
+                .return_value();
+
+            assert_eq!(set_allowance_tx, Ok(()));
+
+            let mint_tx = client
+                .call(&ink_e2e::bob(), &contract.mint(account_id(Charlie), 60))
+                .submit()
+                .await
+                .expect("mint failed")
+                .return_value();
+
+            assert_eq!(mint_tx, Ok(()));
+            assert_eq!(balance_of!(client, contract, Charlie), 60);
+
+            let minter_allowance = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.minter_allowance(account_id(Bob)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(minter_allowance, 40);
+
+            let mint_tx = client
+                .call(&ink_e2e::bob(), &contract.mint(account_id(Charlie), 41))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(mint_tx, Err(PSP22Error::MintAllowanceExceeded));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn only_owner_can_set_minter_allowance(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new();
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_mint_allowance",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let set_allowance_tx = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.set_minter_allowance(account_id(Bob), 100),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(
+                set_allowance_tx,
+                Err(PSP22Error::Custom("O::CallerIsNotOwner".into()))
+            );
+
+            let mint_tx = client
+                .call(&ink_e2e::bob(), &contract.mint(account_id(Bob), 1))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(mint_tx, Err(PSP22Error::MintAllowanceExceeded));
+
+            Ok(())
+        }
+    }
+}
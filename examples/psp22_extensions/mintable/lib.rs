@@ -5,9 +5,9 @@
 /// A PSP22 contract with mintable extension - anyone can mint tokens.
 // ########################################################
 // inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
-// and PSP22Mintable trait's default implementation (PSP22MintableDefaultImpl)
+// and PSP22Mintable trait's default implementation (PSP22MintableDefaultImpl) callable by anyone (guard = None)
 // which reduces the amount of boilerplate code required to implement trait messages drastically
-#[pendzl::implementation(PSP22, PSP22Mintable)]
+#[pendzl::implementation(PSP22, PSP22Mintable(guard = None))]
 #[ink::contract]
 pub mod my_psp22_mintable {
     use pendzl::contracts::psp22::*;
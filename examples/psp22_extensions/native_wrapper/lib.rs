@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract wrapping the native currency one-to-one.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22NativeWrapper trait's default implementation (PSP22NativeWrapperDefaultImpl & PSP22NativeWrapperInternalDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(PSP22, PSP22NativeWrapper)]
+#[ink::contract]
+pub mod my_psp22_native_wrapper {
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+        // PSP22NativeWrapper requires no additional storage
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Default::default()
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::AccountKeyring::Alice;
+        use test_helpers::balance_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn deposit_mints_transferred_value(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new();
+            let contract = client
+                .instantiate(
+                    "my_psp22_native_wrapper",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed");
+            let contract_id = contract.account_id;
+            let mut contract = contract.call_builder::<Contract>();
+
+            let native_balance_before =
+                client.free_balance(contract_id).await?;
+
+            let deposit_tx = client
+                .call(&ink_e2e::alice(), &contract.deposit())
+                .value(1_000)
+                .submit()
+                .await
+                .expect("deposit failed")
+                .return_value();
+
+            assert_eq!(deposit_tx, Ok(()));
+            assert_eq!(balance_of!(client, contract, Alice), 1_000);
+
+            let total_supply = client
+                .call(&ink_e2e::alice(), &contract.total_supply())
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(total_supply, 1_000);
+            assert_eq!(
+                client.free_balance(contract_id).await?,
+                native_balance_before + 1_000
+            );
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn withdraw_burns_and_returns_native(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new();
+            let contract = client
+                .instantiate(
+                    "my_psp22_native_wrapper",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed");
+            let contract_id = contract.account_id;
+            let mut contract = contract.call_builder::<Contract>();
+
+            client
+                .call(&ink_e2e::alice(), &contract.deposit())
+                .value(1_000)
+                .submit()
+                .await
+                .expect("deposit failed");
+
+            let native_balance_before =
+                client.free_balance(contract_id).await?;
+
+            let withdraw_tx = client
+                .call(&ink_e2e::alice(), &contract.withdraw(400))
+                .submit()
+                .await
+                .expect("withdraw failed")
+                .return_value();
+
+            assert_eq!(withdraw_tx, Ok(()));
+            assert_eq!(balance_of!(client, contract, Alice), 600);
+            assert_eq!(
+                client.free_balance(contract_id).await?,
+                native_balance_before - 400
+            );
+
+            let withdraw_tx = client
+                .call(&ink_e2e::alice(), &contract.withdraw(601))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(withdraw_tx, Err(PSP22Error::InsufficientBalance));
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,258 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with permit extension - allowances can be set with the owner's off-chain signature.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22Permit trait's default implementation (PSP22PermitDefaultImpl & PSP22PermitInternalDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(PSP22, PSP22Permit)]
+#[ink::contract]
+pub mod my_psp22_permit {
+    use pendzl::contracts::psp22::*;
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+        #[storage_field]
+        // PSP22PermitData is a struct that implements PSP22PermitStorage - required by PSP22PermitInternalDefaultImpl trait
+        // it keeps track of the nonces of the owners
+        permit: PSP22PermitData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+
+            // mint total_supply to the caller using _mint_to from PSP22Internal (implemented by PSP22DefaultImpl)
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+
+            instance
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob};
+        use scale::Encode;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        fn sign_permit(
+            domain_separator: [u8; 32],
+            spender: AccountId,
+            value: Balance,
+            deadline: Timestamp,
+            nonce: u64,
+        ) -> PermitSignature {
+            let message = PermitMessage {
+                domain_separator,
+                owner: account_id(Alice),
+                spender,
+                value,
+                deadline,
+                nonce,
+            };
+            PermitSignature::Sr25519(ink_e2e::alice().sign(&message.encode()).0)
+        }
+
+        #[ink_e2e::test]
+        async fn permit_sets_allowance(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_permit",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let domain_separator = client
+                .call(&ink_e2e::bob(), &contract.domain_separator())
+                .dry_run()
+                .await?
+                .return_value();
+
+            let signature = sign_permit(
+                domain_separator,
+                account_id(Bob),
+                100,
+                u64::MAX,
+                0,
+            );
+
+            // bob submits the permit signed by alice
+            let permit_tx = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.permit(
+                        account_id(Alice),
+                        account_id(Bob),
+                        100,
+                        u64::MAX,
+                        signature,
+                    ),
+                )
+                .submit()
+                .await
+                .expect("permit failed")
+                .return_value();
+
+            assert_eq!(permit_tx, Ok(()));
+
+            let allowance = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.allowance(account_id(Alice), account_id(Bob)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(allowance, 100);
+
+            let nonce = client
+                .call(&ink_e2e::bob(), &contract.nonces(account_id(Alice)))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(nonce, 1);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn permit_can_not_be_replayed(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_permit",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let domain_separator = client
+                .call(&ink_e2e::bob(), &contract.domain_separator())
+                .dry_run()
+                .await?
+                .return_value();
+
+            let signature = sign_permit(
+                domain_separator,
+                account_id(Bob),
+                100,
+                u64::MAX,
+                0,
+            );
+
+            let permit_tx = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.permit(
+                        account_id(Alice),
+                        account_id(Bob),
+                        100,
+                        u64::MAX,
+                        signature.clone(),
+                    ),
+                )
+                .submit()
+                .await
+                .expect("permit failed")
+                .return_value();
+
+            assert_eq!(permit_tx, Ok(()));
+
+            let replay = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.permit(
+                        account_id(Alice),
+                        account_id(Bob),
+                        100,
+                        u64::MAX,
+                        signature,
+                    ),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(replay, Err(PSP22Error::PermitInvalidSignature));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn permit_fails_after_deadline(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_permit",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let domain_separator = client
+                .call(&ink_e2e::bob(), &contract.domain_separator())
+                .dry_run()
+                .await?
+                .return_value();
+
+            let signature =
+                sign_permit(domain_separator, account_id(Bob), 100, 0, 0);
+
+            let permit_tx = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.permit(
+                        account_id(Alice),
+                        account_id(Bob),
+                        100,
+                        0,
+                        signature,
+                    ),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(permit_tx, Err(PSP22Error::PermitExpired));
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,250 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with an elastic supply - balances are stored as shares of the total supply.
+/// Only the owner can rebase the total supply, changing every balance proportionally.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// PSP22Rebasing trait's default implementation (PSP22RebasingDefaultImpl & PSP22RebasingInternalDefaultImpl)
+// and Ownable trait's default implementation (OwnableDefaultImpl & OwnableInternalDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(PSP22, PSP22Rebasing, Ownable)]
+#[ink::contract]
+pub mod my_psp22_rebasing {
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22RebasingData is a struct that implements PSP22Storage and PSP22RebasingStorage
+        // with PSP22Rebasing implemented it is used by PSP22InternalDefaultImpl trait in place of PSP22Data
+        // name of the field is arbitrary
+        psp22: PSP22RebasingData,
+        #[storage_field]
+        // OwnableData is a struct that implements OwnableStorage - required by OwnableInternalDefaultImpl trait
+        ownable: OwnableData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance: Contract = Default::default();
+
+            instance._update_owner(&Some(Self::env().caller()));
+            // mint total_supply to the caller using _mint_to from PSP22Internal (implemented by PSP22DefaultImpl)
+            // the first mint converts tokens to shares one to one
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+
+            instance
+        }
+
+        #[ink(message)]
+        // change the total supply, ex. to distribute the staking rewards to all holders
+        pub fn rebase(
+            &mut self,
+            new_total_supply: Balance,
+        ) -> Result<(), PSP22Error> {
+            self._only_owner()?;
+            self._rebase(&new_total_supply)
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob};
+        use test_helpers::balance_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn rebase_changes_balances_proportionally(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1_000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_rebasing",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let transfer_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 250, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed")
+                .return_value();
+
+            assert_eq!(transfer_tx, Ok(()));
+
+            let rebase_tx = client
+                .call(&ink_e2e::alice(), &contract.rebase(2_000))
+                .submit()
+                .await
+                .expect("rebase failed")
+                .return_value();
+
+            assert_eq!(rebase_tx, Ok(()));
+
+            let total_supply = client
+                .call(&ink_e2e::alice(), &contract.total_supply())
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(total_supply, 2_000);
+            assert_eq!(balance_of!(client, contract, Alice), 1_500);
+            assert_eq!(balance_of!(client, contract, Bob), 500);
+
+            let bob_shares = client
+                .call(&ink_e2e::alice(), &contract.shares_of(account_id(Bob)))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(bob_shares, 250);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn transfers_after_rebase_move_shares(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1_000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_rebasing",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let rebase_tx = client
+                .call(&ink_e2e::alice(), &contract.rebase(500))
+                .submit()
+                .await
+                .expect("rebase failed")
+                .return_value();
+
+            assert_eq!(rebase_tx, Ok(()));
+            assert_eq!(balance_of!(client, contract, Alice), 500);
+
+            let transfer_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 100, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed")
+                .return_value();
+
+            assert_eq!(transfer_tx, Ok(()));
+            assert_eq!(balance_of!(client, contract, Alice), 400);
+            assert_eq!(balance_of!(client, contract, Bob), 100);
+
+            let bob_shares = client
+                .call(&ink_e2e::alice(), &contract.shares_of(account_id(Bob)))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(bob_shares, 200);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn only_owner_can_rebase(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1_000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_rebasing",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let rebase_tx = client
+                .call(&ink_e2e::bob(), &contract.rebase(2_000))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(
+                rebase_tx,
+                Err(PSP22Error::Custom("O::CallerIsNotOwner".into()))
+            );
+
+            let rebase_tx = client
+                .call(&ink_e2e::alice(), &contract.rebase(0))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(rebase_tx, Err(PSP22Error::InvalidRebase));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn can_not_rebase_without_shares(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(0);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_rebasing",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let rebase_tx = client
+                .call(&ink_e2e::alice(), &contract.rebase(1_000))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(rebase_tx, Err(PSP22Error::InvalidRebase));
+
+            let total_supply = client
+                .call(&ink_e2e::alice(), &contract.total_supply())
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(total_supply, 0);
+
+            Ok(())
+        }
+    }
+}
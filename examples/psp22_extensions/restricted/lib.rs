@@ -0,0 +1,317 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with restricted extension - the owner can freeze accounts by adding them to the blocklist.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// PSP22Restricted trait's default implementation (PSP22RestrictedDefaultImpl & PSP22RestrictedInternalDefaultImpl) guarded by Ownable
+// and Ownable trait's default implementation (OwnableDefaultImpl & OwnableInternalDefaultImpl)
+// PSP22Restricted also injects the list checks into PSP22Internal::_update, _approve and _increase_allowance_from_to
+#[pendzl::implementation(PSP22, PSP22Restricted(guard = Ownable), Ownable)]
+#[ink::contract]
+pub mod my_psp22_restricted {
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+        #[storage_field]
+        // PSP22RestrictedData is a struct that implements PSP22RestrictedStorage - required by PSP22RestrictedInternalDefaultImpl trait
+        restricted: PSP22RestrictedData,
+        #[storage_field]
+        // OwnableData is a struct that implements OwnableStorage - required by OwnableInternalDefaultImpl trait
+        ownable: OwnableData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self {
+                psp22: Default::default(),
+                restricted: PSP22RestrictedData::new(
+                    RestrictionMode::BlocklistMode,
+                ),
+                ownable: Default::default(),
+            };
+
+            instance._update_owner(&Some(Self::env().caller()));
+            // mint total_supply to the caller using _mint_to from PSP22Internal (implemented by PSP22DefaultImpl)
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+
+            instance
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob};
+        use test_helpers::balance_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn blocked_account_can_not_receive_nor_approve(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_restricted",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let add_to_list_tx = client
+                .call(&ink_e2e::alice(), &contract.add_to_list(account_id(Bob)))
+                .submit()
+                .await
+                .expect("add to list failed")
+                .return_value();
+
+            assert_eq!(add_to_list_tx, Ok(()));
+
+            let is_restricted = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.is_restricted(account_id(Bob)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert!(is_restricted);
+
+            let transfer_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 100, vec![]),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(transfer_tx, Err(PSP22Error::AccountRestricted));
+
+            let approve_tx = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.approve(account_id(Alice), 100),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(approve_tx, Err(PSP22Error::AccountRestricted));
+            assert_eq!(balance_of!(client, contract, Bob), 0);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn removed_account_can_receive(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_restricted",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            client
+                .call(&ink_e2e::alice(), &contract.add_to_list(account_id(Bob)))
+                .submit()
+                .await
+                .expect("add to list failed");
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.remove_from_list(account_id(Bob)),
+                )
+                .submit()
+                .await
+                .expect("remove from list failed");
+
+            let transfer_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 100, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed")
+                .return_value();
+
+            assert_eq!(transfer_tx, Ok(()));
+            assert_eq!(balance_of!(client, contract, Bob), 100);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn allowance_of_blocked_spender_can_be_revoked(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_restricted",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.approve(account_id(Bob), 100),
+                )
+                .submit()
+                .await
+                .expect("approve failed");
+
+            client
+                .call(&ink_e2e::alice(), &contract.add_to_list(account_id(Bob)))
+                .submit()
+                .await
+                .expect("add to list failed");
+
+            let approve_tx = client
+                .call(&ink_e2e::alice(), &contract.approve(account_id(Bob), 50))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(approve_tx, Err(PSP22Error::AccountRestricted));
+
+            let revoke_tx = client
+                .call(&ink_e2e::alice(), &contract.approve(account_id(Bob), 0))
+                .submit()
+                .await
+                .expect("revoke failed")
+                .return_value();
+
+            assert_eq!(revoke_tx, Ok(()));
+
+            let allowance = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.allowance(account_id(Alice), account_id(Bob)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(allowance, 0);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn blocked_spender_can_not_transfer_from(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_restricted",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.approve(account_id(Bob), 100),
+                )
+                .submit()
+                .await
+                .expect("approve failed");
+
+            client
+                .call(&ink_e2e::alice(), &contract.add_to_list(account_id(Bob)))
+                .submit()
+                .await
+                .expect("add to list failed");
+
+            let transfer_from_tx = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.transfer_from(
+                        account_id(Alice),
+                        account_id(Alice),
+                        100,
+                        vec![],
+                    ),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(transfer_from_tx, Err(PSP22Error::AccountRestricted));
+            assert_eq!(balance_of!(client, contract, Alice), 1000);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn only_owner_can_modify_list(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_restricted",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let add_to_list_tx = client
+                .call(&ink_e2e::bob(), &contract.add_to_list(account_id(Alice)))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(
+                add_to_list_tx,
+                Err(PSP22Error::Custom("O::CallerIsNotOwner".into()))
+            );
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with snapshot extension - anyone can take a snapshot and query balances at it.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22Snapshot trait's default implementation (PSP22SnapshotDefaultImpl & PSP22SnapshotInternalDefaultImpl)
+// PSP22Snapshot also injects recording of the balances into PSP22Internal::_update
+#[pendzl::implementation(PSP22, PSP22Snapshot)]
+#[ink::contract]
+pub mod my_psp22_snapshot {
+    use pendzl::contracts::psp22::*;
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+        #[storage_field]
+        // PSP22SnapshotData is a struct that implements PSP22SnapshotStorage - required by PSP22SnapshotInternalDefaultImpl trait
+        snapshot: PSP22SnapshotData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+
+            // mint total_supply to the caller using _mint_to from PSP22Internal (implemented by PSP22DefaultImpl)
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+
+            instance
+        }
+
+        // in production contracts taking snapshots should be restricted (ex. by ownable or access control)
+        #[ink(message)]
+        pub fn take_snapshot(&mut self) -> Result<SnapshotId, PSP22Error> {
+            self._snapshot()
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob};
+        use test_helpers::balance_of;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn balances_at_snapshot_do_not_change(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_snapshot",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let snapshot_id = client
+                .call(&ink_e2e::alice(), &contract.take_snapshot())
+                .submit()
+                .await
+                .expect("snapshot failed")
+                .return_value();
+
+            assert_eq!(snapshot_id, Ok(1));
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 400, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed");
+
+            assert_eq!(balance_of!(client, contract, Alice), 600);
+            assert_eq!(balance_of!(client, contract, Bob), 400);
+
+            let alice_at = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.balance_of_at(account_id(Alice), 1),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            let bob_at = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.balance_of_at(account_id(Bob), 1),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+            let total_supply_at = client
+                .call(&ink_e2e::alice(), &contract.total_supply_at(1))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(alice_at, Some(1000));
+            assert_eq!(bob_at, Some(0));
+            assert_eq!(total_supply_at, Some(1000));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn nonexistent_snapshot_returns_none(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let contract = client
+                .instantiate(
+                    "my_psp22_snapshot",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let alice_at = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.balance_of_at(account_id(Alice), 1),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(alice_at, None);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 vault contract with asynchronous redeems (ERC7540) and synchronous deposits.
+/// Redeem requests are fulfilled by the owner, for example after the assets are returned from an illiquid strategy.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// PSP22Vault trait's default implementation (PSP22VaultDefaultImpl & PSP22VaultInternalDefaultImpl)
+// PSP22VaultAsync trait's default implementation (PSP22VaultAsyncDefaultImpl & PSP22VaultAsyncInternalDefaultImpl) guarded by Ownable
+// PSP22VaultCaps trait's default implementation (PSP22VaultCapsDefaultImpl) guarded by Ownable
+// PSP22Metadata trait's default implementation (PSP22MetadataDefaultImpl)
+// and Ownable trait's default implementation (OwnableDefaultImpl & OwnableInternalDefaultImpl)
+// PSP22VaultAsync also makes `withdraw` and `redeem` (and `max_withdraw`, `max_redeem`) of PSP22Vault claim the redeem requests
+#[pendzl::implementation(
+    PSP22,
+    PSP22Vault,
+    PSP22VaultAsync(guard = Ownable),
+    PSP22VaultCaps(guard = Ownable),
+    PSP22Metadata,
+    Ownable
+)]
+#[ink::contract]
+pub mod my_psp22_vault_async {
+    use ink::prelude::string::ToString;
+
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+        #[storage_field]
+        // PSP22VaultData is a struct that implements PSP22VaultStorage - required by PSP22VaultInternalDefaultImpl trait
+        vault: PSP22VaultData,
+        #[storage_field]
+        // PSP22VaultAsyncData is a struct that implements PSP22VaultAsyncStorage - required by PSP22VaultAsyncInternalDefaultImpl trait
+        vault_async: PSP22VaultAsyncData,
+        #[storage_field]
+        // PSP22MetadataData is a struct that implements PSP22MetadataStorage - required by PSP22MetadataInternalDefaultImpl trait
+        metadata: PSP22MetadataData,
+        #[storage_field]
+        // OwnableData is a struct that implements OwnableStorage - required by OwnableInternalDefaultImpl trait
+        ownable: OwnableData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(asset: AccountId) -> Self {
+            let mut instance = Self {
+                psp22: PSP22Data::default(),
+                vault: PSP22VaultData::new(asset, None),
+                vault_async: PSP22VaultAsyncData::default(),
+                metadata: PSP22MetadataData::new(
+                    Some("Name".to_string()),
+                    Some("Symbol".to_string()),
+                ),
+                ownable: OwnableData::default(),
+            };
+            instance._update_owner(&Some(Self::env().caller()));
+            instance
+        }
+    }
+
+    // override _is_async_deposit from PSP22VaultAsyncInternal trait's default implementation (PSP22VaultAsyncInternalDefaultImpl)
+    // so `deposit` and `mint` stay synchronous
+    #[overrider(PSP22VaultAsyncInternal)]
+    fn _is_async_deposit(&self) -> bool {
+        false
+    }
+}
@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 vault contract with flash lender extension - flash loans of the vault shares are disabled.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// PSP22Vault trait's default implementation (PSP22VaultDefaultImpl & PSP22VaultInternalDefaultImpl)
+// and PSP22FlashLender trait's default implementation (PSP22FlashLenderDefaultImpl & PSP22FlashLenderInternalDefaultImpl)
+// PSP22Vault makes `max_flash_loan` of PSP22FlashLender return 0 - flash minted shares would be counted
+// in the price of the shares deposited or minted in the flash loan callback, diluting the other holders
+#[pendzl::implementation(PSP22, PSP22Vault, PSP22FlashLender)]
+#[ink::contract]
+pub mod my_psp22_vault_flash_lender {
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+        #[storage_field]
+        // PSP22VaultData is a struct that implements PSP22VaultStorage - required by PSP22VaultInternalDefaultImpl trait
+        vault: PSP22VaultData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(asset: AccountId) -> Self {
+            Self {
+                psp22: PSP22Data::default(),
+                vault: PSP22VaultData::new(asset, None),
+            }
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::AccountKeyring::Alice;
+        use my_psp22::my_psp22::{Contract as Asset, ContractRef as AssetRef};
+        use my_psp22_flash_borrower::my_psp22_flash_borrower::ContractRef as BorrowerRef;
+        use test_helpers::{balance_of, balance_of2};
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn max_flash_loan_of_shares_is_zero(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut asset_constructor = AssetRef::new(1000);
+            let asset = client
+                .instantiate(
+                    "my_psp22",
+                    &ink_e2e::alice(),
+                    &mut asset_constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate asset failed");
+            let mut asset_call = asset.call_builder::<Asset>();
+
+            let mut constructor = ContractRef::new(asset.account_id);
+            let vault = client
+                .instantiate(
+                    "my_psp22_vault_flash_lender",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate vault failed");
+            let mut vault_call = vault.call_builder::<Contract>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &asset_call.approve(vault.account_id, 100),
+                )
+                .submit()
+                .await
+                .expect("approve failed");
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &vault_call.deposit(100, ink_e2e::account_id(Alice)),
+                )
+                .submit()
+                .await
+                .expect("deposit failed");
+
+            let max_flash_loan = client
+                .call(&ink_e2e::alice(), &vault_call.max_flash_loan())
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(max_flash_loan, 0);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn deposit_in_flash_loan_callback_fails(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut asset_constructor = AssetRef::new(1000);
+            let asset = client
+                .instantiate(
+                    "my_psp22",
+                    &ink_e2e::alice(),
+                    &mut asset_constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate asset failed");
+            let mut asset_call = asset.call_builder::<Asset>();
+
+            let mut constructor = ContractRef::new(asset.account_id);
+            let vault = client
+                .instantiate(
+                    "my_psp22_vault_flash_lender",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate vault failed");
+            let mut vault_call = vault.call_builder::<Contract>();
+
+            let mut borrower_constructor = BorrowerRef::new_vault_depositor();
+            let borrower = client
+                .instantiate(
+                    "my_psp22_flash_borrower",
+                    &ink_e2e::alice(),
+                    &mut borrower_constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate borrower failed");
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &asset_call.approve(vault.account_id, 100),
+                )
+                .submit()
+                .await
+                .expect("approve failed");
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &vault_call.deposit(100, ink_e2e::account_id(Alice)),
+                )
+                .submit()
+                .await
+                .expect("deposit failed");
+            // the borrower deposits these assets in the flash loan callback
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &asset_call.transfer(borrower.account_id, 100, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed");
+
+            let flash_loan = client
+                .call(
+                    &ink_e2e::alice(),
+                    &vault_call.flash_loan(borrower.account_id, 1000, vec![]),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(flash_loan, Err(PSP22Error::MaxFlashLoanExceeded));
+            assert_eq!(
+                balance_of2!(client, vault_call, borrower.account_id),
+                0
+            );
+            assert_eq!(balance_of!(client, vault_call, Alice), 100);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 vault contract charging a performance fee on the profit above the high-water mark.
+/// The fee and its recipient are set by the owner, `harvest` can be called by anyone.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// PSP22Vault trait's default implementation (PSP22VaultDefaultImpl & PSP22VaultInternalDefaultImpl)
+// PSP22VaultPerformanceFee trait's default implementation (PSP22VaultPerformanceFeeDefaultImpl & PSP22VaultPerformanceFeeInternalDefaultImpl)
+// PSP22Metadata trait's default implementation (PSP22MetadataDefaultImpl)
+// and Ownable trait's default implementation (OwnableDefaultImpl & OwnableInternalDefaultImpl)
+#[pendzl::implementation(
+    PSP22,
+    PSP22Vault,
+    PSP22VaultPerformanceFee,
+    PSP22Metadata,
+    Ownable
+)]
+#[ink::contract]
+pub mod my_psp22_vault_performance_fee {
+    use ink::prelude::string::ToString;
+
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+        #[storage_field]
+        // PSP22VaultData is a struct that implements PSP22VaultStorage - required by PSP22VaultInternalDefaultImpl trait
+        vault: PSP22VaultData,
+        #[storage_field]
+        // PSP22VaultPerformanceFeeData is a struct that implements PSP22VaultPerformanceFeeStorage - required by PSP22VaultPerformanceFeeInternalDefaultImpl trait
+        performance_fee: PSP22VaultPerformanceFeeData,
+        #[storage_field]
+        // PSP22MetadataData is a struct that implements PSP22MetadataStorage - required by PSP22MetadataInternalDefaultImpl trait
+        metadata: PSP22MetadataData,
+        #[storage_field]
+        // OwnableData is a struct that implements OwnableStorage - required by OwnableInternalDefaultImpl trait
+        ownable: OwnableData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(asset: AccountId) -> Self {
+            let mut instance = Self {
+                psp22: PSP22Data::default(),
+                vault: PSP22VaultData::new(asset, None),
+                performance_fee: PSP22VaultPerformanceFeeData::default(),
+                metadata: PSP22MetadataData::new(
+                    Some("Name".to_string()),
+                    Some("Symbol".to_string()),
+                ),
+                ownable: OwnableData::default(),
+            };
+            instance._update_owner(&Some(Self::env().caller()));
+            instance
+        }
+
+        // sets the performance fee (in basis points) and its recipient
+        #[ink(message)]
+        pub fn set_performance_fee(
+            &mut self,
+            fee_bps: u16,
+            fee_recipient: Option<AccountId>,
+        ) -> Result<(), PSP22Error> {
+            self._only_owner()?;
+            // settle the profit made so far with the old fee
+            self._harvest()?;
+            self._set_performance_fee(&fee_bps, &fee_recipient)
+        }
+    }
+}
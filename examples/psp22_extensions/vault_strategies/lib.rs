@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 vault contract deploying its idle assets to yield earning strategy contracts.
+/// The strategies and their allocation weights are managed by the owner.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// PSP22Vault trait's default implementation (PSP22VaultDefaultImpl & PSP22VaultInternalDefaultImpl)
+// PSP22VaultStrategies trait's default implementation (PSP22VaultStrategiesDefaultImpl & PSP22VaultStrategiesInternalDefaultImpl) guarded by Ownable
+// PSP22Metadata trait's default implementation (PSP22MetadataDefaultImpl)
+// and Ownable trait's default implementation (OwnableDefaultImpl & OwnableInternalDefaultImpl)
+// PSP22VaultStrategies also makes `total_assets` of PSP22Vault include the deployed assets
+// and `withdraw` and `redeem` pull the missing assets from the strategies
+#[pendzl::implementation(
+    PSP22,
+    PSP22Vault,
+    PSP22VaultStrategies(guard = Ownable),
+    PSP22Metadata,
+    Ownable
+)]
+#[ink::contract]
+pub mod my_psp22_vault_strategies {
+    use ink::prelude::string::ToString;
+
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+        #[storage_field]
+        // PSP22VaultData is a struct that implements PSP22VaultStorage - required by PSP22VaultInternalDefaultImpl trait
+        vault: PSP22VaultData,
+        #[storage_field]
+        // PSP22VaultStrategiesData is a struct that implements PSP22VaultStrategiesStorage - required by PSP22VaultStrategiesInternalDefaultImpl trait
+        strategies: PSP22VaultStrategiesData,
+        #[storage_field]
+        // PSP22MetadataData is a struct that implements PSP22MetadataStorage - required by PSP22MetadataInternalDefaultImpl trait
+        metadata: PSP22MetadataData,
+        #[storage_field]
+        // OwnableData is a struct that implements OwnableStorage - required by OwnableInternalDefaultImpl trait
+        ownable: OwnableData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(asset: AccountId) -> Self {
+            let mut instance = Self {
+                psp22: PSP22Data::default(),
+                vault: PSP22VaultData::new(asset, None),
+                strategies: PSP22VaultStrategiesData::default(),
+                metadata: PSP22MetadataData::new(
+                    Some("Name".to_string()),
+                    Some("Symbol".to_string()),
+                ),
+                ownable: OwnableData::default(),
+            };
+            instance._update_owner(&Some(Self::env().caller()));
+            instance
+        }
+    }
+}
@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract with votes extension - token holders can delegate their voting power.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22Votes trait's default implementation (PSP22VotesDefaultImpl & PSP22VotesInternalDefaultImpl)
+// PSP22Votes also injects moving of the voting units into PSP22Internal::_update
+#[pendzl::implementation(PSP22, PSP22Votes)]
+#[ink::contract]
+pub mod my_psp22_votes {
+    use pendzl::contracts::psp22::*;
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+        #[storage_field]
+        // PSP22VotesData is a struct that implements PSP22VotesStorage - required by PSP22VotesInternalDefaultImpl trait
+        votes: PSP22VotesData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+
+            // mint total_supply to the caller using _mint_to from PSP22Internal (implemented by PSP22DefaultImpl)
+            instance
+                ._mint_to(&Self::env().caller(), &total_supply)
+                .expect("Should mint");
+
+            instance
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob, Charlie};
+        use scale::Encode;
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn delegation_gives_voting_power(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_votes",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let votes = client
+                .call(&ink_e2e::alice(), &contract.get_votes(account_id(Bob)))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(votes, 0);
+
+            let delegate_tx = client
+                .call(&ink_e2e::alice(), &contract.delegate(account_id(Bob)))
+                .submit()
+                .await
+                .expect("delegate failed")
+                .return_value();
+
+            assert_eq!(delegate_tx, Ok(()));
+
+            let delegate = client
+                .call(&ink_e2e::alice(), &contract.delegates(account_id(Alice)))
+                .dry_run()
+                .await?
+                .return_value();
+            let votes = client
+                .call(&ink_e2e::alice(), &contract.get_votes(account_id(Bob)))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(delegate, Some(account_id(Bob)));
+            assert_eq!(votes, 1000);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn transfer_moves_voting_power(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_votes",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            client
+                .call(&ink_e2e::alice(), &contract.delegate(account_id(Alice)))
+                .submit()
+                .await
+                .expect("delegate failed");
+            client
+                .call(&ink_e2e::bob(), &contract.delegate(account_id(Charlie)))
+                .submit()
+                .await
+                .expect("delegate failed");
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.transfer(account_id(Bob), 300, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed");
+
+            let alice_votes = client
+                .call(&ink_e2e::alice(), &contract.get_votes(account_id(Alice)))
+                .dry_run()
+                .await?
+                .return_value();
+            let charlie_votes = client
+                .call(
+                    &ink_e2e::alice(),
+                    &contract.get_votes(account_id(Charlie)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(alice_votes, 700);
+            assert_eq!(charlie_votes, 300);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn delegate_by_sig_works(
+            client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut constructor = ContractRef::new(1000);
+            let mut contract = client
+                .instantiate(
+                    "my_psp22_votes",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate failed")
+                .call_builder::<Contract>();
+
+            let domain_separator = client
+                .call(&ink_e2e::bob(), &contract.domain_separator())
+                .dry_run()
+                .await?
+                .return_value();
+
+            let message = DelegationMessage {
+                domain_separator,
+                delegator: account_id(Alice),
+                delegatee: account_id(Bob),
+                nonce: 0,
+                expiry: u64::MAX,
+            };
+            let signature = PermitSignature::Sr25519(
+                ink_e2e::alice().sign(&message.encode()).0,
+            );
+
+            // bob submits the delegation signed by alice
+            let delegate_tx = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.delegate_by_sig(
+                        account_id(Alice),
+                        account_id(Bob),
+                        0,
+                        u64::MAX,
+                        signature,
+                    ),
+                )
+                .submit()
+                .await
+                .expect("delegate_by_sig failed")
+                .return_value();
+
+            assert_eq!(delegate_tx, Ok(()));
+
+            let votes = client
+                .call(&ink_e2e::bob(), &contract.get_votes(account_id(Bob)))
+                .dry_run()
+                .await?
+                .return_value();
+            let nonce = client
+                .call(
+                    &ink_e2e::bob(),
+                    &contract.delegation_nonces(account_id(Alice)),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(votes, 1000);
+            assert_eq!(nonce, 1);
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,339 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A PSP22 contract wrapping an underlying PSP22 token one-to-one.
+/// Surplus of the underlying token can be recovered by the owner.
+// ########################################################
+// inject PSP22 trait's default implementation (PSP22DefaultImpl & PSP22InternalDefaultImpl)
+// and PSP22Wrapper trait's default implementation (PSP22WrapperDefaultImpl & PSP22WrapperInternalDefaultImpl)
+// and Ownable trait's default implementation (OwnableDefaultImpl & OwnableInternalDefaultImpl)
+// which reduces the amount of boilerplate code required to implement trait messages drastically
+#[pendzl::implementation(PSP22, PSP22Wrapper, Ownable)]
+#[ink::contract]
+pub mod my_psp22_wrapper {
+    #[ink(storage)]
+    // derive explained below
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22Data is a struct that implements PSP22Storage - required by PSP22InternalDefaultImpl trait
+        // note it's not strictly required by PSP22 trait - just the default implementation
+        // name of the field is arbitrary
+        psp22: PSP22Data,
+        // apply the storage_field attribute so it's accessible via `self.data::<PSP22Wrapper>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // PSP22WrapperData is a struct that implements PSP22WrapperStorage - required by PSP22WrapperInternalDefaultImpl trait
+        // note it's not strictly required by PSP22Wrapper trait - just the default implementation
+        // name of the field is arbitrary
+        wrapper: PSP22WrapperData,
+        // apply the storage_field attribute so it's accessible via `self.data::<Ownable>()` (provided by StorageFieldGetter derive)
+        #[storage_field]
+        // OwnableData is a struct that implements OwnableStorage - required by OwnableInternalDefaultImpl trait
+        // note it's not strictly required by Ownable trait - just the default implementation
+        // name of the field is arbitrary
+        ownable: OwnableData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(underlying: AccountId) -> Self {
+            let mut instance = Self {
+                psp22: PSP22Data::default(),
+                wrapper: PSP22WrapperData::new(underlying),
+                ownable: OwnableData::default(),
+            };
+            // set the owner using _update_owner from OwnableInternal (implemented by OwnableDefaultImpl)
+            instance._update_owner(&Some(Self::env().caller()));
+            instance
+        }
+
+        #[ink(message)]
+        // mint wrapped tokens covering the underlying tokens that were transferred directly to the contract
+        pub fn recover(
+            &mut self,
+            to: AccountId,
+        ) -> Result<Balance, PSP22Error> {
+            self._only_owner()?;
+            self._recover(&to)
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        #[rustfmt::skip]
+        use super::*;
+        #[rustfmt::skip]
+        use ink_e2e::ContractsBackend;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob};
+        use my_psp22::my_psp22::{
+            Contract as Underlying, ContractRef as UnderlyingRef,
+        };
+        use test_helpers::{balance_of, balance_of2};
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn deposit_for_mints_wrapped_tokens(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut underlying_constructor = UnderlyingRef::new(1000);
+            let underlying = client
+                .instantiate(
+                    "my_psp22",
+                    &ink_e2e::alice(),
+                    &mut underlying_constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate underlying failed");
+            let mut underlying_call = underlying.call_builder::<Underlying>();
+
+            let mut constructor = ContractRef::new(underlying.account_id);
+            let wrapper = client
+                .instantiate(
+                    "my_psp22_wrapper",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate wrapper failed");
+            let wrapper_account = wrapper.account_id;
+            let mut wrapper_call = wrapper.call_builder::<Contract>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &underlying_call.approve(wrapper_account, 100),
+                )
+                .submit()
+                .await
+                .expect("approve failed");
+
+            let deposit_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &wrapper_call.deposit_for(account_id(Bob), 100),
+                )
+                .submit()
+                .await
+                .expect("deposit for failed")
+                .return_value();
+
+            assert_eq!(deposit_tx, Ok(()));
+            assert_eq!(balance_of!(client, wrapper_call, Bob), 100);
+            assert_eq!(balance_of!(client, underlying_call, Alice), 900);
+            assert_eq!(
+                balance_of2!(client, underlying_call, wrapper_account),
+                100
+            );
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn withdraw_to_transfers_underlying_tokens(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut underlying_constructor = UnderlyingRef::new(1000);
+            let underlying = client
+                .instantiate(
+                    "my_psp22",
+                    &ink_e2e::alice(),
+                    &mut underlying_constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate underlying failed");
+            let mut underlying_call = underlying.call_builder::<Underlying>();
+
+            let mut constructor = ContractRef::new(underlying.account_id);
+            let wrapper = client
+                .instantiate(
+                    "my_psp22_wrapper",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate wrapper failed");
+            let wrapper_account = wrapper.account_id;
+            let mut wrapper_call = wrapper.call_builder::<Contract>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &underlying_call.approve(wrapper_account, 100),
+                )
+                .submit()
+                .await
+                .expect("approve failed");
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &wrapper_call.deposit_for(account_id(Alice), 100),
+                )
+                .submit()
+                .await
+                .expect("deposit for failed");
+
+            let withdraw_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &wrapper_call.withdraw_to(account_id(Bob), 40),
+                )
+                .submit()
+                .await
+                .expect("withdraw to failed")
+                .return_value();
+
+            assert_eq!(withdraw_tx, Ok(()));
+            assert_eq!(balance_of!(client, wrapper_call, Alice), 60);
+            assert_eq!(balance_of!(client, underlying_call, Bob), 40);
+            assert_eq!(
+                balance_of2!(client, underlying_call, wrapper_account),
+                60
+            );
+
+            let withdraw_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &wrapper_call.withdraw_to(account_id(Bob), 61),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(withdraw_tx, Err(PSP22Error::InsufficientBalance));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn can_not_deposit_for_wrapper(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut underlying_constructor = UnderlyingRef::new(1000);
+            let underlying = client
+                .instantiate(
+                    "my_psp22",
+                    &ink_e2e::alice(),
+                    &mut underlying_constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate underlying failed");
+            let mut underlying_call = underlying.call_builder::<Underlying>();
+
+            let mut constructor = ContractRef::new(underlying.account_id);
+            let wrapper = client
+                .instantiate(
+                    "my_psp22_wrapper",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate wrapper failed");
+            let wrapper_account = wrapper.account_id;
+            let mut wrapper_call = wrapper.call_builder::<Contract>();
+
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &underlying_call.approve(wrapper_account, 100),
+                )
+                .submit()
+                .await
+                .expect("approve failed");
+
+            let deposit_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &wrapper_call.deposit_for(wrapper_account, 100),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(deposit_tx, Err(PSP22Error::InvalidDepositRecipient));
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn owner_can_recover_surplus(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut underlying_constructor = UnderlyingRef::new(1000);
+            let underlying = client
+                .instantiate(
+                    "my_psp22",
+                    &ink_e2e::alice(),
+                    &mut underlying_constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate underlying failed");
+            let mut underlying_call = underlying.call_builder::<Underlying>();
+
+            let mut constructor = ContractRef::new(underlying.account_id);
+            let wrapper = client
+                .instantiate(
+                    "my_psp22_wrapper",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate wrapper failed");
+            let wrapper_account = wrapper.account_id;
+            let mut wrapper_call = wrapper.call_builder::<Contract>();
+
+            // underlying tokens transferred directly are not wrapped
+            client
+                .call(
+                    &ink_e2e::alice(),
+                    &underlying_call.transfer(wrapper_account, 50, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed");
+
+            let recover_tx = client
+                .call(&ink_e2e::bob(), &wrapper_call.recover(account_id(Bob)))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(
+                recover_tx,
+                Err(PSP22Error::Custom("O::CallerIsNotOwner".into()))
+            );
+
+            let recover_tx = client
+                .call(&ink_e2e::alice(), &wrapper_call.recover(account_id(Bob)))
+                .submit()
+                .await
+                .expect("recover failed")
+                .return_value();
+
+            assert_eq!(recover_tx, Ok(50));
+            assert_eq!(balance_of!(client, wrapper_call, Bob), 50);
+
+            let recover_tx = client
+                .call(&ink_e2e::alice(), &wrapper_call.recover(account_id(Bob)))
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(recover_tx, Ok(0));
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,197 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Contract Summary:
+/// A contract accepting transfers of a single PSP22 token and rejecting all others.
+// ########################################################
+#[ink::contract]
+pub mod my_psp22_receiver {
+    use ink::prelude::{string::String, vec::Vec};
+    use pendzl::contracts::psp22::{PSP22Receiver, PSP22ReceiverError};
+
+    #[ink(storage)]
+    pub struct Contract {
+        accepted_token: AccountId,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(accepted_token: AccountId) -> Self {
+            Self { accepted_token }
+        }
+    }
+
+    impl PSP22Receiver for Contract {
+        #[ink(message)]
+        fn before_received(
+            &mut self,
+            _from: AccountId,
+            _operator: AccountId,
+            _value: Balance,
+            _data: Vec<u8>,
+        ) -> Result<(), PSP22ReceiverError> {
+            // the caller is the token that is being transferred
+            if Self::env().caller() != self.accepted_token {
+                return Err(PSP22ReceiverError::TransferRejected(
+                    String::from("token not accepted"),
+                ));
+            }
+            Ok(())
+        }
+    }
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    pub mod tests {
+        use super::*;
+        use ink_e2e::account_id;
+        use ink_e2e::AccountKeyring::{Alice, Bob};
+        use ink_e2e::ContractsBackend;
+        use my_psp22::my_psp22::{Contract as Token, ContractRef as TokenRef};
+        use pendzl::contracts::psp22::{PSP22Error, PSP22};
+        use test_helpers::{balance_of, balance_of2};
+
+        type E2EResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+        #[ink_e2e::test]
+        async fn accepted_token_can_be_transferred(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut token_constructor = TokenRef::new(1000);
+            let token = client
+                .instantiate(
+                    "my_psp22",
+                    &ink_e2e::alice(),
+                    &mut token_constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate token failed");
+            let mut token_call = token.call_builder::<Token>();
+
+            let mut constructor = ContractRef::new(token.account_id);
+            let receiver = client
+                .instantiate(
+                    "my_psp22_receiver",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate receiver failed");
+
+            let transfer_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &token_call.transfer(receiver.account_id, 100, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed")
+                .return_value();
+
+            assert_eq!(transfer_tx, Ok(()));
+
+            let receiver_account = receiver.account_id;
+            assert_eq!(balance_of2!(client, token_call, receiver_account), 100);
+            assert_eq!(balance_of!(client, token_call, Alice), 900);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn other_token_is_rejected(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut token_constructor = TokenRef::new(1000);
+            let mut token_call = client
+                .instantiate(
+                    "my_psp22",
+                    &ink_e2e::alice(),
+                    &mut token_constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate token failed")
+                .call_builder::<Token>();
+
+            let mut constructor = ContractRef::new(account_id(Bob));
+            let receiver = client
+                .instantiate(
+                    "my_psp22_receiver",
+                    &ink_e2e::alice(),
+                    &mut constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate receiver failed");
+
+            let transfer_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &token_call.transfer(receiver.account_id, 100, vec![]),
+                )
+                .dry_run()
+                .await?
+                .return_value();
+
+            assert_eq!(
+                transfer_tx,
+                Err(PSP22Error::SafeTransferCheckFailed(String::from(
+                    "token not accepted"
+                )))
+            );
+
+            let receiver_account = receiver.account_id;
+            assert_eq!(balance_of2!(client, token_call, receiver_account), 0);
+            assert_eq!(balance_of!(client, token_call, Alice), 1000);
+
+            Ok(())
+        }
+
+        #[ink_e2e::test]
+        async fn contract_without_receiver_accepts_transfer(
+            mut client: ink_e2e::Client<C, E>,
+        ) -> E2EResult<()> {
+            let mut token_constructor = TokenRef::new(1000);
+            let mut token_call = client
+                .instantiate(
+                    "my_psp22",
+                    &ink_e2e::alice(),
+                    &mut token_constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate token failed")
+                .call_builder::<Token>();
+
+            // a PSP22 token doesn't implement PSP22Receiver
+            let mut other_constructor = TokenRef::new(0);
+            let other_token = client
+                .instantiate(
+                    "my_psp22",
+                    &ink_e2e::alice(),
+                    &mut other_constructor,
+                )
+                .submit()
+                .await
+                .expect("instantiate other token failed");
+
+            let transfer_tx = client
+                .call(
+                    &ink_e2e::alice(),
+                    &token_call.transfer(other_token.account_id, 100, vec![]),
+                )
+                .submit()
+                .await
+                .expect("transfer failed")
+                .return_value();
+
+            assert_eq!(transfer_tx, Ok(()));
+
+            let other_account = other_token.account_id;
+            assert_eq!(balance_of2!(client, token_call, other_account), 100);
+
+            Ok(())
+        }
+    }
+}
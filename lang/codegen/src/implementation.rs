@@ -15,19 +15,26 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
     }
     let input: TokenStream = ink_module;
 
-    // map attribute args to default contract names
+    // map attribute args to default contract names, their optional guards and flags
     let args = syn::parse2::<AttributeArgs>(attrs)
         .expect("No default contracts to implement provided")
         .iter()
         .map(|arg| match arg {
-            NestedMeta::Path(method) => {
-                method.to_token_stream().to_string().replace(' ', "")
-            }
-            _ => panic!(
-                "Expected names of pendzl traits to implement in the contract!"
+            NestedMeta::Path(method) => (
+                method.to_token_stream().to_string().replace(' ', ""),
+                None,
+                vec![],
             ),
+            NestedMeta::List(list) => {
+                let (guard, flags) = extract_options(list);
+                (
+                    list.path.to_token_stream().to_string().replace(' ', ""),
+                    guard,
+                    flags,
+                )
+            }
         })
-        .collect::<Vec<String>>();
+        .collect::<Vec<(String, Option<Guard>, Vec<String>)>>();
 
     let mut module = syn::parse2::<syn::ItemMod>(input)
         .expect("Can't parse contract module");
@@ -50,22 +57,57 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
     let mut imports = HashMap::<&str, syn::ItemUse>::default();
     // if multiple contracts are using the same trait implemented differently we override it this way
     let mut overriden_traits = HashMap::<&str, syn::Item>::default();
+    // statements that extensions inject into fns of other traits
+    let mut hooks = FnHooksMap::default();
 
     let mut impl_args = ImplArgs::new(
         &map,
         &mut items,
         &mut imports,
         &mut overriden_traits,
+        &mut hooks,
         ident,
     );
 
-    for to_default_implement in &args {
+    for (to_default_implement, guard, flags) in &args {
         match to_default_implement.as_str() {
             "PSP22" => impl_psp22(&mut impl_args),
             "PSP22Burnable" => impl_psp22_burnable(&mut impl_args),
+            "PSP22BurnableFrom" => impl_psp22_burnable_from(&mut impl_args),
+            "PSP22BurnableSelf" => impl_psp22_burnable_self(&mut impl_args),
             "PSP22Mintable" => impl_psp22_mintable(&mut impl_args),
             "PSP22Vault" => impl_psp22_vault(&mut impl_args),
+            "PSP22VaultCaps" => impl_psp22_vault_caps(&mut impl_args),
+            "PSP22VaultAsync" => impl_psp22_vault_async(&mut impl_args),
+            "PSP22VaultStrategies" => {
+                impl_psp22_vault_strategies(&mut impl_args)
+            }
+            "PSP22VaultPerformanceFee" => {
+                impl_psp22_vault_performance_fee(&mut impl_args)
+            }
             "PSP22Metadata" => impl_psp22_metadata(&mut impl_args),
+            "PSP22Permit" => impl_psp22_permit(&mut impl_args),
+            "PSP22Capped" => impl_psp22_capped(&mut impl_args),
+            "PSP22Snapshot" => impl_psp22_snapshot(&mut impl_args),
+            "PSP22Votes" => impl_psp22_votes(&mut impl_args),
+            "PSP22FlashLender" => impl_psp22_flash_lender(&mut impl_args),
+            "PSP22Wrapper" => impl_psp22_wrapper(&mut impl_args),
+            "PSP22Batch" => impl_psp22_batch(&mut impl_args),
+            "PSP22Fee" => impl_psp22_fee(&mut impl_args),
+            "PSP22Restricted" => impl_psp22_restricted(&mut impl_args),
+            "PSP22Rebasing" => impl_psp22_rebasing(&mut impl_args),
+            "PSP22Lockable" => impl_psp22_lockable(&mut impl_args),
+            "PSP22MetadataMutable" => {
+                impl_psp22_metadata_mutable(&mut impl_args)
+            }
+            "PSP22ExpiringAllowance" => {
+                impl_psp22_expiring_allowance(&mut impl_args)
+            }
+            "PSP22MintAllowance" => impl_psp22_mint_allowance(&mut impl_args),
+            "PSP22Dividends" => impl_psp22_dividends(&mut impl_args),
+            "PSP22NativeWrapper" => {
+                impl_psp22_native_wrapper(&mut impl_args)
+            }
             "PSP34" => impl_psp34(&mut impl_args),
             "PSP34Burnable" => impl_psp34_burnable(&mut impl_args),
             "PSP34Metadata" => impl_psp34_metadata(&mut impl_args),
@@ -77,8 +119,26 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
             "SetCodeHash" => impl_set_code_hash(&mut impl_args),
             _ => panic!("pendzl::implementation({to_default_implement}) not implemented!"),
         }
+        if let Some(guard) = guard {
+            impl_args.add_guard(to_default_implement, guard);
+        } else if GUARD_REQUIRED.contains(&to_default_implement.as_str()) {
+            panic!(
+                "pendzl::implementation({to_default_implement}) requires a guard, ex. {to_default_implement}(guard = Ownable) or {to_default_implement}(guard = None) to let anyone call it!"
+            );
+        }
+        for flag in flags {
+            impl_args.add_flag(to_default_implement, flag);
+        }
     }
 
+    let implemented = args
+        .iter()
+        .map(|(to_default_implement, ..)| to_default_implement.as_str())
+        .collect::<Vec<_>>();
+    impl_args.combine(&implemented);
+
+    inject_hooks(impl_args.items, impl_args.hooks);
+
     cleanup_imports(impl_args.imports);
 
     let import_storage = syn::parse2::<syn::ItemUse>(quote!(
@@ -109,6 +169,71 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
     }
 }
 
+// traits with privileged messages that must not be callable by anyone by accident - `guard = None` opts out explicitly
+const GUARD_REQUIRED: [&str; 10] = [
+    "PSP22Mintable",
+    "PSP22Burnable",
+    "PSP22Fee",
+    "PSP22Restricted",
+    "PSP22Lockable",
+    "PSP22MetadataMutable",
+    "PSP22MintAllowance",
+    "PSP22VaultCaps",
+    "PSP22VaultAsync",
+    "PSP22VaultStrategies",
+];
+
+// parses `guard = ...` and flags (ex. `infinite_allowance`) from `Trait(guard = ..., flag)` arg
+fn extract_options(list: &MetaList) -> (Option<Guard>, Vec<String>) {
+    let trait_name = list.path.to_token_stream().to_string();
+    let mut guard = None;
+    let mut flags = vec![];
+    for option in list.nested.iter() {
+        match option {
+            syn::Expr::Assign(assign) => match assign.left.as_ref() {
+                syn::Expr::Path(left)
+                    if left.path.is_ident("guard") && guard.is_none() =>
+                {
+                    guard = Some(extract_guard(&trait_name, &assign.right));
+                }
+                _ => panic!(
+                    "Expected `{trait_name}(guard = ...)`, the guard can be set once!"
+                ),
+            },
+            syn::Expr::Path(flag) if flag.path.get_ident().is_some() => {
+                flags.push(flag.to_token_stream().to_string());
+            }
+            _ => panic!(
+                "Expected `{trait_name}(guard = ...)` or `{trait_name}(flag)`!"
+            ),
+        }
+    }
+    (guard, flags)
+}
+
+// parses `Ownable`, `AccessControl(ROLE)` or `None` from `guard = ...`
+fn extract_guard(trait_name: &str, value: &syn::Expr) -> Guard {
+    let expected = format!(
+        "Expected `{trait_name}(guard = Ownable)`, `{trait_name}(guard = AccessControl(ROLE))` or `{trait_name}(guard = None)`!"
+    );
+    match value {
+        syn::Expr::Path(guard) if guard.path.is_ident("Ownable") => {
+            Guard::Ownable
+        }
+        syn::Expr::Path(guard) if guard.path.is_ident("None") => Guard::None,
+        syn::Expr::Call(guard) if guard.args.len() == 1 => match guard
+            .func
+            .as_ref()
+        {
+            syn::Expr::Path(func) if func.path.is_ident("AccessControl") => {
+                Guard::AccessControl(Box::new(guard.args[0].clone()))
+            }
+            _ => panic!("{expected}"),
+        },
+        _ => panic!("{expected}"),
+    }
+}
+
 //TODO verify
 fn cleanup_imports(imports: &mut HashMap<&str, syn::ItemUse>) {
     // we will remove unnecessary imports
@@ -149,7 +274,9 @@ fn consume_overriders(
                 // remove the overrider attribute
                 let to_remove_idx = attributes
                     .iter()
-                    .position(|attr| is_attr(&[attr.clone()], attr_name))
+                    .position(|attr| {
+                        is_attr(std::slice::from_ref(attr), attr_name)
+                    })
                     .expect("No {attr_name} attribute found!");
                 let overrider_attribute = attributes.remove(to_remove_idx);
 
@@ -181,8 +308,7 @@ pub fn extract_storage_struct_name(items: &[syn::Item]) -> String {
                 let ink_attr_maybe = structure
                     .attrs
                     .iter()
-                    .cloned()
-                    .find(|attr| is_attr(&[attr.clone()], "ink"));
+                    .find(|attr| is_attr(std::slice::from_ref(*attr), "ink"));
 
                 if let Some(ink_attr) = ink_attr_maybe {
                     if let Ok(path) = ink_attr.parse_args::<Path>() {
@@ -15,11 +15,45 @@ pub type OverridenFnMap = HashMap<
     )>,
 >;
 
+/// Statements injected at the beginning of a trait fn body, keyed by (trait name, fn name).
+/// Used by extensions that need to hook into fns implemented by other traits (ex. `PSP22Internal::_update`).
+pub type FnHooksMap = HashMap<(&'static str, &'static str), Vec<syn::Stmt>>;
+
+/// Access check injected by `Trait(guard = ...)` args at the beginning of the guarded messages of the trait.
+pub enum Guard {
+    /// `guard = Ownable` - only the owner can call the guarded messages.
+    Ownable,
+    /// `guard = AccessControl(ROLE)` - only accounts with the ROLE can call the guarded messages.
+    AccessControl(Box<syn::Expr>),
+    /// `guard = None` - the messages are deliberately left callable by anyone.
+    None,
+}
+
+impl Guard {
+    fn check(&self) -> Option<syn::Stmt> {
+        let check = match self {
+            Guard::Ownable => quote!(
+                pendzl::contracts::ownable::OwnableInternal::_only_owner(self)?;
+            ),
+            Guard::AccessControl(role) => quote!(
+                pendzl::contracts::access_control::AccessControlInternal::_ensure_has_role(
+                    self,
+                    #role,
+                    Some(Self::env().caller()),
+                )?;
+            ),
+            Guard::None => return None,
+        };
+        Some(syn::parse2::<syn::Stmt>(check).expect("Should parse"))
+    }
+}
+
 pub struct ImplArgs<'a> {
     pub map: &'a OverridenFnMap,
     pub items: &'a mut Vec<syn::Item>,
     pub imports: &'a mut HashMap<&'a str, syn::ItemUse>,
     pub overriden_traits: &'a mut HashMap<&'a str, syn::Item>,
+    pub hooks: &'a mut FnHooksMap,
     pub storage_struct_name: String,
 }
 
@@ -29,6 +63,7 @@ impl<'a> ImplArgs<'a> {
         items: &'a mut Vec<syn::Item>,
         imports: &'a mut HashMap<&'a str, syn::ItemUse>,
         overriden_traits: &'a mut HashMap<&'a str, syn::Item>,
+        hooks: &'a mut FnHooksMap,
         storage_struct_name: String,
     ) -> Self {
         Self {
@@ -36,6 +71,7 @@ impl<'a> ImplArgs<'a> {
             items,
             imports,
             overriden_traits,
+            hooks,
             storage_struct_name,
         }
     }
@@ -51,6 +87,154 @@ impl<'a> ImplArgs<'a> {
         .expect("Should parse");
         self.imports.insert("vec", vec_import);
     }
+
+    fn add_hook(
+        &mut self,
+        trait_name: &'static str,
+        fn_name: &'static str,
+        hook: syn::Stmt,
+    ) {
+        self.hooks
+            .entry((trait_name, fn_name))
+            .or_default()
+            .push(hook);
+    }
+
+    pub(crate) fn add_guard(&mut self, trait_name: &str, guard: &Guard) {
+        let (trait_name, guarded_fns): (&'static str, &[&'static str]) =
+            match trait_name {
+                "PSP22Mintable" => ("PSP22Mintable", &["mint"]),
+                "PSP22Burnable" => ("PSP22Burnable", &["burn"]),
+                "PSP22Fee" => (
+                    "PSP22Fee",
+                    &["set_fee_bps", "set_fee_recipient", "set_fee_exempt"],
+                ),
+                "PSP22Restricted" => (
+                    "PSP22Restricted",
+                    &["add_to_list", "remove_from_list"],
+                ),
+                "PSP22Lockable" => ("PSP22Lockable", &["lock"]),
+                "PSP22MetadataMutable" => (
+                    "PSP22MetadataMutable",
+                    &["set_token_name", "set_token_symbol"],
+                ),
+                "PSP22MintAllowance" => {
+                    ("PSP22MintAllowance", &["set_minter_allowance"])
+                }
+                "PSP22VaultCaps" => (
+                    "PSP22VaultCaps",
+                    &["set_deposit_cap", "set_receiver_cap"],
+                ),
+                "PSP22VaultStrategies" => (
+                    "PSP22VaultStrategies",
+                    &[
+                        "add_strategy",
+                        "remove_strategy",
+                        "force_remove_strategy",
+                        "set_strategy_weight",
+                        "rebalance",
+                    ],
+                ),
+                "PSP22VaultAsync" => {
+                    ("PSP22VaultAsync", &["fulfill_deposit", "fulfill_redeem"])
+                }
+                _ => panic!(
+                    "pendzl::implementation({trait_name}) does not support guards!"
+                ),
+            };
+        let Some(check) = guard.check() else {
+            return;
+        };
+        for fn_name in guarded_fns {
+            self.add_hook(trait_name, fn_name, check.clone());
+        }
+    }
+
+    /// Adjusts the implementations of extensions that have to be aware of each other, when all of them are implemented.
+    pub(crate) fn combine(&mut self, implemented: &[&str]) {
+        // flash minted shares of a Vault would dilute the shares deposited or minted in the flash loan callback
+        if implemented.contains(&"PSP22Vault")
+            && implemented.contains(&"PSP22FlashLender")
+        {
+            self.replace_default_body(
+                "PSP22FlashLenderInternal",
+                "_max_flash_loan",
+                quote!({ 0 }),
+            );
+        }
+    }
+
+    /// Replaces the body of a generated fn, unless it is overriden by the user with `#[overrider]`.
+    fn replace_default_body(
+        &mut self,
+        trait_name: &str,
+        fn_name: &str,
+        body: proc_macro2::TokenStream,
+    ) {
+        let overriden = self.map.get(trait_name).is_some_and(|overrides| {
+            overrides.iter().any(|(name, _)| name == fn_name)
+        });
+        if overriden {
+            return;
+        }
+        let method = self
+            .items
+            .iter_mut()
+            .filter_map(|item| match item {
+                syn::Item::Impl(implementation) => Some(implementation),
+                _ => None,
+            })
+            .filter(|implementation| {
+                implementation.trait_.as_ref().is_some_and(|(_, path, _)| {
+                    path.segments
+                        .last()
+                        .is_some_and(|segment| segment.ident == trait_name)
+                })
+            })
+            .flat_map(|implementation| implementation.items.iter_mut())
+            .find_map(|item| match item {
+                syn::ImplItem::Method(method)
+                    if method.sig.ident == fn_name =>
+                {
+                    Some(method)
+                }
+                _ => None,
+            })
+            .unwrap_or_else(|| {
+                panic!("Could not find fn {fn_name} in trait {trait_name}")
+            });
+        method.block = syn::parse2::<Block>(body).expect("Should parse");
+    }
+
+    /// Switches on an optional behaviour of a trait requested by a `Trait(flag)` arg.
+    pub(crate) fn add_flag(&mut self, trait_name: &str, flag: &str) {
+        match (trait_name, flag) {
+            // allowance of Balance::MAX is unlimited - spending it doesn't write the allowance nor emit `Approval`.
+            // The owner decreasing its own allowance (ex. via `decrease_allowance`) still decreases it.
+            ("PSP22", "infinite_allowance") => {
+                let hook = syn::parse2::<syn::Stmt>(quote!(
+                    if pendzl::contracts::psp22::PSP22Internal::_allowance(self, owner, spender) == Balance::MAX {
+                        return Ok(());
+                    }
+                ))
+                .expect("Should parse");
+                self.add_hook("PSP22Internal", "_spend_allowance", hook);
+            }
+            // `PSP22Receiver::before_received` is called on the recipient contract of `transfer` and `transfer_from`.
+            ("PSP22", "safe_transfer") => {
+                let hook = syn::parse2::<syn::Stmt>(quote!(
+                    pendzl::contracts::psp22::PSP22InternalDefaultImpl::_do_safe_transfer_check_default_impl(
+                        self, _from, _to, _operator, _value, _data,
+                    )?;
+                ))
+                .expect("Should parse");
+                self.add_hook("PSP22Internal", "_do_safe_transfer_check", hook);
+            }
+            _ => panic!(
+                "pendzl::implementation({trait_name}) does not support `{flag}`!"
+            ),
+        }
+    }
 }
 
 pub(crate) fn impl_psp22(impl_args: &mut ImplArgs) {
@@ -126,6 +310,15 @@ pub(crate) fn impl_psp22(impl_args: &mut ImplArgs) {
                 pendzl::contracts::psp22::PSP22InternalDefaultImpl::_decrease_allowance_from_to_default_impl(self, owner, spender, amount)
             }
 
+            fn _spend_allowance(
+                &mut self,
+                owner: &AccountId,
+                spender: &AccountId,
+                amount: &Balance,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::PSP22Internal::_decrease_allowance_from_to(self, owner, spender, amount)
+            }
+
             fn _increase_allowance_from_to(
                 &mut self,
                 owner: &AccountId,
@@ -135,6 +328,18 @@ pub(crate) fn impl_psp22(impl_args: &mut ImplArgs) {
                 pendzl::contracts::psp22::PSP22InternalDefaultImpl::_increase_allowance_from_to_default_impl(self, owner, spender, amount)
 
             }
+
+            // the check is switched on by the `safe_transfer` flag
+            fn _do_safe_transfer_check(
+                &mut self,
+                _from: &AccountId,
+                _to: &AccountId,
+                _operator: &AccountId,
+                _value: &Balance,
+                _data: &[u8],
+            ) -> Result<(), PSP22Error> {
+                Ok(())
+            }
         }
     ))
     .expect("Should parse");
@@ -249,6 +454,72 @@ pub(crate) fn impl_psp22_burnable(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(burnable));
 }
 
+pub(crate) fn impl_psp22_burnable_from(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let burnable_from_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::burnable::PSP22BurnableFromDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut burnable_from = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::burnable::PSP22BurnableFrom for #storage_struct_name {
+            #[ink(message)]
+            fn burn_from(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::burnable::PSP22BurnableFromDefaultImpl::burn_from_default_impl(self, account, amount)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::burnable::*;
+    ))
+    .expect("Should parse");
+
+    impl_args.imports.insert("PSP22Burnable", import);
+    impl_args.vec_import();
+
+    override_functions("PSP22BurnableFrom", &mut burnable_from, impl_args.map);
+
+    impl_args
+        .items
+        .push(syn::Item::Impl(burnable_from_default_impl));
+    impl_args.items.push(syn::Item::Impl(burnable_from));
+}
+
+pub(crate) fn impl_psp22_burnable_self(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let burnable_self_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::burnable::PSP22BurnableSelfDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut burnable_self = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::burnable::PSP22BurnableSelf for #storage_struct_name {
+            #[ink(message)]
+            fn burn_self(&mut self, amount: Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::burnable::PSP22BurnableSelfDefaultImpl::burn_self_default_impl(self, amount)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::burnable::*;
+    ))
+    .expect("Should parse");
+
+    impl_args.imports.insert("PSP22Burnable", import);
+    impl_args.vec_import();
+
+    override_functions("PSP22BurnableSelf", &mut burnable_self, impl_args.map);
+
+    impl_args
+        .items
+        .push(syn::Item::Impl(burnable_self_default_impl));
+    impl_args.items.push(syn::Item::Impl(burnable_self));
+}
+
 pub(crate) fn impl_psp22_mintable(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let mintable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
@@ -351,6 +622,22 @@ pub(crate) fn impl_psp22_vault(impl_args: &mut ImplArgs) {
                 pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_total_assets_default_impl(self)
             }
 
+            fn _idle_assets(&self) -> Balance {
+                pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_idle_assets_default_impl(self)
+            }
+
+            fn _deployed_assets(&self) -> Balance {
+                pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_deployed_assets_default_impl(self)
+            }
+
+            fn _reserved_assets(&self) -> Balance {
+                pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_reserved_assets_default_impl(self)
+            }
+
+            fn _set_reserved_assets(&mut self, reserved_assets: &Balance) {
+                pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_set_reserved_assets_default_impl(self, reserved_assets)
+            }
+
             fn _convert_to_shares(&self, assets: &Balance, rounding: Rounding) -> Result<Balance, MathError> {
                 pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_convert_to_shares_default_impl(self, assets, rounding)
             }
@@ -368,6 +655,22 @@ pub(crate) fn impl_psp22_vault(impl_args: &mut ImplArgs) {
                 pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_max_mint_default_impl(self, to)
             }
 
+            fn _deposit_cap(&self) -> Option<Balance> {
+                pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_deposit_cap_default_impl(self)
+            }
+
+            fn _receiver_cap(&self, receiver: &AccountId) -> Option<Balance> {
+                pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_receiver_cap_default_impl(self, receiver)
+            }
+
+            fn _set_deposit_cap(&mut self, cap: &Option<Balance>) {
+                pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_set_deposit_cap_default_impl(self, cap)
+            }
+
+            fn _set_receiver_cap(&mut self, receiver: &Option<AccountId>, cap: &Option<Balance>) {
+                pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_set_receiver_cap_default_impl(self, receiver, cap)
+            }
+
             fn _max_withdraw(&self, owner: &AccountId) -> Balance {
                 pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_max_withdraw_default_impl(self, owner)
             }
@@ -392,6 +695,30 @@ pub(crate) fn impl_psp22_vault(impl_args: &mut ImplArgs) {
                 pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_preview_redeem_default_impl(self, shares)
             }
 
+            fn _deposit_fee_bps(&self) -> u16 {
+                pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_deposit_fee_bps_default_impl(self)
+            }
+
+            fn _withdraw_fee_bps(&self) -> u16 {
+                pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_withdraw_fee_bps_default_impl(self)
+            }
+
+            fn _vault_fee_recipient(&self) -> Option<AccountId> {
+                pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_vault_fee_recipient_default_impl(self)
+            }
+
+            fn _set_vault_fees(&mut self, deposit_fee_bps: &u16, withdraw_fee_bps: &u16, fee_recipient: &Option<AccountId>) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_set_vault_fees_default_impl(self, deposit_fee_bps, withdraw_fee_bps, fee_recipient)
+            }
+
+            fn _fee_on_raw(&self, assets: &Balance, fee_bps: &u16) -> Result<Balance, MathError> {
+                pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_fee_on_raw_default_impl(self, assets, fee_bps)
+            }
+
+            fn _fee_on_total(&self, assets: &Balance, fee_bps: &u16) -> Result<Balance, MathError> {
+                pendzl::contracts::psp22::vault::PSP22VaultInternalDefaultImpl::_fee_on_total_default_impl(self, assets, fee_bps)
+            }
+
             fn _deposit(
                 &mut self,
                 caller: &AccountId,
@@ -539,721 +866,2800 @@ pub(crate) fn impl_psp22_vault(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(psp22_vault));
 }
 
-pub(crate) fn impl_psp34(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_psp22_capped(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::psp34::PSP34InternalDefaultImpl for #storage_struct_name {}
+        impl pendzl::contracts::psp22::capped::PSP22CappedInternalDefaultImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
     let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::psp34::PSP34Internal for #storage_struct_name {
-            fn _balance_of(&self, owner: &AccountId) -> u32 {
-                pendzl::contracts::psp34::PSP34InternalDefaultImpl::_balance_of_default_impl(self, owner)
+        impl pendzl::contracts::psp22::capped::PSP22CappedInternal for #storage_struct_name {
+            fn _cap(&self) -> Balance {
+                pendzl::contracts::psp22::capped::PSP22CappedInternalDefaultImpl::_cap_default_impl(self)
             }
 
-            fn _total_supply(&self) -> u64 {
-                pendzl::contracts::psp34::PSP34InternalDefaultImpl::_total_supply_default_impl(self)
+            fn _set_cap(&mut self, cap: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::capped::PSP22CappedInternalDefaultImpl::_set_cap_default_impl(self, cap)
             }
 
-            fn _owner_of(&self, id: &Id) -> Option<AccountId> {
-                pendzl::contracts::psp34::PSP34InternalDefaultImpl::_owner_of_default_impl(self,id)
+            fn _ensure_cap_not_exceeded(&self, amount: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::capped::PSP22CappedInternalDefaultImpl::_ensure_cap_not_exceeded_default_impl(self, amount)
             }
+        }
+    ))
+    .expect("Should parse");
 
-            fn _allowance(&self, owner: &AccountId, operator: &AccountId, id: &Option<Id>) -> bool {
-                pendzl::contracts::psp34::PSP34InternalDefaultImpl::_allowance_default_impl(self, owner, operator, id)
-            }
+    let capped_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::capped::PSP22CappedDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
 
-            fn _approve(&mut self, owner: &AccountId,operator: &AccountId, id: &Option<Id>, approved: &bool) -> Result<(), PSP34Error> {
-                pendzl::contracts::psp34::PSP34InternalDefaultImpl::_approve_default_impl(self, owner, operator, id, approved)
+    let mut capped = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::capped::PSP22Capped for #storage_struct_name {
+            #[ink(message)]
+            fn cap(&self) -> Balance {
+                pendzl::contracts::psp22::capped::PSP22CappedDefaultImpl::cap_default_impl(self)
             }
+        }
+    ))
+    .expect("Should parse");
 
-            fn _update(
-                &mut self,
-                from: &Option<&AccountId>,
-                to: &Option<&AccountId>,
-                id: &Id,
-            ) -> Result<(), PSP34Error>{
-                pendzl::contracts::psp34::PSP34InternalDefaultImpl::_update_default_impl(self, from, to, id)
+    let update_hook = syn::parse2::<syn::Stmt>(quote!(
+        if from.is_none() {
+            pendzl::contracts::psp22::capped::PSP22CappedInternal::_ensure_cap_not_exceeded(self, amount)?;
+        }
+    ))
+    .expect("Should parse");
 
-            }
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::capped::*;
+    ))
+    .expect("Should parse");
 
-            fn _transfer(&mut self, from: &AccountId, to: &AccountId, id: &Id, data: &Vec<u8>) -> Result<(), PSP34Error> {
-                pendzl::contracts::psp34::PSP34Internal::_update(self, &Some(from), &Some(to), id)
-            }
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::capped::PSP22CappedData;
+    ))
+    .expect("Should parse import");
 
-            fn _mint_to(&mut self, to: &AccountId, id: &Id) -> Result<(), PSP34Error> {
-                pendzl::contracts::psp34::PSP34Internal::_update(self, &None, &Some(to), id)
-            }
+    impl_args.imports.insert("PSP22Capped", import);
+    impl_args.imports.insert("PSP22CappedData", import_data);
+    impl_args.vec_import();
 
-            fn _burn_from(&mut self, from: &AccountId, id: &Id) -> Result<(), PSP34Error> {
-                pendzl::contracts::psp34::PSP34Internal::_update(self, &Some(from), &None, id)
-            }
+    override_functions("PSP22CappedInternal", &mut internal, impl_args.map);
+    override_functions("PSP22Capped", &mut capped, impl_args.map);
 
-        }
-    ))
-    .expect("Should parse");
+    impl_args.add_hook("PSP22Internal", "_update", update_hook);
 
-    let psp34_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::psp34::PSP34DefaultImpl for #storage_struct_name {}
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(capped_default_impl));
+    impl_args.items.push(syn::Item::Impl(capped));
+}
+
+pub(crate) fn impl_psp22_snapshot(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::snapshot::PSP22SnapshotInternalDefaultImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let mut psp34 = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::psp34::PSP34 for #storage_struct_name {
-            #[ink(message)]
-            fn collection_id(&self) -> Id {
-                pendzl::contracts::psp34::PSP34DefaultImpl::collection_id_default_impl(self)
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::snapshot::PSP22SnapshotInternal for #storage_struct_name {
+            fn _snapshot(&mut self) -> Result<SnapshotId, PSP22Error> {
+                pendzl::contracts::psp22::snapshot::PSP22SnapshotInternalDefaultImpl::_snapshot_default_impl(self)
             }
 
-            #[ink(message)]
-            fn balance_of(&self, owner: AccountId) -> u32 {
-                pendzl::contracts::psp34::PSP34DefaultImpl::balance_of_default_impl(self, owner)
+            fn _current_snapshot_id(&self) -> SnapshotId {
+                pendzl::contracts::psp22::snapshot::PSP22SnapshotInternalDefaultImpl::_current_snapshot_id_default_impl(self)
             }
 
-            #[ink(message)]
-            fn owner_of(&self, id: Id) -> Option<AccountId> {
-                pendzl::contracts::psp34::PSP34DefaultImpl::owner_of_default_impl(self, id)
+            fn _balance_of_at(&self, account: &AccountId, snapshot_id: SnapshotId) -> Option<Balance> {
+                pendzl::contracts::psp22::snapshot::PSP22SnapshotInternalDefaultImpl::_balance_of_at_default_impl(self, account, snapshot_id)
             }
 
-            #[ink(message)]
-            fn allowance(&self, owner: AccountId, operator: AccountId, id: Option<Id>) -> bool {
-                pendzl::contracts::psp34::PSP34DefaultImpl::allowance_default_impl(self, owner, operator, id)
+            fn _total_supply_at(&self, snapshot_id: SnapshotId) -> Option<Balance> {
+                pendzl::contracts::psp22::snapshot::PSP22SnapshotInternalDefaultImpl::_total_supply_at_default_impl(self, snapshot_id)
             }
 
-            #[ink(message)]
-            fn approve(&mut self, operator: AccountId, id: Option<Id>, approved: bool) -> Result<(), PSP34Error> {
-                pendzl::contracts::psp34::PSP34DefaultImpl::approve_default_impl(self, operator, id, approved)
+            fn _update_snapshots(
+                &mut self,
+                from: Option<&AccountId>,
+                to: Option<&AccountId>,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::snapshot::PSP22SnapshotInternalDefaultImpl::_update_snapshots_default_impl(self, from, to)
             }
+        }
+    ))
+    .expect("Should parse");
+
+    let snapshot_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::snapshot::PSP22SnapshotDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
 
+    let mut snapshot = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::snapshot::PSP22Snapshot for #storage_struct_name {
             #[ink(message)]
-            fn transfer(&mut self, to: AccountId, id: Id, data: Vec<u8>) -> Result<(), PSP34Error> {
-                pendzl::contracts::psp34::PSP34DefaultImpl::transfer_default_impl(self, to, id, data)
+            fn balance_of_at(&self, account: AccountId, snapshot_id: SnapshotId) -> Option<Balance> {
+                pendzl::contracts::psp22::snapshot::PSP22SnapshotDefaultImpl::balance_of_at_default_impl(self, account, snapshot_id)
             }
 
             #[ink(message)]
-            fn total_supply(&self) -> u64 {
-                pendzl::contracts::psp34::PSP34DefaultImpl::total_supply_default_impl(self)
+            fn total_supply_at(&self, snapshot_id: SnapshotId) -> Option<Balance> {
+                pendzl::contracts::psp22::snapshot::PSP22SnapshotDefaultImpl::total_supply_at_default_impl(self, snapshot_id)
             }
         }
     ))
     .expect("Should parse");
 
+    let update_hook = syn::parse2::<syn::Stmt>(quote!(
+        pendzl::contracts::psp22::snapshot::PSP22SnapshotInternal::_update_snapshots(self, from, to)?;
+    ))
+    .expect("Should parse");
+
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        pub use pendzl::contracts::psp34::*;
+        pub use pendzl::contracts::psp22::snapshot::*;
     ))
     .expect("Should parse");
 
     let import_data = syn::parse2::<syn::ItemUse>(quote!(
-        pub use pendzl::contracts::psp34::PSP34Data;
+        pub use pendzl::contracts::psp22::snapshot::PSP22SnapshotData;
     ))
     .expect("Should parse import");
 
-    impl_args.imports.insert("PSP34", import);
-    impl_args.imports.insert("PSP34Data", import_data);
+    impl_args.imports.insert("PSP22Snapshot", import);
+    impl_args.imports.insert("PSP22SnapshotData", import_data);
     impl_args.vec_import();
 
-    override_functions("PSP34Internal", &mut internal, impl_args.map);
-    override_functions("PSP34", &mut psp34, impl_args.map);
+    override_functions("PSP22SnapshotInternal", &mut internal, impl_args.map);
+    override_functions("PSP22Snapshot", &mut snapshot, impl_args.map);
+
+    impl_args.add_hook("PSP22Internal", "_update", update_hook);
 
-    // only insert this if it is not present
     impl_args.items.push(syn::Item::Impl(internal_default_impl));
     impl_args.items.push(syn::Item::Impl(internal));
-    impl_args.items.push(syn::Item::Impl(psp34_default_impl));
-    impl_args.items.push(syn::Item::Impl(psp34));
+    impl_args.items.push(syn::Item::Impl(snapshot_default_impl));
+    impl_args.items.push(syn::Item::Impl(snapshot));
 }
 
-pub(crate) fn impl_psp34_burnable(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_psp22_votes(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
-    let burnable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::psp34::burnable::PSP34BurnableDefaultImpl for #storage_struct_name {}
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::votes::PSP22VotesInternalDefaultImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let mut burnable = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::psp34::burnable::PSP34Burnable for #storage_struct_name {
-            #[ink(message)]
-            fn burn(&mut self, from: AccountId, id: Id) -> Result<(), PSP34Error> {
-                pendzl::contracts::psp34::burnable::PSP34BurnableDefaultImpl::burn_default_impl(self,from,id)
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::votes::PSP22VotesInternal for #storage_struct_name {
+            fn _delegates(&self, account: &AccountId) -> Option<AccountId> {
+                pendzl::contracts::psp22::votes::PSP22VotesInternalDefaultImpl::_delegates_default_impl(self, account)
             }
-        }
-    ))
-    .expect("Should parse");
 
-    let import = syn::parse2::<syn::ItemUse>(quote!(
-        pub use pendzl::contracts::psp34::burnable::*;
-    ))
-    .expect("Should parse");
+            fn _get_votes(&self, account: &AccountId) -> Balance {
+                pendzl::contracts::psp22::votes::PSP22VotesInternalDefaultImpl::_get_votes_default_impl(self, account)
+            }
 
-    impl_args.imports.insert("PSP34Burnable", import);
-    impl_args.vec_import();
+            fn _get_past_votes(&self, account: &AccountId, timestamp: &Timestamp) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::votes::PSP22VotesInternalDefaultImpl::_get_past_votes_default_impl(self, account, timestamp)
+            }
 
-    override_functions("PSP34Burnable", &mut burnable, impl_args.map);
+            fn _get_past_total_supply(&self, timestamp: &Timestamp) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::votes::PSP22VotesInternalDefaultImpl::_get_past_total_supply_default_impl(self, timestamp)
+            }
 
-    impl_args.items.push(syn::Item::Impl(burnable_default_impl));
-    impl_args.items.push(syn::Item::Impl(burnable));
-}
+            fn _delegate(&mut self, delegator: &AccountId, delegatee: &AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::votes::PSP22VotesInternalDefaultImpl::_delegate_default_impl(self, delegator, delegatee)
+            }
 
-pub(crate) fn impl_psp34_mintable(impl_args: &mut ImplArgs) {
-    let storage_struct_name = impl_args.contract_name();
-    let mintable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::psp34::mintable::PSP34MintableDefaultImpl for #storage_struct_name {}
-    ))
-    .expect("Should parse");
+            fn _delegate_by_sig(
+                &mut self,
+                delegator: &AccountId,
+                delegatee: &AccountId,
+                nonce: &u64,
+                expiry: &Timestamp,
+                signature: &PermitSignature,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::votes::PSP22VotesInternalDefaultImpl::_delegate_by_sig_default_impl(self, delegator, delegatee, nonce, expiry, signature)
+            }
 
-    let mut mintable = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::psp34::mintable::PSP34Mintable for #storage_struct_name {
-            #[ink(message)]
-            fn mint(&mut self, from: AccountId, id: Id) -> Result<(), PSP34Error> {
-                pendzl::contracts::psp34::mintable::PSP34MintableDefaultImpl::mint_default_impl(self,from,id)
+            fn _delegation_nonces(&self, account: &AccountId) -> u64 {
+                pendzl::contracts::psp22::votes::PSP22VotesInternalDefaultImpl::_delegation_nonces_default_impl(self, account)
+            }
+
+            fn _domain_separator(&self) -> [u8; 32] {
+                pendzl::contracts::psp22::votes::PSP22VotesInternalDefaultImpl::_domain_separator_default_impl(self)
+            }
+
+            fn _transfer_voting_units(
+                &mut self,
+                from: Option<&AccountId>,
+                to: Option<&AccountId>,
+                amount: &Balance,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::votes::PSP22VotesInternalDefaultImpl::_transfer_voting_units_default_impl(self, from, to, amount)
+            }
+
+            fn _move_delegate_votes(
+                &mut self,
+                from: Option<&AccountId>,
+                to: Option<&AccountId>,
+                amount: &Balance,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::votes::PSP22VotesInternalDefaultImpl::_move_delegate_votes_default_impl(self, from, to, amount)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let votes_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::votes::PSP22VotesDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut votes = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::votes::PSP22Votes for #storage_struct_name {
+            #[ink(message)]
+            fn delegates(&self, account: AccountId) -> Option<AccountId> {
+                pendzl::contracts::psp22::votes::PSP22VotesDefaultImpl::delegates_default_impl(self, account)
+            }
+
+            #[ink(message)]
+            fn get_votes(&self, account: AccountId) -> Balance {
+                pendzl::contracts::psp22::votes::PSP22VotesDefaultImpl::get_votes_default_impl(self, account)
+            }
+
+            #[ink(message)]
+            fn get_past_votes(&self, account: AccountId, timestamp: Timestamp) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::votes::PSP22VotesDefaultImpl::get_past_votes_default_impl(self, account, timestamp)
+            }
+
+            #[ink(message)]
+            fn get_past_total_supply(&self, timestamp: Timestamp) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::votes::PSP22VotesDefaultImpl::get_past_total_supply_default_impl(self, timestamp)
+            }
+
+            #[ink(message)]
+            fn delegate(&mut self, delegatee: AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::votes::PSP22VotesDefaultImpl::delegate_default_impl(self, delegatee)
+            }
+
+            #[ink(message)]
+            fn delegate_by_sig(
+                &mut self,
+                delegator: AccountId,
+                delegatee: AccountId,
+                nonce: u64,
+                expiry: Timestamp,
+                signature: PermitSignature,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::votes::PSP22VotesDefaultImpl::delegate_by_sig_default_impl(self, delegator, delegatee, nonce, expiry, signature)
+            }
+
+            #[ink(message)]
+            fn delegation_nonces(&self, account: AccountId) -> u64 {
+                pendzl::contracts::psp22::votes::PSP22VotesDefaultImpl::delegation_nonces_default_impl(self, account)
+            }
+
+            #[ink(message)]
+            fn domain_separator(&self) -> [u8; 32] {
+                pendzl::contracts::psp22::votes::PSP22VotesDefaultImpl::domain_separator_default_impl(self)
             }
         }
     ))
     .expect("Should parse");
 
+    let update_hook = syn::parse2::<syn::Stmt>(quote!(
+        pendzl::contracts::psp22::votes::PSP22VotesInternal::_transfer_voting_units(self, from, to, amount)?;
+    ))
+    .expect("Should parse");
+
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        pub use pendzl::contracts::psp34::mintable::*;
+        pub use pendzl::contracts::psp22::votes::*;
     ))
     .expect("Should parse");
 
-    impl_args.imports.insert("PSP34Mintable", import);
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::votes::PSP22VotesData;
+    ))
+    .expect("Should parse import");
+
+    impl_args.imports.insert("PSP22Votes", import);
+    impl_args.imports.insert("PSP22VotesData", import_data);
     impl_args.vec_import();
 
-    override_functions("PSP34Mintable", &mut mintable, impl_args.map);
+    override_functions("PSP22VotesInternal", &mut internal, impl_args.map);
+    override_functions("PSP22Votes", &mut votes, impl_args.map);
 
-    impl_args.items.push(syn::Item::Impl(mintable_default_impl));
-    impl_args.items.push(syn::Item::Impl(mintable));
+    impl_args.add_hook("PSP22Internal", "_update", update_hook);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(votes_default_impl));
+    impl_args.items.push(syn::Item::Impl(votes));
 }
 
-pub(crate) fn impl_psp34_metadata(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_psp22_flash_lender(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::psp34::metadata::PSP34MetadataInternalDefaultImpl for #storage_struct_name {}
+        impl pendzl::contracts::psp22::flash_lender::PSP22FlashLenderInternalDefaultImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
     let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::psp34::metadata::PSP34MetadataInternal for #storage_struct_name {
+        impl pendzl::contracts::psp22::flash_lender::PSP22FlashLenderInternal for #storage_struct_name {
+            fn _max_flash_loan(&self) -> Balance {
+                pendzl::contracts::psp22::flash_lender::PSP22FlashLenderInternalDefaultImpl::_max_flash_loan_default_impl(self)
+            }
 
-            fn _set_attribute(&mut self, id: &Id, key: &String, value: &String) {
-                pendzl::contracts::psp34::metadata::PSP34MetadataInternalDefaultImpl::_set_attribute_default_impl(self, id, key, value)
+            fn _flash_fee(&self, amount: &Balance) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::flash_lender::PSP22FlashLenderInternalDefaultImpl::_flash_fee_default_impl(self, amount)
+            }
+
+            fn _flash_fee_receiver(&self) -> Option<AccountId> {
+                pendzl::contracts::psp22::flash_lender::PSP22FlashLenderInternalDefaultImpl::_flash_fee_receiver_default_impl(self)
+            }
+
+            fn _flash_loan(&mut self, receiver: &AccountId, amount: &Balance, data: &[u8]) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::flash_lender::PSP22FlashLenderInternalDefaultImpl::_flash_loan_default_impl(self, receiver, amount, data)
             }
         }
     ))
     .expect("Should parse");
 
-    let metadata_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::psp34::metadata::PSP34MetadataDefaultImpl for #storage_struct_name {}
+    let flash_lender_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::flash_lender::PSP22FlashLenderDefaultImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let mut metadata = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::psp34::metadata::PSP34Metadata for #storage_struct_name {
+    let mut flash_lender = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::flash_lender::PSP22FlashLender for #storage_struct_name {
             #[ink(message)]
-            fn get_attribute(&self, id: Id, key: String) -> Option<String> {
-                pendzl::contracts::psp34::metadata::PSP34MetadataDefaultImpl::get_attribute_default_impl(self, id, key)
+            fn max_flash_loan(&self) -> Balance {
+                pendzl::contracts::psp22::flash_lender::PSP22FlashLenderDefaultImpl::max_flash_loan_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn flash_fee(&self, amount: Balance) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::flash_lender::PSP22FlashLenderDefaultImpl::flash_fee_default_impl(self, amount)
+            }
+
+            #[ink(message)]
+            fn flash_loan(&mut self, receiver: AccountId, amount: Balance, data: Vec<u8>) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::flash_lender::PSP22FlashLenderDefaultImpl::flash_loan_default_impl(self, receiver, amount, data)
             }
         }
     ))
     .expect("Should parse");
 
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        pub use pendzl::contracts::psp34::metadata::*;
+        pub use pendzl::contracts::psp22::flash_lender::*;
     ))
     .expect("Should parse");
 
-    let import_data = syn::parse2::<syn::ItemUse>(quote!(
-        pub use pendzl::contracts::psp34::metadata::PSP34MetadataData;
-    ))
-    .expect("Should parse import");
-
-    impl_args.imports.insert("PSP34Metadata", import);
-    impl_args.imports.insert("PSP34MetadataData", import_data);
-
+    impl_args.imports.insert("PSP22FlashLender", import);
     impl_args.vec_import();
 
-    override_functions("PSP34MetadataInternal", &mut internal, impl_args.map);
-    override_functions("PSP34Metadata", &mut metadata, impl_args.map);
+    override_functions(
+        "PSP22FlashLenderInternal",
+        &mut internal,
+        impl_args.map,
+    );
+    override_functions("PSP22FlashLender", &mut flash_lender, impl_args.map);
 
     impl_args.items.push(syn::Item::Impl(internal_default_impl));
     impl_args.items.push(syn::Item::Impl(internal));
-    impl_args.items.push(syn::Item::Impl(metadata_default_impl));
-    impl_args.items.push(syn::Item::Impl(metadata));
+    impl_args
+        .items
+        .push(syn::Item::Impl(flash_lender_default_impl));
+    impl_args.items.push(syn::Item::Impl(flash_lender));
 }
 
-pub(crate) fn impl_ownable(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_psp22_permit(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::ownable::OwnableInternalDefaultImpl for #storage_struct_name {}
+        impl pendzl::contracts::psp22::permit::PSP22PermitInternalDefaultImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
     let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::ownable::OwnableInternal for #storage_struct_name {
-            fn _owner(&self) -> Option<AccountId>{
-                pendzl::contracts::ownable::OwnableInternalDefaultImpl::_owner_default_impl(self)
+        impl pendzl::contracts::psp22::permit::PSP22PermitInternal for #storage_struct_name {
+            fn _nonces(&self, owner: &AccountId) -> u64 {
+                pendzl::contracts::psp22::permit::PSP22PermitInternalDefaultImpl::_nonces_default_impl(self, owner)
             }
-            fn _update_owner(&mut self, owner: &Option<AccountId>){
-                pendzl::contracts::ownable::OwnableInternalDefaultImpl::_update_owner_default_impl(self, owner);
 
+            fn _domain_separator(&self) -> [u8; 32] {
+                pendzl::contracts::psp22::permit::PSP22PermitInternalDefaultImpl::_domain_separator_default_impl(self)
             }
-            fn _only_owner(&self) -> Result<(), OwnableError> {
-                pendzl::contracts::ownable::OwnableInternalDefaultImpl::_only_owner_default_impl(self)
+
+            fn _permit(
+                &mut self,
+                owner: &AccountId,
+                spender: &AccountId,
+                value: &Balance,
+                deadline: &Timestamp,
+                signature: &PermitSignature,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::permit::PSP22PermitInternalDefaultImpl::_permit_default_impl(self, owner, spender, value, deadline, signature)
             }
         }
     ))
     .expect("Should parse");
 
-    let ownable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::ownable::OwnableDefaultImpl for #storage_struct_name {}
+    let permit_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::permit::PSP22PermitDefaultImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let mut ownable = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::ownable::Ownable for #storage_struct_name {
+    let mut permit = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::permit::PSP22Permit for #storage_struct_name {
             #[ink(message)]
-            fn owner(&self) -> Option<AccountId> {
-                pendzl::contracts::ownable::OwnableDefaultImpl::owner_default_impl(self)
+            fn permit(
+                &mut self,
+                owner: AccountId,
+                spender: AccountId,
+                value: Balance,
+                deadline: Timestamp,
+                signature: PermitSignature,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::permit::PSP22PermitDefaultImpl::permit_default_impl(self, owner, spender, value, deadline, signature)
             }
 
             #[ink(message)]
-            fn renounce_ownership(&mut self) -> Result<(), OwnableError> {
-                pendzl::contracts::ownable::OwnableDefaultImpl::renounce_ownership_default_impl(self)
+            fn nonces(&self, owner: AccountId) -> u64 {
+                pendzl::contracts::psp22::permit::PSP22PermitDefaultImpl::nonces_default_impl(self, owner)
             }
 
             #[ink(message)]
-            fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), OwnableError> {
-                pendzl::contracts::ownable::OwnableDefaultImpl::transfer_ownership_default_impl(self, new_owner)
+            fn domain_separator(&self) -> [u8; 32] {
+                pendzl::contracts::psp22::permit::PSP22PermitDefaultImpl::domain_separator_default_impl(self)
             }
         }
     ))
     .expect("Should parse");
 
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        pub use pendzl::contracts::ownable::*;
+        pub use pendzl::contracts::psp22::permit::*;
     ))
     .expect("Should parse");
 
     let import_data = syn::parse2::<syn::ItemUse>(quote!(
-        pub use pendzl::contracts::ownable::OwnableData;
+        pub use pendzl::contracts::psp22::permit::PSP22PermitData;
     ))
     .expect("Should parse import");
 
-    impl_args.imports.insert("Ownable", import);
-    impl_args.imports.insert("OwnableData", import_data);
+    impl_args.imports.insert("PSP22Permit", import);
+    impl_args.imports.insert("PSP22PermitData", import_data);
+    impl_args.vec_import();
 
-    override_functions("OwnableInternal", &mut internal, impl_args.map);
-    override_functions("Ownable", &mut ownable, impl_args.map);
+    override_functions("PSP22PermitInternal", &mut internal, impl_args.map);
+    override_functions("PSP22Permit", &mut permit, impl_args.map);
 
     impl_args.items.push(syn::Item::Impl(internal_default_impl));
     impl_args.items.push(syn::Item::Impl(internal));
-    impl_args.items.push(syn::Item::Impl(ownable_default_impl));
-    impl_args.items.push(syn::Item::Impl(ownable));
+    impl_args.items.push(syn::Item::Impl(permit_default_impl));
+    impl_args.items.push(syn::Item::Impl(permit));
 }
 
-pub(crate) fn impl_access_control(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_psp22_dividends(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::access_control::AccessControlInternalDefaultImpl for #storage_struct_name {}
+        impl pendzl::contracts::psp22::dividends::PSP22DividendsInternalDefaultImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
     let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::access_control::AccessControlInternal for #storage_struct_name {
-            fn _default_admin() -> RoleType {
-                <Self as pendzl::contracts::access_control::AccessControlInternalDefaultImpl>::_default_admin_default_impl()
+        impl pendzl::contracts::psp22::dividends::PSP22DividendsInternal for #storage_struct_name {
+            fn _accumulative_dividends_of(&self, account: &AccountId, asset: &Option<AccountId>) -> Balance {
+                pendzl::contracts::psp22::dividends::PSP22DividendsInternalDefaultImpl::_accumulative_dividends_of_default_impl(self, account, asset)
             }
 
-            fn _has_role(&self, role: RoleType, account: Option<AccountId>) -> bool{
-                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_has_role_default_impl(self, role, account)
+            fn _withdrawable_dividends_of(&self, account: &AccountId, asset: &Option<AccountId>) -> Balance {
+                pendzl::contracts::psp22::dividends::PSP22DividendsInternalDefaultImpl::_withdrawable_dividends_of_default_impl(self, account, asset)
             }
 
-            fn _grant_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
-                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_grant_role_default_impl(self, role, account)
+            fn _withdrawn_dividends_of(&self, account: &AccountId, asset: &Option<AccountId>) -> Balance {
+                pendzl::contracts::psp22::dividends::PSP22DividendsInternalDefaultImpl::_withdrawn_dividends_of_default_impl(self, account, asset)
             }
 
-            fn _do_revoke_role(&mut self, role: RoleType, account: Option<AccountId>)  -> Result<(), AccessControlError>  {
-                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_do_revoke_role_default_impl(self, role, account)
+            fn _distribute_dividends(&mut self, from: &AccountId, asset: &Option<AccountId>, amount: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::dividends::PSP22DividendsInternalDefaultImpl::_distribute_dividends_default_impl(self, from, asset, amount)
             }
 
-            fn _get_role_admin(&self, role: RoleType) -> RoleType {
-                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_get_role_admin_default_impl(self, role)
+            fn _withdraw_dividends(&mut self, account: &AccountId, asset: &Option<AccountId>) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::dividends::PSP22DividendsInternalDefaultImpl::_withdraw_dividends_default_impl(self, account, asset)
             }
 
-            fn _set_role_admin(&mut self, role: RoleType, new_admin: RoleType) {
-                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_set_role_admin_default_impl(self, role, new_admin);
+            fn _update_dividends_corrections(&mut self, from: Option<&AccountId>, to: Option<&AccountId>, amount: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::dividends::PSP22DividendsInternalDefaultImpl::_update_dividends_corrections_default_impl(self, from, to, amount)
             }
 
-            fn _ensure_has_role(&self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
-                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_ensure_has_role_default_impl(self, role, account)
+            fn _handle_dividends_transfer_in(&mut self, asset: &Option<AccountId>, from: &AccountId, amount: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::dividends::PSP22DividendsInternalDefaultImpl::_handle_dividends_transfer_in_default_impl(self, asset, from, amount)
             }
 
+            fn _handle_dividends_transfer_out(&mut self, asset: &Option<AccountId>, to: &AccountId, amount: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::dividends::PSP22DividendsInternalDefaultImpl::_handle_dividends_transfer_out_default_impl(self, asset, to, amount)
+            }
         }
     ))
     .expect("Should parse");
 
-    let access_control_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::access_control::AccessControlDefaultImpl for #storage_struct_name {}
+    let dividends_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::dividends::PSP22DividendsDefaultImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let mut access_control = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::access_control::AccessControl for #storage_struct_name {
-            #[ink(message)]
-            fn has_role(&self, role: RoleType, address: Option<AccountId>) -> bool {
-                pendzl::contracts::access_control::AccessControlDefaultImpl::has_role_default_impl(self, role, address)
-            }
-
-            #[ink(message)]
-            fn get_role_admin(&self, role: RoleType) -> RoleType {
-                pendzl::contracts::access_control::AccessControlDefaultImpl::get_role_admin_default_impl(self, role)
-            }
-
-            #[ink(message)]
-            fn grant_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
-                pendzl::contracts::access_control::AccessControlDefaultImpl::grant_role_default_impl(self, role, account)
+    let mut dividends = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::dividends::PSP22Dividends for #storage_struct_name {
+            #[ink(message, payable)]
+            fn distribute_dividends(&mut self, asset: Option<AccountId>, amount: Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::dividends::PSP22DividendsDefaultImpl::distribute_dividends_default_impl(self, asset, amount)
             }
 
             #[ink(message)]
-            fn revoke_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
-                pendzl::contracts::access_control::AccessControlDefaultImpl::revoke_role_default_impl(self, role, account)
+            fn withdraw_dividends(&mut self, asset: Option<AccountId>) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::dividends::PSP22DividendsDefaultImpl::withdraw_dividends_default_impl(self, asset)
             }
 
             #[ink(message)]
-            fn renounce_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
-                pendzl::contracts::access_control::AccessControlDefaultImpl::renounce_role_default_impl(self, role, account)
+            fn withdrawable_dividends_of(&self, account: AccountId, asset: Option<AccountId>) -> Balance {
+                pendzl::contracts::psp22::dividends::PSP22DividendsDefaultImpl::withdrawable_dividends_of_default_impl(self, account, asset)
             }
 
             #[ink(message)]
-            fn set_role_admin(&mut self, role: RoleType, new_admin: RoleType) -> Result<(), AccessControlError> {
-                pendzl::contracts::access_control::AccessControlDefaultImpl::set_role_admin_default_impl(self, role, new_admin)
+            fn withdrawn_dividends_of(&self, account: AccountId, asset: Option<AccountId>) -> Balance {
+                pendzl::contracts::psp22::dividends::PSP22DividendsDefaultImpl::withdrawn_dividends_of_default_impl(self, account, asset)
             }
         }
     ))
     .expect("Should parse");
 
+    let update_hook = syn::parse2::<syn::Stmt>(quote!(
+        pendzl::contracts::psp22::dividends::PSP22DividendsInternal::_update_dividends_corrections(self, from, to, amount)?;
+    ))
+    .expect("Should parse");
+
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        pub use pendzl::contracts::access_control::*;
+        pub use pendzl::contracts::psp22::dividends::*;
     ))
     .expect("Should parse");
 
     let import_data = syn::parse2::<syn::ItemUse>(quote!(
-        pub use pendzl::contracts::access_control::AccessControlData;
+        pub use pendzl::contracts::psp22::dividends::PSP22DividendsData;
     ))
     .expect("Should parse import");
 
-    impl_args.imports.insert("AccessControl", import);
-    impl_args.imports.insert("AccessControlData", import_data);
+    impl_args.imports.insert("PSP22Dividends", import);
+    impl_args.imports.insert("PSP22DividendsData", import_data);
+    impl_args.vec_import();
 
-    override_functions("AccessControlInternal", &mut internal, impl_args.map);
-    override_functions("AccessControl", &mut access_control, impl_args.map);
+    override_functions("PSP22DividendsInternal", &mut internal, impl_args.map);
+    override_functions("PSP22Dividends", &mut dividends, impl_args.map);
+
+    impl_args.add_hook("PSP22Internal", "_update", update_hook);
 
     impl_args.items.push(syn::Item::Impl(internal_default_impl));
     impl_args.items.push(syn::Item::Impl(internal));
     impl_args
         .items
-        .push(syn::Item::Impl(access_control_default_impl));
-    impl_args.items.push(syn::Item::Impl(access_control));
+        .push(syn::Item::Impl(dividends_default_impl));
+    impl_args.items.push(syn::Item::Impl(dividends));
 }
 
-pub(crate) fn impl_pausable(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_psp22_vault_caps(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
-    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::pausable::PausableInternalDefaultImpl for #storage_struct_name {}
+    let caps_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault::PSP22VaultCapsDefaultImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::pausable::PausableInternal for #storage_struct_name {
-            fn _paused(&self) -> bool {
-                pendzl::contracts::pausable::PausableInternalDefaultImpl::_paused_default_impl(self)
-            }
-
-            fn _pause(&mut self) -> Result<(), PausableError> {
-                pendzl::contracts::pausable::PausableInternalDefaultImpl::_pause_default_impl(self)
-            }
-
-            fn _unpause(&mut self) -> Result<(), PausableError> {
-                pendzl::contracts::pausable::PausableInternalDefaultImpl::_unpause_default_impl(self)
+    let mut caps = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault::PSP22VaultCaps for #storage_struct_name {
+            #[ink(message)]
+            fn deposit_cap(&self) -> Option<Balance> {
+                pendzl::contracts::psp22::vault::PSP22VaultCapsDefaultImpl::deposit_cap_default_impl(self)
             }
 
-            fn _ensure_paused(&self) -> Result<(), PausableError> {
-                pendzl::contracts::pausable::PausableInternalDefaultImpl::_ensure_paused_default_impl(self)
+            #[ink(message)]
+            fn receiver_cap(&self, receiver: AccountId) -> Option<Balance> {
+                pendzl::contracts::psp22::vault::PSP22VaultCapsDefaultImpl::receiver_cap_default_impl(self, receiver)
             }
 
-            fn _ensure_not_paused(&self) -> Result<(), PausableError> {
-                pendzl::contracts::pausable::PausableInternalDefaultImpl::_ensure_not_paused_default_impl(self)
+            #[ink(message)]
+            fn set_deposit_cap(&mut self, cap: Option<Balance>) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault::PSP22VaultCapsDefaultImpl::set_deposit_cap_default_impl(self, cap)
             }
-        }
-    ))
-    .expect("Should parse");
 
-    let pausable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl  pendzl::contracts::pausable::PausableDefaultImpl for #storage_struct_name {}
-    ))
-    .expect("Should parse");
-
-    let mut pausable = syn::parse2::<syn::ItemImpl>(quote!(
-        impl  pendzl::contracts::pausable::Pausable for #storage_struct_name {
             #[ink(message)]
-            fn paused(&self) -> bool {
-                pendzl::contracts::pausable::PausableDefaultImpl::paused_default_impl(self)
+            fn set_receiver_cap(&mut self, receiver: Option<AccountId>, cap: Option<Balance>) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault::PSP22VaultCapsDefaultImpl::set_receiver_cap_default_impl(self, receiver, cap)
             }
         }
     ))
     .expect("Should parse");
 
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        pub use pendzl::contracts::pausable::*;
+        pub use pendzl::contracts::psp22::vault::*;
     ))
-    .expect("Should parse import");
+    .expect("Should parse");
 
-    let import_data = syn::parse2::<syn::ItemUse>(quote!(
-        pub use pendzl::contracts::pausable::PausableData;
-    ))
-    .expect("Should parse import");
-    impl_args.imports.insert("Pausable", import);
-    impl_args.imports.insert("PausableData", import_data);
+    impl_args.imports.insert("PSP22Vault", import);
+    impl_args.vec_import();
 
-    override_functions("PausableInternal", &mut internal, impl_args.map);
-    override_functions("Pausable", &mut pausable, impl_args.map);
+    override_functions("PSP22VaultCaps", &mut caps, impl_args.map);
 
-    impl_args.items.push(syn::Item::Impl(internal_default_impl));
-    impl_args.items.push(syn::Item::Impl(internal));
-    impl_args.items.push(syn::Item::Impl(pausable_default_impl));
-    impl_args.items.push(syn::Item::Impl(pausable));
+    impl_args.items.push(syn::Item::Impl(caps_default_impl));
+    impl_args.items.push(syn::Item::Impl(caps));
 }
-pub(crate) fn impl_vesting(impl_args: &mut ImplArgs) {
+
+pub(crate) fn impl_psp22_vault_async(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::general_vest::GeneralVestInternalDefaultImpl for #storage_struct_name {}
+        impl pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternalDefaultImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
     let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::general_vest::GeneralVestInternal for #storage_struct_name {
-            fn _create_vest(&mut self,
-                receiver: AccountId,
-                asset: Option<AccountId>,
-                amount: Balance,
-                schedule: VestingSchedule,
-                data: &Vec<u8>
-            )-> Result<(), VestingError>  {
-                pendzl::contracts::general_vest::GeneralVestInternalDefaultImpl::_create_vest_default_impl(
-                    self,
-                    receiver,
-                    asset,
-                    amount,
-                    schedule,
-                    data
-                )
+        impl pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternal for #storage_struct_name {
+            fn _is_async_deposit(&self) -> bool {
+                pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternalDefaultImpl::_is_async_deposit_default_impl(self)
             }
 
-            fn _release(&mut self, receiver: Option<AccountId>, asset: Option<AccountId>, data: &Vec<u8>) -> Result<u128, VestingError> {
-                pendzl::contracts::general_vest::GeneralVestInternalDefaultImpl::_release_default_impl(self, receiver, asset, data)
+            fn _is_async_redeem(&self) -> bool {
+                pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternalDefaultImpl::_is_async_redeem_default_impl(self)
             }
 
-            fn _release_by_vest_id(&mut self, receiver: Option<AccountId>, asset: Option<AccountId>, id: u32, data: &Vec<u8>) -> Result<(), VestingError> {
-                pendzl::contracts::general_vest::GeneralVestInternalDefaultImpl::_release_by_vest_id_default_impl(self, receiver, asset, id, data)
+            fn _pending_deposit_request(&self, controller: &AccountId) -> Balance {
+                pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternalDefaultImpl::_pending_deposit_request_default_impl(self, controller)
             }
 
-            fn _handle_transfer_in(&mut self, asset: Option<AccountId>, from: AccountId, amount: Balance, data: &Vec<u8>) -> Result<(), VestingError> {
-                pendzl::contracts::general_vest::GeneralVestInternalDefaultImpl::_handle_transfer_in_default_impl(self, asset, from, amount, data)
+            fn _claimable_deposit_request(&self, controller: &AccountId) -> (Balance, Balance) {
+                pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternalDefaultImpl::_claimable_deposit_request_default_impl(self, controller)
             }
 
-            fn _handle_transfer_out(&mut self, asset: Option<AccountId>, to: AccountId, amount: Balance, data: &Vec<u8>) -> Result<(), VestingError> {
-                pendzl::contracts::general_vest::GeneralVestInternalDefaultImpl::_handle_transfer_out_default_impl(self, asset, to, amount, data)
+            fn _pending_redeem_request(&self, controller: &AccountId) -> Balance {
+                pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternalDefaultImpl::_pending_redeem_request_default_impl(self, controller)
             }
 
-            fn _next_id_vest_of(&self, of: AccountId, asset: Option<AccountId>, data: &Vec<u8>) -> u32 {
-                pendzl::contracts::general_vest::GeneralVestInternalDefaultImpl::_next_id_vest_of_default_impl(self, of, asset, data)
+            fn _claimable_redeem_request(&self, controller: &AccountId) -> (Balance, Balance) {
+                pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternalDefaultImpl::_claimable_redeem_request_default_impl(self, controller)
             }
 
-            fn _vesting_schedule_of(&self, of: AccountId, asset: Option<AccountId>, id: u32, data: &Vec<u8>) -> Option<VestingData> {
-                pendzl::contracts::general_vest::GeneralVestInternalDefaultImpl::_vesting_schedule_of_default_impl(self, of, asset, id, data)
+            fn _request_deposit(&mut self, sender: &AccountId, assets: &Balance, controller: &AccountId, owner: &AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternalDefaultImpl::_request_deposit_default_impl(self, sender, assets, controller, owner)
+            }
+
+            fn _request_redeem(&mut self, sender: &AccountId, shares: &Balance, controller: &AccountId, owner: &AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternalDefaultImpl::_request_redeem_default_impl(self, sender, shares, controller, owner)
+            }
+
+            fn _fulfill_deposit(&mut self, controller: &AccountId, assets: &Balance) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternalDefaultImpl::_fulfill_deposit_default_impl(self, controller, assets)
+            }
+
+            fn _fulfill_redeem(&mut self, controller: &AccountId, shares: &Balance) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternalDefaultImpl::_fulfill_redeem_default_impl(self, controller, shares)
+            }
+
+            fn _claim_deposit(&mut self, controller: &AccountId, receiver: &AccountId, assets: &Balance) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternalDefaultImpl::_claim_deposit_default_impl(self, controller, receiver, assets)
+            }
+
+            fn _claim_mint(&mut self, controller: &AccountId, receiver: &AccountId, shares: &Balance) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternalDefaultImpl::_claim_mint_default_impl(self, controller, receiver, shares)
+            }
+
+            fn _claim_withdraw(&mut self, controller: &AccountId, receiver: &AccountId, assets: &Balance) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternalDefaultImpl::_claim_withdraw_default_impl(self, controller, receiver, assets)
+            }
+
+            fn _claim_redeem(&mut self, controller: &AccountId, receiver: &AccountId, shares: &Balance) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternalDefaultImpl::_claim_redeem_default_impl(self, controller, receiver, shares)
             }
         }
     ))
     .expect("Should parse");
 
-    let vesting_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl  pendzl::contracts::general_vest::GeneralVestDefaultImpl for #storage_struct_name {}
+    let vault_async_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault_async::PSP22VaultAsyncDefaultImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let mut general_vest = syn::parse2::<syn::ItemImpl>(quote!(
-        impl  pendzl::contracts::general_vest::GeneralVest for #storage_struct_name {
-            #[ink(message, payable)]
-            fn create_vest(
-                &mut self,
-                receiver: AccountId,
-                asset: Option<AccountId>,
-                amount: Balance,
-                schedule: VestingSchedule,
-                data: Vec<u8>,
-            ) -> Result<(), VestingError> {
-                pendzl::contracts::general_vest::GeneralVestDefaultImpl::create_vest_default_impl(
-                    self,
-                    receiver,
-                    asset,
-                    amount,
-                    schedule,
-                    data
-                )
+    let mut vault_async = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault_async::PSP22VaultAsync for #storage_struct_name {
+            #[ink(message)]
+            fn request_deposit(&mut self, assets: Balance, controller: AccountId, owner: AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault_async::PSP22VaultAsyncDefaultImpl::request_deposit_default_impl(self, assets, controller, owner)
             }
+
             #[ink(message)]
-            fn release(&mut self, receiver: Option<AccountId>, asset: Option<AccountId>, data: Vec<u8>) -> Result<u128, VestingError> {
-                pendzl::contracts::general_vest::GeneralVestDefaultImpl::release_default_impl(self, receiver, asset, data)
+            fn request_redeem(&mut self, shares: Balance, controller: AccountId, owner: AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault_async::PSP22VaultAsyncDefaultImpl::request_redeem_default_impl(self, shares, controller, owner)
             }
+
             #[ink(message)]
-            fn release_by_vest_id(&mut self, receiver: Option<AccountId>, asset: Option<AccountId>, id: u32, data: Vec<u8>) -> Result<(), VestingError> {
-                pendzl::contracts::general_vest::GeneralVestDefaultImpl::release_by_vest_id_default_impl(self, receiver, asset, id, data)
+            fn pending_deposit_request(&self, controller: AccountId) -> Balance {
+                pendzl::contracts::psp22::vault_async::PSP22VaultAsyncDefaultImpl::pending_deposit_request_default_impl(self, controller)
             }
+
             #[ink(message)]
-            fn next_id_vest_of(&self,  of: AccountId, asset: Option<AccountId>, data: Vec<u8>) -> u32 {
-                pendzl::contracts::general_vest::GeneralVestDefaultImpl::next_id_vest_of_default_impl(self, of, asset, data)
+            fn claimable_deposit_request(&self, controller: AccountId) -> Balance {
+                pendzl::contracts::psp22::vault_async::PSP22VaultAsyncDefaultImpl::claimable_deposit_request_default_impl(self, controller)
+            }
+
+            #[ink(message)]
+            fn pending_redeem_request(&self, controller: AccountId) -> Balance {
+                pendzl::contracts::psp22::vault_async::PSP22VaultAsyncDefaultImpl::pending_redeem_request_default_impl(self, controller)
             }
+
             #[ink(message)]
-            fn vesting_schedule_of(&self, of: AccountId, asset: Option<AccountId>, id: u32, data: Vec<u8>) -> Option<VestingData> {
-                pendzl::contracts::general_vest::GeneralVestDefaultImpl::vesting_schedule_of_default_impl(self, of, asset, id, data)
+            fn claimable_redeem_request(&self, controller: AccountId) -> Balance {
+                pendzl::contracts::psp22::vault_async::PSP22VaultAsyncDefaultImpl::claimable_redeem_request_default_impl(self, controller)
+            }
+
+            #[ink(message)]
+            fn fulfill_deposit(&mut self, controller: AccountId, assets: Balance) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault_async::PSP22VaultAsyncDefaultImpl::fulfill_deposit_default_impl(self, controller, assets)
+            }
+
+            #[ink(message)]
+            fn fulfill_redeem(&mut self, controller: AccountId, shares: Balance) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault_async::PSP22VaultAsyncDefaultImpl::fulfill_redeem_default_impl(self, controller, shares)
             }
         }
     ))
     .expect("Should parse");
 
+    // for the asynchronous side `PSP22Vault` messages claim the caller's requests
+    let hooks = [
+        ("max_deposit", quote!(
+            if pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternal::_is_async_deposit(self) {
+                return pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternal::_claimable_deposit_request(self, &to).0;
+            }
+        )),
+        ("max_mint", quote!(
+            if pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternal::_is_async_deposit(self) {
+                return pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternal::_claimable_deposit_request(self, &to).1;
+            }
+        )),
+        ("max_withdraw", quote!(
+            if pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternal::_is_async_redeem(self) {
+                return pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternal::_claimable_redeem_request(self, &owner).0;
+            }
+        )),
+        ("max_redeem", quote!(
+            if pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternal::_is_async_redeem(self) {
+                return pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternal::_claimable_redeem_request(self, &owner).1;
+            }
+        )),
+        ("deposit", quote!(
+            if pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternal::_is_async_deposit(self) {
+                return pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternal::_claim_deposit(self, &Self::env().caller(), &receiver, &assets);
+            }
+        )),
+        ("mint", quote!(
+            if pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternal::_is_async_deposit(self) {
+                return pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternal::_claim_mint(self, &Self::env().caller(), &receiver, &shares);
+            }
+        )),
+        ("withdraw", quote!(
+            if pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternal::_is_async_redeem(self) {
+                if owner != Self::env().caller() {
+                    return Err(PSP22Error::InvalidRequestCaller);
+                }
+                return pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternal::_claim_withdraw(self, &owner, &receiver, &assets);
+            }
+        )),
+        ("redeem", quote!(
+            if pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternal::_is_async_redeem(self) {
+                if owner != Self::env().caller() {
+                    return Err(PSP22Error::InvalidRequestCaller);
+                }
+                return pendzl::contracts::psp22::vault_async::PSP22VaultAsyncInternal::_claim_redeem(self, &owner, &receiver, &shares);
+            }
+        )),
+    ];
+
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        pub use pendzl::contracts::general_vest::*;
+        pub use pendzl::contracts::psp22::vault_async::*;
     ))
-    .expect("Should parse import");
+    .expect("Should parse");
 
     let import_data = syn::parse2::<syn::ItemUse>(quote!(
-        pub use pendzl::contracts::general_vest::GeneralVestData;
+        pub use pendzl::contracts::psp22::vault_async::PSP22VaultAsyncData;
     ))
     .expect("Should parse import");
-    impl_args.imports.insert("GeneralVest", import);
-    impl_args.imports.insert("GeneralVestData", import_data);
 
+    impl_args.imports.insert("PSP22VaultAsync", import);
+    impl_args.imports.insert("PSP22VaultAsyncData", import_data);
     impl_args.vec_import();
 
-    override_functions("GeneralVestInternal", &mut internal, impl_args.map);
-    override_functions("GeneralVest", &mut general_vest, impl_args.map);
+    override_functions("PSP22VaultAsyncInternal", &mut internal, impl_args.map);
+    override_functions("PSP22VaultAsync", &mut vault_async, impl_args.map);
+
+    for (fn_name, hook) in hooks {
+        let hook = syn::parse2::<syn::Stmt>(hook).expect("Should parse");
+        impl_args.add_hook("PSP22Vault", fn_name, hook);
+    }
 
     impl_args.items.push(syn::Item::Impl(internal_default_impl));
     impl_args.items.push(syn::Item::Impl(internal));
-    impl_args.items.push(syn::Item::Impl(vesting_default_impl));
-    impl_args.items.push(syn::Item::Impl(general_vest));
+    impl_args
+        .items
+        .push(syn::Item::Impl(vault_async_default_impl));
+    impl_args.items.push(syn::Item::Impl(vault_async));
 }
 
-pub(crate) fn impl_set_code_hash(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_psp22_vault_performance_fee(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::set_code_hash::SetCodeHashInternalDefaultImpl for #storage_struct_name {}
+        impl pendzl::contracts::psp22::vault_performance_fee::PSP22VaultPerformanceFeeInternalDefaultImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
     let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::set_code_hash::SetCodeHashInternal for #storage_struct_name {
-            fn _set_code_hash(&mut self, set_code_hash: Hash) -> Result<(), SetCodeHashError> {
-                pendzl::contracts::set_code_hash::SetCodeHashInternalDefaultImpl::_set_code_hash_default_impl(self, set_code_hash)
+        impl pendzl::contracts::psp22::vault_performance_fee::PSP22VaultPerformanceFeeInternal for #storage_struct_name {
+            fn _share_price(&self) -> Result<u128, MathError> {
+                pendzl::contracts::psp22::vault_performance_fee::PSP22VaultPerformanceFeeInternalDefaultImpl::_share_price_default_impl(self)
+            }
+
+            fn _high_water_mark(&self) -> u128 {
+                pendzl::contracts::psp22::vault_performance_fee::PSP22VaultPerformanceFeeInternalDefaultImpl::_high_water_mark_default_impl(self)
+            }
+
+            fn _performance_fee(&self) -> u16 {
+                pendzl::contracts::psp22::vault_performance_fee::PSP22VaultPerformanceFeeInternalDefaultImpl::_performance_fee_default_impl(self)
+            }
+
+            fn _performance_fee_recipient(&self) -> Option<AccountId> {
+                pendzl::contracts::psp22::vault_performance_fee::PSP22VaultPerformanceFeeInternalDefaultImpl::_performance_fee_recipient_default_impl(self)
+            }
+
+            fn _set_performance_fee(&mut self, fee_bps: &u16, fee_recipient: &Option<AccountId>) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault_performance_fee::PSP22VaultPerformanceFeeInternalDefaultImpl::_set_performance_fee_default_impl(self, fee_bps, fee_recipient)
+            }
+
+            fn _harvest(&mut self) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault_performance_fee::PSP22VaultPerformanceFeeInternalDefaultImpl::_harvest_default_impl(self)
             }
         }
     ))
     .expect("Should parse");
 
-    let upgradeable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::set_code_hash::SetCodeHashDefaultImpl for #storage_struct_name {}
+    let performance_fee_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault_performance_fee::PSP22VaultPerformanceFeeDefaultImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let mut set_code_hash = syn::parse2::<syn::ItemImpl>(quote!(
-        impl pendzl::contracts::set_code_hash::SetCodeHash for #storage_struct_name {
+    let mut performance_fee = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault_performance_fee::PSP22VaultPerformanceFee for #storage_struct_name {
             #[ink(message)]
-            fn set_code_hash(&mut self, set_code_hash: Hash) -> Result<(), SetCodeHashError> {
-                pendzl::contracts::set_code_hash::SetCodeHashDefaultImpl::set_code_hash_default_impl(self, set_code_hash)
+            fn harvest(&mut self) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::vault_performance_fee::PSP22VaultPerformanceFeeDefaultImpl::harvest_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn share_price(&self) -> Result<u128, MathError> {
+                pendzl::contracts::psp22::vault_performance_fee::PSP22VaultPerformanceFeeDefaultImpl::share_price_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn high_water_mark(&self) -> u128 {
+                pendzl::contracts::psp22::vault_performance_fee::PSP22VaultPerformanceFeeDefaultImpl::high_water_mark_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn performance_fee(&self) -> u16 {
+                pendzl::contracts::psp22::vault_performance_fee::PSP22VaultPerformanceFeeDefaultImpl::performance_fee_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn performance_fee_recipient(&self) -> Option<AccountId> {
+                pendzl::contracts::psp22::vault_performance_fee::PSP22VaultPerformanceFeeDefaultImpl::performance_fee_recipient_default_impl(self)
             }
         }
     ))
     .expect("Should parse");
 
     let import = syn::parse2::<syn::ItemUse>(quote!(
-        pub use pendzl::contracts::set_code_hash::*;
+        pub use pendzl::contracts::psp22::vault_performance_fee::*;
     ))
     .expect("Should parse");
 
-    impl_args.imports.insert("SetCodeHash", import);
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::vault_performance_fee::PSP22VaultPerformanceFeeData;
+    ))
+    .expect("Should parse import");
 
-    override_functions("SetCodeHashInternal", &mut internal, impl_args.map);
-    override_functions("SetCodeHash", &mut set_code_hash, impl_args.map);
+    impl_args.imports.insert("PSP22VaultPerformanceFee", import);
+    impl_args
+        .imports
+        .insert("PSP22VaultPerformanceFeeData", import_data);
+    impl_args.vec_import();
+
+    override_functions(
+        "PSP22VaultPerformanceFeeInternal",
+        &mut internal,
+        impl_args.map,
+    );
+    override_functions(
+        "PSP22VaultPerformanceFee",
+        &mut performance_fee,
+        impl_args.map,
+    );
 
     impl_args.items.push(syn::Item::Impl(internal_default_impl));
     impl_args.items.push(syn::Item::Impl(internal));
     impl_args
         .items
-        .push(syn::Item::Impl(upgradeable_default_impl));
-    impl_args.items.push(syn::Item::Impl(set_code_hash));
+        .push(syn::Item::Impl(performance_fee_default_impl));
+    impl_args.items.push(syn::Item::Impl(performance_fee));
 }
 
-fn override_functions(
-    trait_name: &str,
-    implementation: &mut syn::ItemImpl,
-    map: &OverridenFnMap,
-) {
-    if let Some(overrides) = map.get(trait_name) {
-        // we will find which fns we wanna override
-        for (fn_name, (fn_code, attributes, inputs)) in overrides {
-            let mut original_fn_found = false;
-            for item in implementation.items.iter_mut() {
-                if let syn::ImplItem::Method(method) = item {
-                    if &method.sig.ident.to_string() == fn_name {
-                        let args_diff = crate::internal::inputs_diff(
-                            method.sig.inputs.clone(),
-                            inputs.clone(),
-                        );
-                        if args_diff.added.len() > 0
-                            || args_diff.removed.len() > 0
-                        {
-                            let original_args = method
-                                .sig
-                                .inputs
-                                .clone()
-                                .into_iter()
-                                .map(|arg| {
-                                    crate::internal::format_arg_string(
-                                        &arg.into_token_stream().to_string(),
-                                    )
-                                })
-                                .collect::<Vec<_>>()
-                                .join(", ");
-                            let current_args = inputs
-                                .clone()
-                                .into_iter()
-                                .map(|arg| {
-                                    crate::internal::format_arg_string(
-                                        &arg.into_token_stream().to_string(),
-                                    )
-                                })
-                                .collect::<Vec<_>>()
-                                .join(", ");
+pub(crate) fn impl_psp22_vault_strategies(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
 
-                            panic!(
-                                "Function arguments do not match for fn {} in trait {} \n
-                            original args: {:?} \n
-                            current args: {:?} \n
-                            diff: {:?}",
-                                fn_name, trait_name, original_args, current_args, args_diff
-                            )
-                        }
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesInternal for #storage_struct_name {
+            fn _strategies(&self) -> Vec<Strategy> {
+                pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesInternalDefaultImpl::_strategies_default_impl(self)
+            }
 
-                        method.block = *fn_code.clone();
-                        method.attrs.append(&mut attributes.to_vec());
+            fn _strategy_assets(&self, strategy: &AccountId) -> Balance {
+                pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesInternalDefaultImpl::_strategy_assets_default_impl(self, strategy)
+            }
 
-                        original_fn_found = true;
-                    }
-                }
+            fn _strategies_assets(&self) -> Balance {
+                pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesInternalDefaultImpl::_strategies_assets_default_impl(self)
             }
-            if !original_fn_found {
-                panic!("Could not find fn {} in trait {}", fn_name, trait_name)
+
+            fn _add_strategy(&mut self, strategy: &AccountId, weight: &u16) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesInternalDefaultImpl::_add_strategy_default_impl(self, strategy, weight)
+            }
+
+            fn _remove_strategy(&mut self, strategy: &AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesInternalDefaultImpl::_remove_strategy_default_impl(self, strategy)
+            }
+
+            fn _force_remove_strategy(&mut self, strategy: &AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesInternalDefaultImpl::_force_remove_strategy_default_impl(self, strategy)
+            }
+
+            fn _set_strategy_weight(&mut self, strategy: &AccountId, weight: &u16) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesInternalDefaultImpl::_set_strategy_weight_default_impl(self, strategy, weight)
+            }
+
+            fn _allocate(&mut self, strategy: &AccountId, assets: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesInternalDefaultImpl::_allocate_default_impl(self, strategy, assets)
+            }
+
+            fn _deallocate(&mut self, strategy: &AccountId, assets: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesInternalDefaultImpl::_deallocate_default_impl(self, strategy, assets)
+            }
+
+            fn _rebalance(&mut self) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesInternalDefaultImpl::_rebalance_default_impl(self)
+            }
+
+            fn _ensure_idle_assets(&mut self, assets: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesInternalDefaultImpl::_ensure_idle_assets_default_impl(self, assets)
             }
         }
-    }
+    ))
+    .expect("Should parse");
+
+    let strategies_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut strategies = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategies for #storage_struct_name {
+            #[ink(message)]
+            fn strategies(&self) -> Vec<Strategy> {
+                pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesDefaultImpl::strategies_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn idle_assets(&self) -> Balance {
+                pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesDefaultImpl::idle_assets_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn deployed_assets(&self) -> Balance {
+                pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesDefaultImpl::deployed_assets_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn add_strategy(&mut self, strategy: AccountId, weight: u16) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesDefaultImpl::add_strategy_default_impl(self, strategy, weight)
+            }
+
+            #[ink(message)]
+            fn remove_strategy(&mut self, strategy: AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesDefaultImpl::remove_strategy_default_impl(self, strategy)
+            }
+
+            #[ink(message)]
+            fn force_remove_strategy(&mut self, strategy: AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesDefaultImpl::force_remove_strategy_default_impl(self, strategy)
+            }
+
+            #[ink(message)]
+            fn set_strategy_weight(&mut self, strategy: AccountId, weight: u16) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesDefaultImpl::set_strategy_weight_default_impl(self, strategy, weight)
+            }
+
+            #[ink(message)]
+            fn rebalance(&mut self) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesDefaultImpl::rebalance_default_impl(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    // the assets deployed to the strategies are part of the vault total assets
+    let deployed_assets_hook = syn::parse2::<syn::Stmt>(quote!(
+        if !pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesInternal::_strategies(self).is_empty() {
+            return pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesInternal::_strategies_assets(self);
+        }
+    ))
+    .expect("Should parse");
+
+    // the withdrawn assets (with the withdrawal fee) are pulled from the strategies if the idle assets are short
+    let withdraw_hook = syn::parse2::<syn::Stmt>(quote!(
+        pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesInternal::_ensure_idle_assets(
+            self,
+            &assets.saturating_add(pendzl::contracts::psp22::vault::PSP22VaultInternal::_fee_on_raw(
+                self,
+                assets,
+                &pendzl::contracts::psp22::vault::PSP22VaultInternal::_withdraw_fee_bps(self),
+            )?),
+        )?;
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::vault_strategies::*;
+    ))
+    .expect("Should parse");
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::vault_strategies::PSP22VaultStrategiesData;
+    ))
+    .expect("Should parse import");
+
+    impl_args.imports.insert("PSP22VaultStrategies", import);
+    impl_args
+        .imports
+        .insert("PSP22VaultStrategiesData", import_data);
+    impl_args.vec_import();
+
+    override_functions(
+        "PSP22VaultStrategiesInternal",
+        &mut internal,
+        impl_args.map,
+    );
+    override_functions("PSP22VaultStrategies", &mut strategies, impl_args.map);
+
+    impl_args.add_hook(
+        "PSP22VaultInternal",
+        "_deployed_assets",
+        deployed_assets_hook,
+    );
+    impl_args.add_hook("PSP22VaultInternal", "_withdraw", withdraw_hook);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args
+        .items
+        .push(syn::Item::Impl(strategies_default_impl));
+    impl_args.items.push(syn::Item::Impl(strategies));
+}
+
+pub(crate) fn impl_psp34(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp34::PSP34InternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp34::PSP34Internal for #storage_struct_name {
+            fn _balance_of(&self, owner: &AccountId) -> u32 {
+                pendzl::contracts::psp34::PSP34InternalDefaultImpl::_balance_of_default_impl(self, owner)
+            }
+
+            fn _total_supply(&self) -> u64 {
+                pendzl::contracts::psp34::PSP34InternalDefaultImpl::_total_supply_default_impl(self)
+            }
+
+            fn _owner_of(&self, id: &Id) -> Option<AccountId> {
+                pendzl::contracts::psp34::PSP34InternalDefaultImpl::_owner_of_default_impl(self,id)
+            }
+
+            fn _allowance(&self, owner: &AccountId, operator: &AccountId, id: &Option<Id>) -> bool {
+                pendzl::contracts::psp34::PSP34InternalDefaultImpl::_allowance_default_impl(self, owner, operator, id)
+            }
+
+            fn _approve(&mut self, owner: &AccountId,operator: &AccountId, id: &Option<Id>, approved: &bool) -> Result<(), PSP34Error> {
+                pendzl::contracts::psp34::PSP34InternalDefaultImpl::_approve_default_impl(self, owner, operator, id, approved)
+            }
+
+            fn _update(
+                &mut self,
+                from: &Option<&AccountId>,
+                to: &Option<&AccountId>,
+                id: &Id,
+            ) -> Result<(), PSP34Error>{
+                pendzl::contracts::psp34::PSP34InternalDefaultImpl::_update_default_impl(self, from, to, id)
+
+            }
+
+            fn _transfer(&mut self, from: &AccountId, to: &AccountId, id: &Id, data: &Vec<u8>) -> Result<(), PSP34Error> {
+                pendzl::contracts::psp34::PSP34Internal::_update(self, &Some(from), &Some(to), id)
+            }
+
+            fn _mint_to(&mut self, to: &AccountId, id: &Id) -> Result<(), PSP34Error> {
+                pendzl::contracts::psp34::PSP34Internal::_update(self, &None, &Some(to), id)
+            }
+
+            fn _burn_from(&mut self, from: &AccountId, id: &Id) -> Result<(), PSP34Error> {
+                pendzl::contracts::psp34::PSP34Internal::_update(self, &Some(from), &None, id)
+            }
+
+        }
+    ))
+    .expect("Should parse");
+
+    let psp34_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp34::PSP34DefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut psp34 = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp34::PSP34 for #storage_struct_name {
+            #[ink(message)]
+            fn collection_id(&self) -> Id {
+                pendzl::contracts::psp34::PSP34DefaultImpl::collection_id_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn balance_of(&self, owner: AccountId) -> u32 {
+                pendzl::contracts::psp34::PSP34DefaultImpl::balance_of_default_impl(self, owner)
+            }
+
+            #[ink(message)]
+            fn owner_of(&self, id: Id) -> Option<AccountId> {
+                pendzl::contracts::psp34::PSP34DefaultImpl::owner_of_default_impl(self, id)
+            }
+
+            #[ink(message)]
+            fn allowance(&self, owner: AccountId, operator: AccountId, id: Option<Id>) -> bool {
+                pendzl::contracts::psp34::PSP34DefaultImpl::allowance_default_impl(self, owner, operator, id)
+            }
+
+            #[ink(message)]
+            fn approve(&mut self, operator: AccountId, id: Option<Id>, approved: bool) -> Result<(), PSP34Error> {
+                pendzl::contracts::psp34::PSP34DefaultImpl::approve_default_impl(self, operator, id, approved)
+            }
+
+            #[ink(message)]
+            fn transfer(&mut self, to: AccountId, id: Id, data: Vec<u8>) -> Result<(), PSP34Error> {
+                pendzl::contracts::psp34::PSP34DefaultImpl::transfer_default_impl(self, to, id, data)
+            }
+
+            #[ink(message)]
+            fn total_supply(&self) -> u64 {
+                pendzl::contracts::psp34::PSP34DefaultImpl::total_supply_default_impl(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp34::*;
+    ))
+    .expect("Should parse");
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp34::PSP34Data;
+    ))
+    .expect("Should parse import");
+
+    impl_args.imports.insert("PSP34", import);
+    impl_args.imports.insert("PSP34Data", import_data);
+    impl_args.vec_import();
+
+    override_functions("PSP34Internal", &mut internal, impl_args.map);
+    override_functions("PSP34", &mut psp34, impl_args.map);
+
+    // only insert this if it is not present
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(psp34_default_impl));
+    impl_args.items.push(syn::Item::Impl(psp34));
+}
+
+pub(crate) fn impl_psp34_burnable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let burnable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp34::burnable::PSP34BurnableDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut burnable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp34::burnable::PSP34Burnable for #storage_struct_name {
+            #[ink(message)]
+            fn burn(&mut self, from: AccountId, id: Id) -> Result<(), PSP34Error> {
+                pendzl::contracts::psp34::burnable::PSP34BurnableDefaultImpl::burn_default_impl(self,from,id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp34::burnable::*;
+    ))
+    .expect("Should parse");
+
+    impl_args.imports.insert("PSP34Burnable", import);
+    impl_args.vec_import();
+
+    override_functions("PSP34Burnable", &mut burnable, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(burnable_default_impl));
+    impl_args.items.push(syn::Item::Impl(burnable));
+}
+
+pub(crate) fn impl_psp34_mintable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let mintable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp34::mintable::PSP34MintableDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut mintable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp34::mintable::PSP34Mintable for #storage_struct_name {
+            #[ink(message)]
+            fn mint(&mut self, from: AccountId, id: Id) -> Result<(), PSP34Error> {
+                pendzl::contracts::psp34::mintable::PSP34MintableDefaultImpl::mint_default_impl(self,from,id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp34::mintable::*;
+    ))
+    .expect("Should parse");
+
+    impl_args.imports.insert("PSP34Mintable", import);
+    impl_args.vec_import();
+
+    override_functions("PSP34Mintable", &mut mintable, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(mintable_default_impl));
+    impl_args.items.push(syn::Item::Impl(mintable));
+}
+
+pub(crate) fn impl_psp34_metadata(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp34::metadata::PSP34MetadataInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp34::metadata::PSP34MetadataInternal for #storage_struct_name {
+
+            fn _set_attribute(&mut self, id: &Id, key: &String, value: &String) {
+                pendzl::contracts::psp34::metadata::PSP34MetadataInternalDefaultImpl::_set_attribute_default_impl(self, id, key, value)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let metadata_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp34::metadata::PSP34MetadataDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut metadata = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp34::metadata::PSP34Metadata for #storage_struct_name {
+            #[ink(message)]
+            fn get_attribute(&self, id: Id, key: String) -> Option<String> {
+                pendzl::contracts::psp34::metadata::PSP34MetadataDefaultImpl::get_attribute_default_impl(self, id, key)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp34::metadata::*;
+    ))
+    .expect("Should parse");
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp34::metadata::PSP34MetadataData;
+    ))
+    .expect("Should parse import");
+
+    impl_args.imports.insert("PSP34Metadata", import);
+    impl_args.imports.insert("PSP34MetadataData", import_data);
+
+    impl_args.vec_import();
+
+    override_functions("PSP34MetadataInternal", &mut internal, impl_args.map);
+    override_functions("PSP34Metadata", &mut metadata, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(metadata_default_impl));
+    impl_args.items.push(syn::Item::Impl(metadata));
+}
+
+pub(crate) fn impl_ownable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::ownable::OwnableInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::ownable::OwnableInternal for #storage_struct_name {
+            fn _owner(&self) -> Option<AccountId>{
+                pendzl::contracts::ownable::OwnableInternalDefaultImpl::_owner_default_impl(self)
+            }
+            fn _update_owner(&mut self, owner: &Option<AccountId>){
+                pendzl::contracts::ownable::OwnableInternalDefaultImpl::_update_owner_default_impl(self, owner);
+
+            }
+            fn _only_owner(&self) -> Result<(), OwnableError> {
+                pendzl::contracts::ownable::OwnableInternalDefaultImpl::_only_owner_default_impl(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let ownable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::ownable::OwnableDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut ownable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::ownable::Ownable for #storage_struct_name {
+            #[ink(message)]
+            fn owner(&self) -> Option<AccountId> {
+                pendzl::contracts::ownable::OwnableDefaultImpl::owner_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn renounce_ownership(&mut self) -> Result<(), OwnableError> {
+                pendzl::contracts::ownable::OwnableDefaultImpl::renounce_ownership_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), OwnableError> {
+                pendzl::contracts::ownable::OwnableDefaultImpl::transfer_ownership_default_impl(self, new_owner)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::ownable::*;
+    ))
+    .expect("Should parse");
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::ownable::OwnableData;
+    ))
+    .expect("Should parse import");
+
+    impl_args.imports.insert("Ownable", import);
+    impl_args.imports.insert("OwnableData", import_data);
+
+    override_functions("OwnableInternal", &mut internal, impl_args.map);
+    override_functions("Ownable", &mut ownable, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(ownable_default_impl));
+    impl_args.items.push(syn::Item::Impl(ownable));
+}
+
+pub(crate) fn impl_access_control(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::access_control::AccessControlInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::access_control::AccessControlInternal for #storage_struct_name {
+            fn _default_admin() -> RoleType {
+                <Self as pendzl::contracts::access_control::AccessControlInternalDefaultImpl>::_default_admin_default_impl()
+            }
+
+            fn _has_role(&self, role: RoleType, account: Option<AccountId>) -> bool{
+                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_has_role_default_impl(self, role, account)
+            }
+
+            fn _grant_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_grant_role_default_impl(self, role, account)
+            }
+
+            fn _do_revoke_role(&mut self, role: RoleType, account: Option<AccountId>)  -> Result<(), AccessControlError>  {
+                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_do_revoke_role_default_impl(self, role, account)
+            }
+
+            fn _get_role_admin(&self, role: RoleType) -> RoleType {
+                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_get_role_admin_default_impl(self, role)
+            }
+
+            fn _set_role_admin(&mut self, role: RoleType, new_admin: RoleType) {
+                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_set_role_admin_default_impl(self, role, new_admin);
+            }
+
+            fn _ensure_has_role(&self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                pendzl::contracts::access_control::AccessControlInternalDefaultImpl::_ensure_has_role_default_impl(self, role, account)
+            }
+
+        }
+    ))
+    .expect("Should parse");
+
+    let access_control_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::access_control::AccessControlDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut access_control = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::access_control::AccessControl for #storage_struct_name {
+            #[ink(message)]
+            fn has_role(&self, role: RoleType, address: Option<AccountId>) -> bool {
+                pendzl::contracts::access_control::AccessControlDefaultImpl::has_role_default_impl(self, role, address)
+            }
+
+            #[ink(message)]
+            fn get_role_admin(&self, role: RoleType) -> RoleType {
+                pendzl::contracts::access_control::AccessControlDefaultImpl::get_role_admin_default_impl(self, role)
+            }
+
+            #[ink(message)]
+            fn grant_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                pendzl::contracts::access_control::AccessControlDefaultImpl::grant_role_default_impl(self, role, account)
+            }
+
+            #[ink(message)]
+            fn revoke_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                pendzl::contracts::access_control::AccessControlDefaultImpl::revoke_role_default_impl(self, role, account)
+            }
+
+            #[ink(message)]
+            fn renounce_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                pendzl::contracts::access_control::AccessControlDefaultImpl::renounce_role_default_impl(self, role, account)
+            }
+
+            #[ink(message)]
+            fn set_role_admin(&mut self, role: RoleType, new_admin: RoleType) -> Result<(), AccessControlError> {
+                pendzl::contracts::access_control::AccessControlDefaultImpl::set_role_admin_default_impl(self, role, new_admin)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::access_control::*;
+    ))
+    .expect("Should parse");
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::access_control::AccessControlData;
+    ))
+    .expect("Should parse import");
+
+    impl_args.imports.insert("AccessControl", import);
+    impl_args.imports.insert("AccessControlData", import_data);
+
+    override_functions("AccessControlInternal", &mut internal, impl_args.map);
+    override_functions("AccessControl", &mut access_control, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args
+        .items
+        .push(syn::Item::Impl(access_control_default_impl));
+    impl_args.items.push(syn::Item::Impl(access_control));
+}
+
+pub(crate) fn impl_pausable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::pausable::PausableInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::pausable::PausableInternal for #storage_struct_name {
+            fn _paused(&self) -> bool {
+                pendzl::contracts::pausable::PausableInternalDefaultImpl::_paused_default_impl(self)
+            }
+
+            fn _pause(&mut self) -> Result<(), PausableError> {
+                pendzl::contracts::pausable::PausableInternalDefaultImpl::_pause_default_impl(self)
+            }
+
+            fn _unpause(&mut self) -> Result<(), PausableError> {
+                pendzl::contracts::pausable::PausableInternalDefaultImpl::_unpause_default_impl(self)
+            }
+
+            fn _ensure_paused(&self) -> Result<(), PausableError> {
+                pendzl::contracts::pausable::PausableInternalDefaultImpl::_ensure_paused_default_impl(self)
+            }
+
+            fn _ensure_not_paused(&self) -> Result<(), PausableError> {
+                pendzl::contracts::pausable::PausableInternalDefaultImpl::_ensure_not_paused_default_impl(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let pausable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl  pendzl::contracts::pausable::PausableDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut pausable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl  pendzl::contracts::pausable::Pausable for #storage_struct_name {
+            #[ink(message)]
+            fn paused(&self) -> bool {
+                pendzl::contracts::pausable::PausableDefaultImpl::paused_default_impl(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::pausable::*;
+    ))
+    .expect("Should parse import");
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::pausable::PausableData;
+    ))
+    .expect("Should parse import");
+    impl_args.imports.insert("Pausable", import);
+    impl_args.imports.insert("PausableData", import_data);
+
+    override_functions("PausableInternal", &mut internal, impl_args.map);
+    override_functions("Pausable", &mut pausable, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(pausable_default_impl));
+    impl_args.items.push(syn::Item::Impl(pausable));
+}
+pub(crate) fn impl_vesting(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::general_vest::GeneralVestInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::general_vest::GeneralVestInternal for #storage_struct_name {
+            fn _create_vest(&mut self,
+                receiver: AccountId,
+                asset: Option<AccountId>,
+                amount: Balance,
+                schedule: VestingSchedule,
+                data: &Vec<u8>
+            )-> Result<(), VestingError>  {
+                pendzl::contracts::general_vest::GeneralVestInternalDefaultImpl::_create_vest_default_impl(
+                    self,
+                    receiver,
+                    asset,
+                    amount,
+                    schedule,
+                    data
+                )
+            }
+
+            fn _release(&mut self, receiver: Option<AccountId>, asset: Option<AccountId>, data: &Vec<u8>) -> Result<u128, VestingError> {
+                pendzl::contracts::general_vest::GeneralVestInternalDefaultImpl::_release_default_impl(self, receiver, asset, data)
+            }
+
+            fn _release_by_vest_id(&mut self, receiver: Option<AccountId>, asset: Option<AccountId>, id: u32, data: &Vec<u8>) -> Result<(), VestingError> {
+                pendzl::contracts::general_vest::GeneralVestInternalDefaultImpl::_release_by_vest_id_default_impl(self, receiver, asset, id, data)
+            }
+
+            fn _handle_transfer_in(&mut self, asset: Option<AccountId>, from: AccountId, amount: Balance, data: &Vec<u8>) -> Result<(), VestingError> {
+                pendzl::contracts::general_vest::GeneralVestInternalDefaultImpl::_handle_transfer_in_default_impl(self, asset, from, amount, data)
+            }
+
+            fn _handle_transfer_out(&mut self, asset: Option<AccountId>, to: AccountId, amount: Balance, data: &Vec<u8>) -> Result<(), VestingError> {
+                pendzl::contracts::general_vest::GeneralVestInternalDefaultImpl::_handle_transfer_out_default_impl(self, asset, to, amount, data)
+            }
+
+            fn _next_id_vest_of(&self, of: AccountId, asset: Option<AccountId>, data: &Vec<u8>) -> u32 {
+                pendzl::contracts::general_vest::GeneralVestInternalDefaultImpl::_next_id_vest_of_default_impl(self, of, asset, data)
+            }
+
+            fn _vesting_schedule_of(&self, of: AccountId, asset: Option<AccountId>, id: u32, data: &Vec<u8>) -> Option<VestingData> {
+                pendzl::contracts::general_vest::GeneralVestInternalDefaultImpl::_vesting_schedule_of_default_impl(self, of, asset, id, data)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let vesting_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl  pendzl::contracts::general_vest::GeneralVestDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut general_vest = syn::parse2::<syn::ItemImpl>(quote!(
+        impl  pendzl::contracts::general_vest::GeneralVest for #storage_struct_name {
+            #[ink(message, payable)]
+            fn create_vest(
+                &mut self,
+                receiver: AccountId,
+                asset: Option<AccountId>,
+                amount: Balance,
+                schedule: VestingSchedule,
+                data: Vec<u8>,
+            ) -> Result<(), VestingError> {
+                pendzl::contracts::general_vest::GeneralVestDefaultImpl::create_vest_default_impl(
+                    self,
+                    receiver,
+                    asset,
+                    amount,
+                    schedule,
+                    data
+                )
+            }
+            #[ink(message)]
+            fn release(&mut self, receiver: Option<AccountId>, asset: Option<AccountId>, data: Vec<u8>) -> Result<u128, VestingError> {
+                pendzl::contracts::general_vest::GeneralVestDefaultImpl::release_default_impl(self, receiver, asset, data)
+            }
+            #[ink(message)]
+            fn release_by_vest_id(&mut self, receiver: Option<AccountId>, asset: Option<AccountId>, id: u32, data: Vec<u8>) -> Result<(), VestingError> {
+                pendzl::contracts::general_vest::GeneralVestDefaultImpl::release_by_vest_id_default_impl(self, receiver, asset, id, data)
+            }
+            #[ink(message)]
+            fn next_id_vest_of(&self,  of: AccountId, asset: Option<AccountId>, data: Vec<u8>) -> u32 {
+                pendzl::contracts::general_vest::GeneralVestDefaultImpl::next_id_vest_of_default_impl(self, of, asset, data)
+            }
+            #[ink(message)]
+            fn vesting_schedule_of(&self, of: AccountId, asset: Option<AccountId>, id: u32, data: Vec<u8>) -> Option<VestingData> {
+                pendzl::contracts::general_vest::GeneralVestDefaultImpl::vesting_schedule_of_default_impl(self, of, asset, id, data)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::general_vest::*;
+    ))
+    .expect("Should parse import");
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::general_vest::GeneralVestData;
+    ))
+    .expect("Should parse import");
+    impl_args.imports.insert("GeneralVest", import);
+    impl_args.imports.insert("GeneralVestData", import_data);
+
+    impl_args.vec_import();
+
+    override_functions("GeneralVestInternal", &mut internal, impl_args.map);
+    override_functions("GeneralVest", &mut general_vest, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(vesting_default_impl));
+    impl_args.items.push(syn::Item::Impl(general_vest));
+}
+
+pub(crate) fn impl_set_code_hash(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::set_code_hash::SetCodeHashInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::set_code_hash::SetCodeHashInternal for #storage_struct_name {
+            fn _set_code_hash(&mut self, set_code_hash: Hash) -> Result<(), SetCodeHashError> {
+                pendzl::contracts::set_code_hash::SetCodeHashInternalDefaultImpl::_set_code_hash_default_impl(self, set_code_hash)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let upgradeable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::set_code_hash::SetCodeHashDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut set_code_hash = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::set_code_hash::SetCodeHash for #storage_struct_name {
+            #[ink(message)]
+            fn set_code_hash(&mut self, set_code_hash: Hash) -> Result<(), SetCodeHashError> {
+                pendzl::contracts::set_code_hash::SetCodeHashDefaultImpl::set_code_hash_default_impl(self, set_code_hash)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::set_code_hash::*;
+    ))
+    .expect("Should parse");
+
+    impl_args.imports.insert("SetCodeHash", import);
+
+    override_functions("SetCodeHashInternal", &mut internal, impl_args.map);
+    override_functions("SetCodeHash", &mut set_code_hash, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args
+        .items
+        .push(syn::Item::Impl(upgradeable_default_impl));
+    impl_args.items.push(syn::Item::Impl(set_code_hash));
+}
+
+fn override_functions(
+    trait_name: &str,
+    implementation: &mut syn::ItemImpl,
+    map: &OverridenFnMap,
+) {
+    if let Some(overrides) = map.get(trait_name) {
+        // we will find which fns we wanna override
+        for (fn_name, (fn_code, attributes, inputs)) in overrides {
+            let mut original_fn_found = false;
+            for item in implementation.items.iter_mut() {
+                if let syn::ImplItem::Method(method) = item {
+                    if &method.sig.ident.to_string() == fn_name {
+                        let Some(bindings) = crate::internal::bind_renamed_args(
+                            &method.sig.inputs,
+                            inputs,
+                        ) else {
+                            let original_args = method
+                                .sig
+                                .inputs
+                                .clone()
+                                .into_iter()
+                                .map(|arg| {
+                                    crate::internal::format_arg_string(
+                                        &arg.into_token_stream().to_string(),
+                                    )
+                                })
+                                .collect::<Vec<_>>()
+                                .join(", ");
+                            let current_args = inputs
+                                .clone()
+                                .into_iter()
+                                .map(|arg| {
+                                    crate::internal::format_arg_string(
+                                        &arg.into_token_stream().to_string(),
+                                    )
+                                })
+                                .collect::<Vec<_>>()
+                                .join(", ");
+
+                            panic!(
+                                "Function arguments do not match for fn {} in trait {} - the types must match by position \n
+                            original args: {:?} \n
+                            current args: {:?}",
+                                fn_name, trait_name, original_args, current_args
+                            )
+                        };
+
+                        method.block = *fn_code.clone();
+                        method.block.stmts.splice(0..0, bindings);
+                        method.attrs.append(&mut attributes.to_vec());
+
+                        original_fn_found = true;
+                    }
+                }
+            }
+            if !original_fn_found {
+                panic!("Could not find fn {} in trait {}", fn_name, trait_name)
+            }
+        }
+    }
+}
+
+/// Injects the hooks at the beginning of the (possibly overriden) bodies of the hooked fns.
+pub(crate) fn inject_hooks(items: &mut [syn::Item], hooks: &FnHooksMap) {
+    for ((trait_name, fn_name), stmts) in hooks {
+        let method = items
+            .iter_mut()
+            .filter_map(|item| match item {
+                syn::Item::Impl(implementation) => Some(implementation),
+                _ => None,
+            })
+            .filter(|implementation| {
+                implementation.trait_.as_ref().is_some_and(|(_, path, _)| {
+                    path.segments
+                        .last()
+                        .is_some_and(|segment| segment.ident == trait_name)
+                })
+            })
+            .flat_map(|implementation| implementation.items.iter_mut())
+            .find_map(|item| match item {
+                syn::ImplItem::Method(method)
+                    if method.sig.ident == fn_name =>
+                {
+                    Some(method)
+                }
+                _ => None,
+            })
+            .unwrap_or_else(|| {
+                panic!(
+                    "Could not find fn {} in trait {} - implement {} to use the extensions hooking into it",
+                    fn_name, trait_name, trait_name
+                )
+            });
+
+        method.block.stmts.splice(0..0, stmts.iter().cloned());
+    }
+}
+
+pub(crate) fn impl_psp22_wrapper(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::wrapper::PSP22WrapperInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::wrapper::PSP22WrapperInternal for #storage_struct_name {
+            fn _underlying(&self) -> PSP22Ref {
+                pendzl::contracts::psp22::wrapper::PSP22WrapperInternalDefaultImpl::_underlying_default_impl(self)
+            }
+
+            fn _deposit_for(
+                &mut self,
+                caller: &AccountId,
+                account: &AccountId,
+                amount: &Balance,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::wrapper::PSP22WrapperInternalDefaultImpl::_deposit_for_default_impl(self, caller, account, amount)
+            }
+
+            fn _withdraw_to(
+                &mut self,
+                caller: &AccountId,
+                account: &AccountId,
+                amount: &Balance,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::wrapper::PSP22WrapperInternalDefaultImpl::_withdraw_to_default_impl(self, caller, account, amount)
+            }
+
+            fn _recover(&mut self, account: &AccountId) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::wrapper::PSP22WrapperInternalDefaultImpl::_recover_default_impl(self, account)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let wrapper_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::wrapper::PSP22WrapperDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut wrapper = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::wrapper::PSP22Wrapper for #storage_struct_name {
+            #[ink(message)]
+            fn underlying(&self) -> AccountId {
+                pendzl::contracts::psp22::wrapper::PSP22WrapperDefaultImpl::underlying_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn deposit_for(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::wrapper::PSP22WrapperDefaultImpl::deposit_for_default_impl(self, account, amount)
+            }
+
+            #[ink(message)]
+            fn withdraw_to(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::wrapper::PSP22WrapperDefaultImpl::withdraw_to_default_impl(self, account, amount)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::wrapper::*;
+    ))
+    .expect("Should parse");
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::wrapper::PSP22WrapperData;
+    ))
+    .expect("Should parse import");
+
+    impl_args.imports.insert("PSP22Wrapper", import);
+    impl_args.imports.insert("PSP22WrapperData", import_data);
+    impl_args.vec_import();
+
+    override_functions("PSP22WrapperInternal", &mut internal, impl_args.map);
+    override_functions("PSP22Wrapper", &mut wrapper, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(wrapper_default_impl));
+    impl_args.items.push(syn::Item::Impl(wrapper));
+}
+
+pub(crate) fn impl_psp22_native_wrapper(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::native_wrapper::PSP22NativeWrapperInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::native_wrapper::PSP22NativeWrapperInternal for #storage_struct_name {
+            fn _deposit_native(&mut self, account: &AccountId, amount: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::native_wrapper::PSP22NativeWrapperInternalDefaultImpl::_deposit_native_default_impl(self, account, amount)
+            }
+
+            fn _withdraw_native(&mut self, account: &AccountId, amount: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::native_wrapper::PSP22NativeWrapperInternalDefaultImpl::_withdraw_native_default_impl(self, account, amount)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let native_wrapper_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::native_wrapper::PSP22NativeWrapperDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut native_wrapper = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::native_wrapper::PSP22NativeWrapper for #storage_struct_name {
+            #[ink(message, payable)]
+            fn deposit(&mut self) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::native_wrapper::PSP22NativeWrapperDefaultImpl::deposit_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn withdraw(&mut self, amount: Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::native_wrapper::PSP22NativeWrapperDefaultImpl::withdraw_default_impl(self, amount)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::native_wrapper::*;
+    ))
+    .expect("Should parse");
+
+    impl_args.imports.insert("PSP22NativeWrapper", import);
+    impl_args.vec_import();
+
+    override_functions(
+        "PSP22NativeWrapperInternal",
+        &mut internal,
+        impl_args.map,
+    );
+    override_functions(
+        "PSP22NativeWrapper",
+        &mut native_wrapper,
+        impl_args.map,
+    );
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args
+        .items
+        .push(syn::Item::Impl(native_wrapper_default_impl));
+    impl_args.items.push(syn::Item::Impl(native_wrapper));
+}
+
+pub(crate) fn impl_psp22_batch(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::batch::PSP22BatchInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::batch::PSP22BatchInternal for #storage_struct_name {
+            fn _batch_transfer(
+                &mut self,
+                from: &AccountId,
+                operator: &AccountId,
+                transfers: &[(AccountId, Balance)],
+                data: &[u8],
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::batch::PSP22BatchInternalDefaultImpl::_batch_transfer_default_impl(self, from, operator, transfers, data)
+            }
+
+            fn _batch_approve(
+                &mut self,
+                owner: &AccountId,
+                approvals: &[(AccountId, Balance)],
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::batch::PSP22BatchInternalDefaultImpl::_batch_approve_default_impl(self, owner, approvals)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let batch_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::batch::PSP22BatchDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut batch = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::batch::PSP22Batch for #storage_struct_name {
+            #[ink(message)]
+            fn batch_transfer(
+                &mut self,
+                transfers: Vec<(AccountId, Balance)>,
+                data: Vec<u8>,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::batch::PSP22BatchDefaultImpl::batch_transfer_default_impl(self, transfers, data)
+            }
+
+            #[ink(message)]
+            fn batch_transfer_from(
+                &mut self,
+                from: AccountId,
+                transfers: Vec<(AccountId, Balance)>,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::batch::PSP22BatchDefaultImpl::batch_transfer_from_default_impl(self, from, transfers)
+            }
+
+            #[ink(message)]
+            fn batch_approve(
+                &mut self,
+                approvals: Vec<(AccountId, Balance)>,
+            ) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::batch::PSP22BatchDefaultImpl::batch_approve_default_impl(self, approvals)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::batch::*;
+    ))
+    .expect("Should parse");
+
+    impl_args.imports.insert("PSP22Batch", import);
+    impl_args.vec_import();
+
+    override_functions("PSP22BatchInternal", &mut internal, impl_args.map);
+    override_functions("PSP22Batch", &mut batch, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(batch_default_impl));
+    impl_args.items.push(syn::Item::Impl(batch));
+}
+
+pub(crate) fn impl_psp22_fee(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::fee::PSP22FeeInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::fee::PSP22FeeInternal for #storage_struct_name {
+            fn _fee_bps(&self) -> u16 {
+                pendzl::contracts::psp22::fee::PSP22FeeInternalDefaultImpl::_fee_bps_default_impl(self)
+            }
+
+            fn _fee_recipient(&self) -> Option<AccountId> {
+                pendzl::contracts::psp22::fee::PSP22FeeInternalDefaultImpl::_fee_recipient_default_impl(self)
+            }
+
+            fn _is_fee_exempt(&self, account: &AccountId) -> bool {
+                pendzl::contracts::psp22::fee::PSP22FeeInternalDefaultImpl::_is_fee_exempt_default_impl(self, account)
+            }
+
+            fn _set_fee_bps(&mut self, fee_bps: &u16) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::fee::PSP22FeeInternalDefaultImpl::_set_fee_bps_default_impl(self, fee_bps)
+            }
+
+            fn _set_fee_recipient(&mut self, recipient: &Option<AccountId>) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::fee::PSP22FeeInternalDefaultImpl::_set_fee_recipient_default_impl(self, recipient)
+            }
+
+            fn _set_fee_exempt(&mut self, account: &AccountId, exempt: bool) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::fee::PSP22FeeInternalDefaultImpl::_set_fee_exempt_default_impl(self, account, exempt)
+            }
+
+            fn _fee_rounding(&self) -> Rounding {
+                pendzl::contracts::psp22::fee::PSP22FeeInternalDefaultImpl::_fee_rounding_default_impl(self)
+            }
+
+            fn _fee_of(&self, amount: &Balance) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::fee::PSP22FeeInternalDefaultImpl::_fee_of_default_impl(self, amount)
+            }
+
+            fn _charge_fee(
+                &mut self,
+                from: Option<&AccountId>,
+                to: Option<&AccountId>,
+                amount: &Balance,
+            ) -> Result<Balance, PSP22Error> {
+                pendzl::contracts::psp22::fee::PSP22FeeInternalDefaultImpl::_charge_fee_default_impl(self, from, to, amount)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let fee_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::fee::PSP22FeeDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut fee = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::fee::PSP22Fee for #storage_struct_name {
+            #[ink(message)]
+            fn fee_bps(&self) -> u16 {
+                pendzl::contracts::psp22::fee::PSP22FeeDefaultImpl::fee_bps_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn fee_recipient(&self) -> Option<AccountId> {
+                pendzl::contracts::psp22::fee::PSP22FeeDefaultImpl::fee_recipient_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn is_fee_exempt(&self, account: AccountId) -> bool {
+                pendzl::contracts::psp22::fee::PSP22FeeDefaultImpl::is_fee_exempt_default_impl(self, account)
+            }
+
+            #[ink(message)]
+            fn set_fee_bps(&mut self, fee_bps: u16) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::fee::PSP22FeeDefaultImpl::set_fee_bps_default_impl(self, fee_bps)
+            }
+
+            #[ink(message)]
+            fn set_fee_recipient(&mut self, recipient: Option<AccountId>) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::fee::PSP22FeeDefaultImpl::set_fee_recipient_default_impl(self, recipient)
+            }
+
+            #[ink(message)]
+            fn set_fee_exempt(&mut self, account: AccountId, exempt: bool) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::fee::PSP22FeeDefaultImpl::set_fee_exempt_default_impl(self, account, exempt)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    // shadows `amount` with the net amount, so the rest of `_update` (and other hooks) only moves the net amount
+    let update_hook = syn::parse2::<syn::Stmt>(quote!(
+        let amount = &pendzl::contracts::psp22::fee::PSP22FeeInternal::_charge_fee(self, from, to, amount)?;
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::fee::*;
+    ))
+    .expect("Should parse");
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::fee::PSP22FeeData;
+    ))
+    .expect("Should parse import");
+
+    impl_args.imports.insert("PSP22Fee", import);
+    impl_args.imports.insert("PSP22FeeData", import_data);
+    impl_args.vec_import();
+
+    override_functions("PSP22FeeInternal", &mut internal, impl_args.map);
+    override_functions("PSP22Fee", &mut fee, impl_args.map);
+
+    // the fee hook must run before other `_update` hooks, so they see the net amount
+    impl_args
+        .hooks
+        .entry(("PSP22Internal", "_update"))
+        .or_default()
+        .insert(0, update_hook);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(fee_default_impl));
+    impl_args.items.push(syn::Item::Impl(fee));
+}
+
+pub(crate) fn impl_psp22_restricted(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::restricted::PSP22RestrictedInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::restricted::PSP22RestrictedInternal for #storage_struct_name {
+            fn _restriction_mode(&self) -> RestrictionMode {
+                pendzl::contracts::psp22::restricted::PSP22RestrictedInternalDefaultImpl::_restriction_mode_default_impl(self)
+            }
+
+            fn _is_listed(&self, account: &AccountId) -> bool {
+                pendzl::contracts::psp22::restricted::PSP22RestrictedInternalDefaultImpl::_is_listed_default_impl(self, account)
+            }
+
+            fn _is_restricted(&self, account: &AccountId) -> bool {
+                pendzl::contracts::psp22::restricted::PSP22RestrictedInternalDefaultImpl::_is_restricted_default_impl(self, account)
+            }
+
+            fn _add_to_list(&mut self, account: &AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::restricted::PSP22RestrictedInternalDefaultImpl::_add_to_list_default_impl(self, account)
+            }
+
+            fn _remove_from_list(&mut self, account: &AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::restricted::PSP22RestrictedInternalDefaultImpl::_remove_from_list_default_impl(self, account)
+            }
+
+            fn _ensure_not_restricted(&self, accounts: &[Option<&AccountId>]) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::restricted::PSP22RestrictedInternalDefaultImpl::_ensure_not_restricted_default_impl(self, accounts)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let restricted_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::restricted::PSP22RestrictedDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut restricted = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::restricted::PSP22Restricted for #storage_struct_name {
+            #[ink(message)]
+            fn restriction_mode(&self) -> RestrictionMode {
+                pendzl::contracts::psp22::restricted::PSP22RestrictedDefaultImpl::restriction_mode_default_impl(self)
+            }
+
+            #[ink(message)]
+            fn is_listed(&self, account: AccountId) -> bool {
+                pendzl::contracts::psp22::restricted::PSP22RestrictedDefaultImpl::is_listed_default_impl(self, account)
+            }
+
+            #[ink(message)]
+            fn is_restricted(&self, account: AccountId) -> bool {
+                pendzl::contracts::psp22::restricted::PSP22RestrictedDefaultImpl::is_restricted_default_impl(self, account)
+            }
+
+            #[ink(message)]
+            fn add_to_list(&mut self, account: AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::restricted::PSP22RestrictedDefaultImpl::add_to_list_default_impl(self, account)
+            }
+
+            #[ink(message)]
+            fn remove_from_list(&mut self, account: AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::restricted::PSP22RestrictedDefaultImpl::remove_from_list_default_impl(self, account)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let update_hook = syn::parse2::<syn::Stmt>(quote!(
+        pendzl::contracts::psp22::restricted::PSP22RestrictedInternal::_ensure_not_restricted(self, &[from, to])?;
+    ))
+    .expect("Should parse");
+
+    let approve_hook = syn::parse2::<syn::Stmt>(quote!(
+        pendzl::contracts::psp22::restricted::PSP22RestrictedInternal::_ensure_not_restricted(self, &[Some(owner), Some(spender)])?;
+    ))
+    .expect("Should parse");
+
+    // a restricted spender can not use the allowance it was given before
+    let spend_allowance_hook = syn::parse2::<syn::Stmt>(quote!(
+        pendzl::contracts::psp22::restricted::PSP22RestrictedInternal::_ensure_not_restricted(self, &[Some(spender)])?;
+    ))
+    .expect("Should parse");
+
+    // the allowance of a restricted spender can still be revoked
+    let set_allowance_hook = syn::parse2::<syn::Stmt>(quote!(
+        pendzl::contracts::psp22::restricted::PSP22RestrictedInternal::_ensure_not_restricted(
+            self,
+            &[Some(owner), (*amount != 0).then_some(spender)],
+        )?;
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::restricted::*;
+    ))
+    .expect("Should parse");
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::restricted::PSP22RestrictedData;
+    ))
+    .expect("Should parse import");
+
+    impl_args.imports.insert("PSP22Restricted", import);
+    impl_args.imports.insert("PSP22RestrictedData", import_data);
+    impl_args.vec_import();
+
+    override_functions("PSP22RestrictedInternal", &mut internal, impl_args.map);
+    override_functions("PSP22Restricted", &mut restricted, impl_args.map);
+
+    impl_args.add_hook("PSP22Internal", "_update", update_hook);
+    impl_args.add_hook("PSP22Internal", "_approve", set_allowance_hook);
+    impl_args.add_hook(
+        "PSP22Internal",
+        "_increase_allowance_from_to",
+        approve_hook,
+    );
+    impl_args.add_hook(
+        "PSP22Internal",
+        "_spend_allowance",
+        spend_allowance_hook,
+    );
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args
+        .items
+        .push(syn::Item::Impl(restricted_default_impl));
+    impl_args.items.push(syn::Item::Impl(restricted));
+}
+
+pub(crate) fn impl_psp22_rebasing(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::rebasing::PSP22RebasingInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::rebasing::PSP22RebasingInternal for #storage_struct_name {
+            fn _shares_of(&self, account: &AccountId) -> Balance {
+                pendzl::contracts::psp22::rebasing::PSP22RebasingInternalDefaultImpl::_shares_of_default_impl(self, account)
+            }
+
+            fn _total_shares(&self) -> Balance {
+                pendzl::contracts::psp22::rebasing::PSP22RebasingInternalDefaultImpl::_total_shares_default_impl(self)
+            }
+
+            fn _rebase(&mut self, new_total_supply: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::rebasing::PSP22RebasingInternalDefaultImpl::_rebase_default_impl(self, new_total_supply)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let rebasing_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::rebasing::PSP22RebasingDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut rebasing = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::rebasing::PSP22Rebasing for #storage_struct_name {
+            #[ink(message)]
+            fn shares_of(&self, account: AccountId) -> Balance {
+                pendzl::contracts::psp22::rebasing::PSP22RebasingDefaultImpl::shares_of_default_impl(self, account)
+            }
+
+            #[ink(message)]
+            fn total_shares(&self) -> Balance {
+                pendzl::contracts::psp22::rebasing::PSP22RebasingDefaultImpl::total_shares_default_impl(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::rebasing::*;
+    ))
+    .expect("Should parse");
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::rebasing::PSP22RebasingData;
+    ))
+    .expect("Should parse import");
+
+    impl_args.imports.insert("PSP22Rebasing", import);
+    impl_args.imports.insert("PSP22RebasingData", import_data);
+    impl_args.vec_import();
+
+    override_functions("PSP22RebasingInternal", &mut internal, impl_args.map);
+    override_functions("PSP22Rebasing", &mut rebasing, impl_args.map);
+
+    // the PSP22 implementation stores the balances as shares in PSP22RebasingData
+    let psp22_internal_default_impl = impl_args
+        .items
+        .iter_mut()
+        .find_map(|item| match item {
+            syn::Item::Impl(item_impl) => item_impl
+                .trait_
+                .as_mut()
+                .map(|(_, path, _)| path)
+                .filter(|path| {
+                    path.segments.last().is_some_and(|segment| {
+                        segment.ident == "PSP22InternalDefaultImpl"
+                    })
+                }),
+            _ => None,
+        })
+        .expect("pendzl::implementation(PSP22Rebasing) requires PSP22 to be implemented before it!");
+    *psp22_internal_default_impl = syn::parse2::<syn::Path>(quote!(
+        pendzl::contracts::psp22::PSP22InternalDefaultImpl<
+            pendzl::contracts::psp22::rebasing::PSP22RebasingData,
+        >
+    ))
+    .expect("Should parse");
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(rebasing_default_impl));
+    impl_args.items.push(syn::Item::Impl(rebasing));
+}
+
+pub(crate) fn impl_psp22_lockable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::lockable::PSP22LockableInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::lockable::PSP22LockableInternal for #storage_struct_name {
+            fn _locked_balance_of(&self, account: &AccountId) -> Balance {
+                pendzl::contracts::psp22::lockable::PSP22LockableInternalDefaultImpl::_locked_balance_of_default_impl(self, account)
+            }
+
+            fn _locks_of(&self, account: &AccountId) -> Vec<Lock> {
+                pendzl::contracts::psp22::lockable::PSP22LockableInternalDefaultImpl::_locks_of_default_impl(self, account)
+            }
+
+            fn _lock(&mut self, account: &AccountId, amount: &Balance, until: &Timestamp) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::lockable::PSP22LockableInternalDefaultImpl::_lock_default_impl(self, account, amount, until)
+            }
+
+            fn _ensure_unlocked(&mut self, from: Option<&AccountId>, amount: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::lockable::PSP22LockableInternalDefaultImpl::_ensure_unlocked_default_impl(self, from, amount)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let lockable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::lockable::PSP22LockableDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut lockable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::lockable::PSP22Lockable for #storage_struct_name {
+            #[ink(message)]
+            fn locked_balance_of(&self, account: AccountId) -> Balance {
+                pendzl::contracts::psp22::lockable::PSP22LockableDefaultImpl::locked_balance_of_default_impl(self, account)
+            }
+
+            #[ink(message)]
+            fn locks_of(&self, account: AccountId) -> Vec<Lock> {
+                pendzl::contracts::psp22::lockable::PSP22LockableDefaultImpl::locks_of_default_impl(self, account)
+            }
+
+            #[ink(message)]
+            fn lock(&mut self, account: AccountId, amount: Balance, until: Timestamp) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::lockable::PSP22LockableDefaultImpl::lock_default_impl(self, account, amount, until)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let update_hook = syn::parse2::<syn::Stmt>(quote!(
+        pendzl::contracts::psp22::lockable::PSP22LockableInternal::_ensure_unlocked(self, from, amount)?;
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::lockable::*;
+    ))
+    .expect("Should parse");
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::lockable::PSP22LockableData;
+    ))
+    .expect("Should parse import");
+
+    impl_args.imports.insert("PSP22Lockable", import);
+    impl_args.imports.insert("PSP22LockableData", import_data);
+    impl_args.vec_import();
+
+    override_functions("PSP22LockableInternal", &mut internal, impl_args.map);
+    override_functions("PSP22Lockable", &mut lockable, impl_args.map);
+
+    impl_args.add_hook("PSP22Internal", "_update", update_hook);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(lockable_default_impl));
+    impl_args.items.push(syn::Item::Impl(lockable));
+}
+
+pub(crate) fn impl_psp22_metadata_mutable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::metadata::PSP22MetadataMutableInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::metadata::PSP22MetadataMutableInternal for #storage_struct_name {
+            fn _set_token_name(&mut self, name: &Option<String>) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::metadata::PSP22MetadataMutableInternalDefaultImpl::_set_token_name_default_impl(self, name)
+            }
+
+            fn _set_token_symbol(&mut self, symbol: &Option<String>) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::metadata::PSP22MetadataMutableInternalDefaultImpl::_set_token_symbol_default_impl(self, symbol)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let metadata_mutable_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::metadata::PSP22MetadataMutableDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut metadata_mutable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::metadata::PSP22MetadataMutable for #storage_struct_name {
+            #[ink(message)]
+            fn set_token_name(&mut self, name: Option<String>) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::metadata::PSP22MetadataMutableDefaultImpl::set_token_name_default_impl(self, name)
+            }
+
+            #[ink(message)]
+            fn set_token_symbol(&mut self, symbol: Option<String>) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::metadata::PSP22MetadataMutableDefaultImpl::set_token_symbol_default_impl(self, symbol)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::metadata::*;
+    ))
+    .expect("Should parse");
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::metadata::PSP22MetadataData;
+    ))
+    .expect("Should parse");
+
+    impl_args.imports.insert("PSP22Metadata", import);
+    impl_args.imports.insert("PSP22MetadataData", import_data);
+    impl_args.vec_import();
+
+    override_functions(
+        "PSP22MetadataMutableInternal",
+        &mut internal,
+        impl_args.map,
+    );
+    override_functions(
+        "PSP22MetadataMutable",
+        &mut metadata_mutable,
+        impl_args.map,
+    );
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args
+        .items
+        .push(syn::Item::Impl(metadata_mutable_default_impl));
+    impl_args.items.push(syn::Item::Impl(metadata_mutable));
+}
+
+pub(crate) fn impl_psp22_expiring_allowance(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceInternal for #storage_struct_name {
+            fn _allowance_expiry(&self, owner: &AccountId, spender: &AccountId) -> Option<Timestamp> {
+                pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceInternalDefaultImpl::_allowance_expiry_default_impl(self, owner, spender)
+            }
+
+            fn _is_allowance_expired(&self, owner: &AccountId, spender: &AccountId) -> bool {
+                pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceInternalDefaultImpl::_is_allowance_expired_default_impl(self, owner, spender)
+            }
+
+            fn _approve_with_expiry(&mut self, owner: &AccountId, spender: &AccountId, value: &Balance, expires_at: &Timestamp) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceInternalDefaultImpl::_approve_with_expiry_default_impl(self, owner, spender, value, expires_at)
+            }
+
+            fn _clear_allowance_expiry(&mut self, owner: &AccountId, spender: &AccountId) {
+                pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceInternalDefaultImpl::_clear_allowance_expiry_default_impl(self, owner, spender)
+            }
+
+            fn _ensure_allowance_not_expired(&self, owner: &AccountId, spender: &AccountId) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceInternalDefaultImpl::_ensure_allowance_not_expired_default_impl(self, owner, spender)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let expiring_allowance_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut expiring_allowance = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowance for #storage_struct_name {
+            #[ink(message)]
+            fn approve_with_expiry(&mut self, spender: AccountId, value: Balance, expires_at: Timestamp) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceDefaultImpl::approve_with_expiry_default_impl(self, spender, value, expires_at)
+            }
+
+            #[ink(message)]
+            fn allowance_expiry(&self, owner: AccountId, spender: AccountId) -> Option<Timestamp> {
+                pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceDefaultImpl::allowance_expiry_default_impl(self, owner, spender)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let allowance_hook = syn::parse2::<syn::Stmt>(quote!(
+        if pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceInternal::_is_allowance_expired(self, owner, spender) {
+            return 0;
+        }
+    ))
+    .expect("Should parse");
+
+    let approve_hook = syn::parse2::<syn::Stmt>(quote!(
+        pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceInternal::_clear_allowance_expiry(self, owner, spender);
+    ))
+    .expect("Should parse");
+
+    let ensure_not_expired_hook = syn::parse2::<syn::Stmt>(quote!(
+        pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceInternal::_ensure_allowance_not_expired(self, owner, spender)?;
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::expiring_allowance::*;
+    ))
+    .expect("Should parse");
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::expiring_allowance::PSP22ExpiringAllowanceData;
+    ))
+    .expect("Should parse import");
+
+    impl_args.imports.insert("PSP22ExpiringAllowance", import);
+    impl_args
+        .imports
+        .insert("PSP22ExpiringAllowanceData", import_data);
+    impl_args.vec_import();
+
+    override_functions(
+        "PSP22ExpiringAllowanceInternal",
+        &mut internal,
+        impl_args.map,
+    );
+    override_functions(
+        "PSP22ExpiringAllowance",
+        &mut expiring_allowance,
+        impl_args.map,
+    );
+
+    impl_args.add_hook("PSP22Internal", "_allowance", allowance_hook);
+    impl_args.add_hook("PSP22Internal", "_approve", approve_hook);
+    // an expired allowance can't be increased, it has to be approved again
+    impl_args.add_hook(
+        "PSP22Internal",
+        "_increase_allowance_from_to",
+        ensure_not_expired_hook.clone(),
+    );
+    // checked before the infinite allowance shortcut, so an expired unlimited allowance can't be spent
+    impl_args
+        .hooks
+        .entry(("PSP22Internal", "_spend_allowance"))
+        .or_default()
+        .insert(0, ensure_not_expired_hook);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args
+        .items
+        .push(syn::Item::Impl(expiring_allowance_default_impl));
+    impl_args.items.push(syn::Item::Impl(expiring_allowance));
+}
+
+pub(crate) fn impl_psp22_mint_allowance(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::mint_allowance::PSP22MintAllowanceInternalDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::mint_allowance::PSP22MintAllowanceInternal for #storage_struct_name {
+            fn _minter_allowance(&self, minter: &AccountId) -> Balance {
+                pendzl::contracts::psp22::mint_allowance::PSP22MintAllowanceInternalDefaultImpl::_minter_allowance_default_impl(self, minter)
+            }
+
+            fn _set_minter_allowance(&mut self, minter: &AccountId, amount: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::mint_allowance::PSP22MintAllowanceInternalDefaultImpl::_set_minter_allowance_default_impl(self, minter, amount)
+            }
+
+            fn _spend_minter_allowance(&mut self, minter: &AccountId, amount: &Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::mint_allowance::PSP22MintAllowanceInternalDefaultImpl::_spend_minter_allowance_default_impl(self, minter, amount)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let mint_allowance_default_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::mint_allowance::PSP22MintAllowanceDefaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut mint_allowance = syn::parse2::<syn::ItemImpl>(quote!(
+        impl pendzl::contracts::psp22::mint_allowance::PSP22MintAllowance for #storage_struct_name {
+            #[ink(message)]
+            fn minter_allowance(&self, minter: AccountId) -> Balance {
+                pendzl::contracts::psp22::mint_allowance::PSP22MintAllowanceDefaultImpl::minter_allowance_default_impl(self, minter)
+            }
+
+            #[ink(message)]
+            fn set_minter_allowance(&mut self, minter: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+                pendzl::contracts::psp22::mint_allowance::PSP22MintAllowanceDefaultImpl::set_minter_allowance_default_impl(self, minter, amount)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let mint_hook = syn::parse2::<syn::Stmt>(quote!(
+        pendzl::contracts::psp22::mint_allowance::PSP22MintAllowanceInternal::_spend_minter_allowance(self, &Self::env().caller(), &amount)?;
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::mint_allowance::*;
+    ))
+    .expect("Should parse");
+
+    let import_data = syn::parse2::<syn::ItemUse>(quote!(
+        pub use pendzl::contracts::psp22::mint_allowance::PSP22MintAllowanceData;
+    ))
+    .expect("Should parse import");
+
+    impl_args.imports.insert("PSP22MintAllowance", import);
+    impl_args
+        .imports
+        .insert("PSP22MintAllowanceData", import_data);
+    impl_args.vec_import();
+
+    override_functions(
+        "PSP22MintAllowanceInternal",
+        &mut internal,
+        impl_args.map,
+    );
+    override_functions(
+        "PSP22MintAllowance",
+        &mut mint_allowance,
+        impl_args.map,
+    );
+
+    impl_args.add_hook("PSP22Mintable", "mint", mint_hook);
+
+    impl_args.items.push(syn::Item::Impl(internal_default_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args
+        .items
+        .push(syn::Item::Impl(mint_allowance_default_impl));
+    impl_args.items.push(syn::Item::Impl(mint_allowance));
 }
@@ -5,8 +5,7 @@
 
 extern crate proc_macro;
 
-use quote::ToTokens;
-use std::collections::HashSet;
+use quote::{quote, ToTokens};
 use syn::{
     ext::IdentExt,
     parenthesized,
@@ -17,36 +16,55 @@ use syn::{
 };
 
 pub(crate) struct MetaList {
-    pub _path: syn::Path,
+    pub path: syn::Path,
     pub _paren_token: syn::token::Paren,
-    pub _nested: syn::punctuated::Punctuated<syn::Expr, syn::Token![,]>,
+    pub nested: syn::punctuated::Punctuated<syn::Expr, syn::Token![,]>,
 }
-#[derive(Debug)]
-pub struct InputsDiff {
-    pub added: HashSet<String>,
-    pub removed: HashSet<String>,
-}
-
-pub fn inputs_diff(
-    inputs_a: Punctuated<FnArg, Comma>,
-    inputs_b: Punctuated<FnArg, Comma>,
-) -> InputsDiff {
-    let set_a: HashSet<_> = inputs_a
-        .into_iter()
-        .map(|arg| arg.into_token_stream().to_string())
-        .collect();
-    let set_b: HashSet<_> = inputs_b
-        .into_iter()
-        .map(|arg| arg.into_token_stream().to_string())
-        .collect();
-
-    let added = &set_b - &set_a;
-    let removed = &set_a - &set_b;
-
-    InputsDiff {
-        added: added.into_iter().collect(),
-        removed: removed.into_iter().collect(),
+/// Matches the args of an overrider with the args of the overriden fn by position and type.
+///
+/// Returns `let` statements binding the args renamed by the overrider to the original args,
+/// so both the overrider body and the hooks (using the original names) can be injected into the fn,
+/// or `None` if the args don't match.
+pub fn bind_renamed_args(
+    original: &Punctuated<FnArg, Comma>,
+    overrider: &Punctuated<FnArg, Comma>,
+) -> Option<Vec<syn::Stmt>> {
+    if original.len() != overrider.len() {
+        return None;
+    }
+    let mut bindings = vec![];
+    for (original_arg, overrider_arg) in original.iter().zip(overrider.iter()) {
+        match (original_arg, overrider_arg) {
+            (FnArg::Receiver(original_arg), FnArg::Receiver(overrider_arg)) => {
+                if original_arg.to_token_stream().to_string()
+                    != overrider_arg.to_token_stream().to_string()
+                {
+                    return None;
+                }
+            }
+            (FnArg::Typed(original_arg), FnArg::Typed(overrider_arg)) => {
+                if original_arg.ty.to_token_stream().to_string()
+                    != overrider_arg.ty.to_token_stream().to_string()
+                {
+                    return None;
+                }
+                let original_pat = &original_arg.pat;
+                let overrider_pat = &overrider_arg.pat;
+                if original_pat.to_token_stream().to_string()
+                    != overrider_pat.to_token_stream().to_string()
+                {
+                    bindings.push(
+                        syn::parse2::<syn::Stmt>(quote!(
+                            let #overrider_pat = #original_pat;
+                        ))
+                        .expect("Should parse"),
+                    );
+                }
+            }
+            _ => return None,
+        }
     }
+    Some(bindings)
 }
 
 pub fn format_arg_string(arg: &str) -> String {
@@ -117,9 +135,9 @@ fn parse_meta_list_after_path(
 ) -> syn::Result<MetaList> {
     let content;
     Ok(MetaList {
-        _path: path,
+        path,
         _paren_token: parenthesized!(content in input),
-        _nested: content.parse_terminated(syn::Expr::parse)?,
+        nested: content.parse_terminated(syn::Expr::parse)?,
     })
 }
 
@@ -183,14 +201,6 @@ impl std::ops::DerefMut for AttributeArgs {
     }
 }
 
-pub(crate) struct Attributes(Vec<syn::Attribute>);
-
-impl Parse for Attributes {
-    fn parse(input: ParseStream) -> syn::Result<Self> {
-        Ok(Self(syn::Attribute::parse_outer(input)?))
-    }
-}
-
 #[inline]
 pub(crate) fn is_attr(attrs: &[syn::Attribute], ident: &str) -> bool {
     attrs.iter().any(|attr| {
@@ -209,3 +219,54 @@ pub(crate) const INK_PREFIX: &str = "ink=";
 pub(crate) fn skip() -> bool {
     !std::env::args().any(|arg| arg.contains(INK_PREFIX))
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn inputs(tokens: proc_macro2::TokenStream) -> Punctuated<FnArg, Comma> {
+        syn::parse2::<syn::Signature>(quote!(fn f(#tokens)))
+            .expect("Should parse")
+            .inputs
+    }
+
+    #[test]
+    fn same_args_need_no_bindings() {
+        let original =
+            inputs(quote!(&mut self, to: AccountId, amount: Balance));
+        let bindings = bind_renamed_args(&original, &original);
+        assert_eq!(bindings.map(|b| b.len()), Some(0));
+    }
+
+    #[test]
+    fn renamed_args_are_bound_to_the_original_ones() {
+        let original =
+            inputs(quote!(&mut self, to: AccountId, amount: Balance));
+        let overrider =
+            inputs(quote!(&mut self, account: AccountId, mut value: Balance));
+        let bindings = bind_renamed_args(&original, &overrider)
+            .expect("Should match")
+            .iter()
+            .map(|binding| binding.to_token_stream().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bindings,
+            vec![
+                quote!(let account = to;).to_string(),
+                quote!(let mut value = amount;).to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn args_of_other_types_do_not_match() {
+        let original =
+            inputs(quote!(&mut self, to: AccountId, amount: Balance));
+        let swapped = inputs(quote!(&mut self, amount: Balance, to: AccountId));
+        let missing = inputs(quote!(&mut self, to: AccountId));
+        let receiver = inputs(quote!(&self, to: AccountId, amount: Balance));
+        assert!(bind_renamed_args(&original, &swapped).is_none());
+        assert!(bind_renamed_args(&original, &missing).is_none());
+        assert!(bind_renamed_args(&original, &receiver).is_none());
+    }
+}
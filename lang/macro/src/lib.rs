@@ -31,40 +31,75 @@ pub fn storage_field_getter_derive(
     storage_field_getter_derive::storage_field_getter_derive(item.into()).into()
 }
 
-////This macro implements the default traits defined in pendzl, while also allowing users
-////to override them with `#[overrider]` attribute. `#[overrider]` is used when
-////you want to change the behavior of the method by your implementation.
-////# Example
-////```skip
-////#[pendzl::implementation(PSP22)]
-////#[ink::contract]
-////pub mod MyInkToken {
-////    use pendzl::traits::StorageFieldGetter;
-////
-////    #[ink(storage)]
-////    #[derive(StorageFieldGetter)]
-////    pub struct MyInkToken {
-////        #[storage_field]
-////        psp22: psp22::Data
-////    }
-////
-////    // this will override a function from PSP22Internal
-////    #[overrider(PSP22Internal)]
-////    fn _update(
-////        &mut self,
-////        from: Option<&AccountId>,
-////        to: Option<&AccountId>,
-////        amount: &Balance,
-////    ) -> Result<(), PSP22Error> {
-////        // here we can change the update fn behavior
-////    }
-////
-////    // this will override a function from PSP22
-////    #[overrider(PSP22)]
-////    fn balance_of(&self, owner: AccountId) -> Balance {
-////         // here we can change the behavior of balance_of
-////    }
-//// ```
+/// This macro implements the default traits defined in pendzl, while also allowing users
+/// to override them with `#[overrider]` attribute. `#[overrider]` is used when
+/// you want to change the behavior of the method by your implementation.
+/// The args of the overrider must match the args of the overriden method by position and type,
+/// they can be named differently.
+/// # Example
+/// ```skip
+/// #[pendzl::implementation(PSP22)]
+/// #[ink::contract]
+/// pub mod MyInkToken {
+///    use pendzl::traits::StorageFieldGetter;
+///
+///    #[ink(storage)]
+///    #[derive(StorageFieldGetter)]
+///    pub struct MyInkToken {
+///        #[storage_field]
+///        psp22: psp22::Data
+///    }
+///
+///    // this will override a function from PSP22Internal
+///    #[overrider(PSP22Internal)]
+///    fn _update(
+///        &mut self,
+///        from: Option<&AccountId>,
+///        to: Option<&AccountId>,
+///        amount: &Balance,
+///    ) -> Result<(), PSP22Error> {
+///        // here we can change the update fn behavior
+///    }
+///
+///    // this will override a function from PSP22
+///    #[overrider(PSP22)]
+///    fn balance_of(&self, owner: AccountId) -> Balance {
+///         // here we can change the behavior of balance_of
+///    }
+/// ```
+///
+/// Privileged messages are restricted with a `guard` argument.
+/// `guard = Ownable` injects `OwnableInternal::_only_owner` check and `guard = AccessControl(ROLE)`
+/// injects `AccessControlInternal::_ensure_has_role` check of the caller at the beginning of the
+/// guarded messages (also if they are overriden). The guard trait must be implemented too.
+/// `mint` of `PSP22Mintable`, `burn` of `PSP22Burnable`, the setters of `PSP22Fee`, `PSP22Restricted`, `PSP22Lockable`,
+/// `PSP22MetadataMutable`, `PSP22MintAllowance` and `PSP22VaultCaps` as well as the fulfillment messages
+/// of `PSP22VaultAsync` and the strategy management messages of `PSP22VaultStrategies` require a guard.
+/// `guard = None` explicitly leaves them callable by anyone.
+///
+/// `PSP22BurnableFrom` (`burn_from` deducting from the caller's allowance) and `PSP22BurnableSelf`
+/// (`burn_self` of the caller's own tokens) are unprivileged alternatives to `PSP22Burnable`.
+///
+/// `PSP22(infinite_allowance)` makes an allowance of `Balance::MAX` unlimited - it is not decreased
+/// (no storage write nor `Approval` event) when spent by the spender.
+///
+/// `PSP22(safe_transfer)` calls `PSP22Receiver::before_received` on the recipient contract of `transfer`
+/// and `transfer_from` reverting with `SafeTransferCheckFailed` if it rejects the tokens.
+/// # Example
+/// ```skip
+/// #[pendzl::implementation(
+///     PSP22,
+///     PSP22Mintable(guard = AccessControl(MINTER)),
+///     PSP22Burnable(guard = Ownable),
+///     AccessControl,
+///     Ownable
+/// )]
+/// #[ink::contract]
+/// pub mod MyInkToken {
+///    const MINTER: RoleType = ink::selector_id!("MINTER");
+///    ...
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn implementation(
     attrs: TokenStream,
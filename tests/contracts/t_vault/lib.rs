@@ -1,7 +1,12 @@
 // SPDX-License-Identifier: MIT
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
-#[pendzl::implementation(PSP22, PSP22Vault, PSP22Metadata)]
+#[pendzl::implementation(
+    PSP22,
+    PSP22Vault,
+    PSP22VaultPerformanceFee,
+    PSP22Metadata
+)]
 #[ink::contract]
 pub mod t_vault {
     use pendzl::contracts::psp22::PSP22InternalDefaultImpl;
@@ -14,6 +19,8 @@ pub mod t_vault {
         #[storage_field]
         vault: PSP22VaultData,
         #[storage_field]
+        performance_fee: PSP22VaultPerformanceFeeData,
+        #[storage_field]
         metadata: PSP22MetadataData,
 
         decimals_offset: u8,
@@ -66,6 +73,48 @@ pub mod t_vault {
                 self, &owner, &spender, &amount,
             )
         }
+
+        #[ink(message)]
+        pub fn t_set_vault_fees(
+            &mut self,
+            deposit_fee_bps: u16,
+            withdraw_fee_bps: u16,
+            fee_recipient: Option<AccountId>,
+        ) -> Result<(), PSP22Error> {
+            self._set_vault_fees(
+                &deposit_fee_bps,
+                &withdraw_fee_bps,
+                &fee_recipient,
+            )
+        }
+
+        #[ink(message)]
+        pub fn t_set_performance_fee(
+            &mut self,
+            fee_bps: u16,
+            fee_recipient: Option<AccountId>,
+        ) -> Result<(), PSP22Error> {
+            self._set_performance_fee(&fee_bps, &fee_recipient)
+        }
+
+        #[ink(message)]
+        pub fn t_set_deposit_cap(
+            &mut self,
+            cap: Option<Balance>,
+        ) -> Result<(), PSP22Error> {
+            self._set_deposit_cap(&cap);
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn t_set_receiver_cap(
+            &mut self,
+            receiver: Option<AccountId>,
+            cap: Option<Balance>,
+        ) -> Result<(), PSP22Error> {
+            self._set_receiver_cap(&receiver, &cap);
+            Ok(())
+        }
     }
 
     #[overrider(PSP22VaultInternal)]
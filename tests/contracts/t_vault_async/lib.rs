@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[pendzl::implementation(
+    PSP22,
+    PSP22Vault,
+    PSP22VaultAsync(guard = Ownable),
+    Ownable
+)]
+#[ink::contract]
+pub mod t_vault_async {
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: PSP22Data,
+        #[storage_field]
+        vault: PSP22VaultData,
+        #[storage_field]
+        vault_async: PSP22VaultAsyncData,
+        #[storage_field]
+        ownable: OwnableData,
+
+        async_redeem_only: bool,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(asset: AccountId, async_redeem_only: bool) -> Self {
+            let mut instance = Self::default();
+            let psp22: PSP22Ref = asset.into();
+            instance.vault.asset.set(&psp22);
+            instance.vault.underlying_decimals.set(&12);
+            instance._update_owner(&Some(Self::env().caller()));
+            instance.async_redeem_only = async_redeem_only;
+            instance
+        }
+
+        #[ink(message)]
+        pub fn t_set_deposit_cap(&mut self, cap: Option<Balance>) {
+            self._set_deposit_cap(&cap)
+        }
+
+        #[ink(message)]
+        pub fn t_set_vault_fees(
+            &mut self,
+            deposit_fee_bps: u16,
+            withdraw_fee_bps: u16,
+            fee_recipient: Option<AccountId>,
+        ) -> Result<(), PSP22Error> {
+            self._set_vault_fees(
+                &deposit_fee_bps,
+                &withdraw_fee_bps,
+                &fee_recipient,
+            )
+        }
+    }
+
+    #[overrider(PSP22VaultAsyncInternal)]
+    fn _is_async_deposit(&self) -> bool {
+        !self.async_redeem_only
+    }
+}
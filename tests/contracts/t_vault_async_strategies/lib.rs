@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[pendzl::implementation(
+    PSP22,
+    PSP22Vault,
+    PSP22VaultAsync(guard = Ownable),
+    PSP22VaultStrategies(guard = Ownable),
+    Ownable
+)]
+#[ink::contract]
+pub mod t_vault_async_strategies {
+    #[ink(storage)]
+    #[derive(Default, StorageFieldGetter)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: PSP22Data,
+        #[storage_field]
+        vault: PSP22VaultData,
+        #[storage_field]
+        vault_async: PSP22VaultAsyncData,
+        #[storage_field]
+        strategies: PSP22VaultStrategiesData,
+        #[storage_field]
+        ownable: OwnableData,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(asset: AccountId) -> Self {
+            let mut instance = Self::default();
+            let psp22: PSP22Ref = asset.into();
+            instance.vault.asset.set(&psp22);
+            instance.vault.underlying_decimals.set(&12);
+            instance._update_owner(&Some(Self::env().caller()));
+            instance
+        }
+    }
+
+    #[overrider(PSP22VaultAsyncInternal)]
+    fn _is_async_deposit(&self) -> bool {
+        false
+    }
+}
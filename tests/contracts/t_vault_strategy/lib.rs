@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[ink::contract]
+pub mod t_vault_strategy {
+    use ink::prelude::vec::Vec;
+    use pendzl::contracts::psp22::vault_strategies::VaultStrategy;
+    use pendzl::contracts::psp22::{PSP22Error, PSP22Ref, PSP22};
+
+    #[ink(storage)]
+    pub struct Contract {
+        asset: PSP22Ref,
+        vault: AccountId,
+        frozen: bool,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(asset: AccountId, vault: AccountId) -> Self {
+            Self {
+                asset: asset.into(),
+                vault,
+                frozen: false,
+            }
+        }
+
+        /// makes `withdraw` fail, ex. as the invested assets got locked
+        #[ink(message)]
+        pub fn t_set_frozen(&mut self, frozen: bool) {
+            self.frozen = frozen;
+        }
+    }
+
+    impl VaultStrategy for Contract {
+        #[ink(message)]
+        fn deposit(&mut self, _assets: Balance) -> Result<(), PSP22Error> {
+            if self.env().caller() != self.vault {
+                return Err(PSP22Error::Custom("S:CallerIsNotVault".into()));
+            }
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn withdraw(&mut self, assets: Balance) -> Result<(), PSP22Error> {
+            if self.env().caller() != self.vault {
+                return Err(PSP22Error::Custom("S:CallerIsNotVault".into()));
+            }
+            if self.frozen {
+                return Err(PSP22Error::Custom("S:Frozen".into()));
+            }
+            self.asset.transfer(self.vault, assets, Vec::<u8>::new())
+        }
+
+        #[ink(message)]
+        fn total_assets(&self) -> Balance {
+            self.asset.balance_of(self.env().account_id())
+        }
+    }
+}
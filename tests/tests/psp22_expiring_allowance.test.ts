@@ -0,0 +1,72 @@
+import { ApiPromise } from '@polkadot/api';
+import BN from 'bn.js';
+import TPsp22ExpiringAllowanceDeployer from 'typechain/deployers/t_psp22_expiring_allowance';
+import TPsp22ExpiringAllowanceContract from 'typechain/contracts/t_psp22_expiring_allowance';
+import '@c-forge/polkahat-chai-matchers';
+import { expect } from 'chai';
+import { getSigners, localApi, time } from '@c-forge/polkahat-network-helpers';
+
+const EXPIRES_AT = 1000;
+
+const [owner, spender, recipient] = getSigners();
+
+let token: TPsp22ExpiringAllowanceContract;
+
+describe('PSP22ExpiringAllowance', function () {
+  let api: ApiPromise;
+  beforeEach(async () => {
+    api = await localApi.get();
+    await time.setTo(10);
+    token = (await new TPsp22ExpiringAllowanceDeployer(api, owner).new(10000)).contract;
+
+    await token.tx.approveWithExpiry(spender.address, 100, EXPIRES_AT);
+  });
+
+  describe('before the expiry', function () {
+    it('allowance can be spent', async function () {
+      const tx = token.withSigner(spender).tx.transferFrom(owner.address, recipient.address, 60, []);
+      await expect(tx).to.changePSP22Balances(token, [owner.address, recipient.address], [new BN(-60), new BN(60)]);
+      await expect(token.query.allowance(owner.address, spender.address)).to.haveOkResult(40);
+    });
+
+    it('allowance can be increased keeping the expiry', async function () {
+      await token.tx.increaseAllowance(spender.address, 50);
+      await expect(token.query.allowance(owner.address, spender.address)).to.haveOkResult(150);
+      await expect(token.query.allowanceExpiry(owner.address, spender.address)).to.haveOkResult(EXPIRES_AT);
+    });
+  });
+
+  describe('after the expiry', function () {
+    beforeEach(async () => {
+      await time.setTo(EXPIRES_AT + 1);
+    });
+
+    it('allowance reads as zero', async function () {
+      await expect(token.query.allowance(owner.address, spender.address)).to.haveOkResult(0);
+    });
+
+    it('allowance can not be spent', async function () {
+      await expect(token.withSigner(spender).query.transferFrom(owner.address, recipient.address, 1, [])).to.be.revertedWithError(
+        'AllowanceExpired',
+      );
+    });
+
+    it('allowance can not be increased', async function () {
+      await expect(token.query.increaseAllowance(spender.address, 50)).to.be.revertedWithError('AllowanceExpired');
+    });
+
+    it('owner can decrease the allowance', async function () {
+      const tx = token.tx.decreaseAllowance(spender.address, 100);
+      await expect(tx).to.emitEvent(token, 'Approval', { owner: owner.address, spender: spender.address, value: 0 });
+    });
+
+    it('approve sets a new allowance without expiry', async function () {
+      await token.tx.approve(spender.address, 50);
+      await expect(token.query.allowance(owner.address, spender.address)).to.haveOkResult(50);
+      expect((await token.query.allowanceExpiry(owner.address, spender.address)).value.ok).to.be.null;
+
+      const tx = token.withSigner(spender).tx.transferFrom(owner.address, recipient.address, 50, []);
+      await expect(tx).to.changePSP22Balances(token, [owner.address, recipient.address], [new BN(-50), new BN(50)]);
+    });
+  });
+});
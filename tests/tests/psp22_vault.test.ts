@@ -64,30 +64,235 @@ describe('ERC4626', function () {
     it('reverts on deposit() above max deposit', async function () {
       const maxDeposit = (await vault.query.maxDeposit(holder.address)).value.ok;
       await expect(vault.withSigner(holder).query.deposit(maxDeposit!.addn(1), recipient.address)).to.be.revertedWithError({
-        custom: 'V:MaxDeposit',
+        maxDepositExceeded: null,
       });
     });
 
     it('reverts on mint() above max mint', async function () {
       const maxMint = (await vault.query.maxMint(holder.address)).value.ok;
-      await expect(vault.withSigner(holder).query.mint(maxMint!.addn(1), recipient.address)).to.be.revertedWithError({ custom: 'V:MaxMint' });
+      await expect(vault.withSigner(holder).query.mint(maxMint!.addn(1), recipient.address)).to.be.revertedWithError({ maxMintExceeded: null });
     });
 
     it('reverts on withdraw() above max withdraw', async function () {
       const maxWithdraw = (await vault.query.maxWithdraw(holder.address)).value.ok;
       await expect(vault.withSigner(holder).query.withdraw(maxWithdraw!.addn(1), recipient.address, holder.address)).to.be.revertedWithError({
-        custom: 'V:MaxWithdraw',
+        maxWithdrawExceeded: null,
       });
     });
 
     it('reverts on redeem() above max redeem', async function () {
       const maxRedeem = (await vault.query.maxRedeem(holder.address)).value.ok;
       await expect(vault.withSigner(holder).query.redeem(maxRedeem!.addn(1), recipient.address, holder.address)).to.be.revertedWithError({
-        custom: 'V:MaxRedeem',
+        maxRedeemExceeded: null,
       });
     });
   });
 
+  describe('caps', async function () {
+    const depositCap = new BN(10000);
+    const receiverCap = new BN(4000);
+
+    beforeEach(async function () {
+      token = (await new TPsp22Deployer(api, deployer).new(0, '', '', decimals)).contract;
+      vault = (await new TVault22Deployer(api, deployer).new(token.address, 0, '', '', null)).contract;
+
+      await token.tx.tMint(holder.address, MAX_U128.divn(2));
+      await token.withSigner(holder).tx.approve(vault.address, MAX_U128);
+    });
+
+    it('is uncapped by default', async function () {
+      await expect(vault.query.maxDeposit(recipient.address)).to.haveOkResult(MAX_U128);
+      await expect(vault.query.maxMint(recipient.address)).to.haveOkResult(MAX_U128);
+    });
+
+    it('limits max deposit and max mint to the remaining deposit cap', async function () {
+      const tx = vault.tx.tSetDepositCap(depositCap);
+      await expect(tx).to.emitEvent(vault, 'DepositCapUpdated', { cap: depositCap });
+      await vault.withSigner(holder).tx.deposit(new BN(3000), holder.address);
+
+      await expect(vault.query.maxDeposit(recipient.address)).to.haveOkResult(depositCap.subn(3000));
+      await expect(vault.query.maxMint(recipient.address)).to.haveOkResult(depositCap.subn(3000));
+      await expect(vault.withSigner(holder).query.deposit(depositCap.subn(2999), recipient.address)).to.be.revertedWithError({
+        maxDepositExceeded: null,
+      });
+      await expect(vault.withSigner(holder).query.mint(depositCap.subn(2999), recipient.address)).to.be.revertedWithError({
+        maxMintExceeded: null,
+      });
+      await expect(vault.withSigner(holder).tx.deposit(depositCap.subn(3000), recipient.address)).to.be.fulfilled;
+      await expect(vault.query.maxDeposit(recipient.address)).to.haveOkResult(0);
+    });
+
+    it('limits max deposit to the remaining receiver cap', async function () {
+      const tx = vault.tx.tSetReceiverCap(null, receiverCap);
+      await expect(tx).to.emitEvent(vault, 'ReceiverCapUpdated', { receiver: null, cap: receiverCap });
+      await vault.tx.tSetReceiverCap(recipient.address, receiverCap.muln(2));
+      await vault.withSigner(holder).tx.deposit(new BN(1000), recipient.address);
+
+      await expect(vault.query.maxDeposit(holder.address)).to.haveOkResult(receiverCap);
+      await expect(vault.query.maxDeposit(recipient.address)).to.haveOkResult(receiverCap.muln(2).subn(1000));
+      await expect(vault.withSigner(holder).query.deposit(receiverCap.addn(1), holder.address)).to.be.revertedWithError({
+        maxDepositExceeded: null,
+      });
+    });
+
+    it('takes the lower of both caps', async function () {
+      await vault.tx.tSetDepositCap(depositCap);
+      await vault.tx.tSetReceiverCap(null, receiverCap);
+      await vault.withSigner(holder).tx.deposit(receiverCap, recipient.address);
+      await vault.withSigner(holder).tx.deposit(receiverCap, holder.address);
+
+      await expect(vault.query.maxDeposit(other.address)).to.haveOkResult(depositCap.sub(receiverCap.muln(2)));
+      await expect(vault.query.maxDeposit(holder.address)).to.haveOkResult(0);
+    });
+  });
+
+  describe('fees', async function () {
+    const feeBasisPoints = 500; // 5%
+    const valueWithoutFees = new BN(10000);
+    const fees = valueWithoutFees.muln(feeBasisPoints).divn(10000);
+    const valueWithFees = valueWithoutFees.add(fees);
+
+    beforeEach(async function () {
+      token = (await new TPsp22Deployer(api, deployer).new(0, '', '', decimals)).contract;
+      vault = (await new TVault22Deployer(api, deployer).new(token.address, 0, '', '', null)).contract;
+      await vault.tx.tSetVaultFees(feeBasisPoints, feeBasisPoints, other.address);
+
+      await token.tx.tMint(holder.address, MAX_U128.divn(2));
+      await token.withSigner(holder).tx.approve(vault.address, MAX_U128);
+    });
+
+    it('rejects fees above 100%', async function () {
+      await expect(vault.query.tSetVaultFees(10001, 0, other.address)).to.be.revertedWithError('InvalidFee');
+    });
+
+    it('deposit', async function () {
+      await expect(vault.query.previewDeposit(valueWithFees)).to.haveOkResult(valueWithoutFees);
+
+      const tx = vault.withSigner(holder).tx.deposit(valueWithFees, recipient.address);
+
+      await expect(tx).to.changePSP22Balances(token, [holder.address, vault.address, other.address], [valueWithFees.neg(), valueWithoutFees, fees]);
+      await expect(tx).to.changePSP22Balances(vault, [recipient.address], [valueWithoutFees]);
+      await expect(tx).to.emitEvent(vault, 'Deposit', {
+        sender: holder.address,
+        owner: recipient.address,
+        assets: valueWithFees,
+        shares: valueWithoutFees,
+      });
+    });
+
+    it('mint', async function () {
+      await expect(vault.query.previewMint(valueWithoutFees)).to.haveOkResult(valueWithFees);
+
+      const tx = vault.withSigner(holder).tx.mint(valueWithoutFees, recipient.address);
+
+      await expect(tx).to.changePSP22Balances(token, [holder.address, vault.address, other.address], [valueWithFees.neg(), valueWithoutFees, fees]);
+      await expect(tx).to.changePSP22Balances(vault, [recipient.address], [valueWithoutFees]);
+    });
+
+    describe('with deposited assets', function () {
+      beforeEach(async function () {
+        await vault.tx.tMint(holder.address, valueWithFees);
+        await token.tx.tMint(vault.address, valueWithFees);
+      });
+
+      it('withdraw', async function () {
+        await expect(vault.query.previewWithdraw(valueWithoutFees)).to.haveOkResult(valueWithFees);
+
+        const tx = vault.withSigner(holder).tx.withdraw(valueWithoutFees, recipient.address, holder.address);
+
+        await expect(tx).to.changePSP22Balances(
+          token,
+          [vault.address, recipient.address, other.address],
+          [valueWithFees.neg(), valueWithoutFees, fees],
+        );
+        await expect(tx).to.changePSP22Balances(vault, [holder.address], [valueWithFees.neg()]);
+        await expect(tx).to.emitEvent(vault, 'Withdraw', {
+          sender: holder.address,
+          receiver: recipient.address,
+          owner: holder.address,
+          assets: valueWithoutFees,
+          shares: valueWithFees,
+        });
+      });
+
+      it('redeem', async function () {
+        await expect(vault.query.maxWithdraw(holder.address)).to.haveOkResult(valueWithoutFees);
+        await expect(vault.query.previewRedeem(valueWithFees)).to.haveOkResult(valueWithoutFees);
+
+        const tx = vault.withSigner(holder).tx.redeem(valueWithFees, recipient.address, holder.address);
+
+        await expect(tx).to.changePSP22Balances(
+          token,
+          [vault.address, recipient.address, other.address],
+          [valueWithFees.neg(), valueWithoutFees, fees],
+        );
+        await expect(tx).to.changePSP22Balances(vault, [holder.address], [valueWithFees.neg()]);
+      });
+    });
+  });
+
+  describe('performance fee', async function () {
+    const SHARE_PRICE_PRECISION = new BN(10).pow(new BN(18));
+    const feeBasisPoints = 1000; // 10%
+
+    beforeEach(async function () {
+      token = (await new TPsp22Deployer(api, deployer).new(0, '', '', decimals)).contract;
+      vault = (await new TVault22Deployer(api, deployer).new(token.address, 0, '', '', null)).contract;
+      await vault.tx.tSetPerformanceFee(feeBasisPoints, other.address);
+
+      await token.tx.tMint(holder.address, MAX_U128.divn(2));
+      await token.withSigner(holder).tx.approve(vault.address, MAX_U128);
+      await vault.withSigner(holder).tx.deposit(10000, holder.address);
+    });
+
+    it('rejects fees above 100%', async function () {
+      await expect(vault.query.tSetPerformanceFee(10001, other.address)).to.be.revertedWithError('InvalidFee');
+    });
+
+    it('does not take fee without profit', async function () {
+      await expect(vault.query.highWaterMark()).to.haveOkResult(SHARE_PRICE_PRECISION);
+      await expect(vault.query.sharePrice()).to.haveOkResult(SHARE_PRICE_PRECISION);
+      await expect(vault.query.harvest()).to.haveOkResult(0);
+
+      const tx = vault.tx.harvest();
+      await expect(tx).to.changePSP22Balances(vault, [other.address], [new BN(0)]);
+    });
+
+    it('takes fee on profit above high-water mark', async function () {
+      // profit of 10000 assets
+      await token.tx.tMint(vault.address, 10000);
+
+      // price = 1e18 * 20001 / 10001, profit = 9999, fee = 999 assets
+      // shares = 999 * (10000 + 1) / (20001 - 999) = 525
+      const tx = vault.tx.harvest();
+      await expect(tx).to.changePSP22Balances(vault, [other.address], [new BN(525)]);
+      await expect(tx).to.emitEvent(vault, 'PerformanceFeeTaken', {
+        recipient: other.address,
+        profit: 9999,
+        feeAssets: 999,
+        shares: 525,
+      });
+
+      const highWaterMark = (await vault.query.highWaterMark()).value.ok!;
+      await expect(vault.query.sharePrice()).to.haveOkResult(highWaterMark);
+      await expect(vault.query.harvest()).to.haveOkResult(0);
+    });
+
+    it('does not take fee on recovering losses', async function () {
+      await token.tx.tMint(vault.address, 10000);
+      await vault.tx.harvest();
+      const highWaterMark = (await vault.query.highWaterMark()).value.ok!;
+
+      // loss of 5000 assets followed by a gain of 5000 assets
+      await token.tx.tBurn(vault.address, 5000);
+      await expect(vault.query.harvest()).to.haveOkResult(0);
+      await token.tx.tMint(vault.address, 5000);
+
+      await expect(vault.query.harvest()).to.haveOkResult(0);
+      await expect(vault.query.highWaterMark()).to.haveOkResult(highWaterMark);
+    });
+  });
+
   for (const offset of [0, 6, 18]) {
     const parseToken = (t: number) => new BN(t).mul(new BN(10).pow(new BN(decimals)));
     const parseShare = (share: number) => new BN(share).mul(new BN(10).pow(new BN(decimals + offset)));
@@ -0,0 +1,195 @@
+import { ApiPromise } from '@polkadot/api';
+import BN from 'bn.js';
+import TPsp22Deployer from 'typechain/deployers/t_psp22';
+import TPsp22Contract from 'typechain/contracts/t_psp22';
+import TVaultAsyncDeployer from 'typechain/deployers/t_vault_async';
+import TVaultAsyncContract from 'typechain/contracts/t_vault_async';
+import '@c-forge/polkahat-chai-matchers';
+import { expect } from 'chai';
+import { getSigners, localApi } from '@c-forge/polkahat-network-helpers';
+
+const decimals: number = 12;
+
+const MAX_U128 = new BN('340282366920938463463374607431768211455');
+
+const [deployer, holder, recipient, other] = getSigners();
+
+let token: TPsp22Contract;
+let vault: TVaultAsyncContract;
+
+describe('ERC7540', function () {
+  let api: ApiPromise;
+  beforeEach(async () => {
+    api = await localApi.get();
+    token = (await new TPsp22Deployer(api, deployer).new(0, '', '', decimals)).contract;
+    vault = (await new TVaultAsyncDeployer(api, deployer).new(token.address, false)).contract;
+
+    await token.tx.tMint(holder.address, 10000);
+    await token.withSigner(holder).tx.approve(vault.address, MAX_U128);
+  });
+
+  describe('deposit request', function () {
+    it('locks assets of the owner', async function () {
+      const tx = vault.withSigner(holder).tx.requestDeposit(1000, holder.address, holder.address);
+
+      await expect(tx).to.changePSP22Balances(token, [holder.address, vault.address], [new BN(-1000), new BN(1000)]);
+      await expect(tx).to.emitEvent(vault, 'DepositRequest', {
+        controller: holder.address,
+        owner: holder.address,
+        sender: holder.address,
+        assets: 1000,
+      });
+      await expect(vault.query.pendingDepositRequest(holder.address)).to.haveOkResult(1000);
+      await expect(vault.query.claimableDepositRequest(holder.address)).to.haveOkResult(0);
+      // pending assets are not managed by the vault yet
+      await expect(vault.query.totalAssets()).to.haveOkResult(0);
+    });
+
+    it('can be made by the owner only', async function () {
+      await expect(vault.withSigner(other).query.requestDeposit(1000, other.address, holder.address)).to.be.revertedWithError(
+        'InvalidRequestCaller',
+      );
+    });
+
+    it('can be fulfilled by the owner only', async function () {
+      await vault.withSigner(holder).tx.requestDeposit(1000, holder.address, holder.address);
+      await expect(vault.withSigner(other).query.fulfillDeposit(holder.address, 1000)).to.be.revertedWithError('CallerIsNotOwner');
+    });
+
+    it('is capped by the deposit cap', async function () {
+      await vault.tx.tSetDepositCap(1500);
+      await vault.withSigner(holder).tx.requestDeposit(1000, holder.address, holder.address);
+      await expect(vault.withSigner(holder).query.requestDeposit(600, holder.address, holder.address)).to.be.revertedWithError(
+        'MaxDepositExceeded',
+      );
+
+      await vault.tx.fulfillDeposit(holder.address, 1000);
+      await expect(vault.withSigner(holder).query.requestDeposit(600, holder.address, holder.address)).to.be.revertedWithError(
+        'MaxDepositExceeded',
+      );
+      await expect(vault.withSigner(holder).tx.requestDeposit(500, holder.address, holder.address)).to.be.fulfilled;
+    });
+
+    it('reverts on fulfilling more than pending', async function () {
+      await vault.withSigner(holder).tx.requestDeposit(1000, holder.address, holder.address);
+      await expect(vault.query.fulfillDeposit(holder.address, 1001)).to.be.revertedWithError('InsufficientPendingRequest');
+    });
+
+    it('is claimable after fulfillment', async function () {
+      await vault.withSigner(holder).tx.requestDeposit(1000, holder.address, holder.address);
+
+      const fulfillTx = vault.tx.fulfillDeposit(holder.address, 1000);
+      await expect(fulfillTx).to.changePSP22Balances(vault, [vault.address], [new BN(1000)]);
+      await expect(fulfillTx).to.emitEvent(vault, 'DepositClaimable', { controller: holder.address, assets: 1000, shares: 1000 });
+
+      await expect(vault.query.pendingDepositRequest(holder.address)).to.haveOkResult(0);
+      await expect(vault.query.claimableDepositRequest(holder.address)).to.haveOkResult(1000);
+      await expect(vault.query.maxDeposit(holder.address)).to.haveOkResult(1000);
+      await expect(vault.query.maxMint(holder.address)).to.haveOkResult(1000);
+      await expect(vault.query.totalAssets()).to.haveOkResult(1000);
+
+      await expect(vault.withSigner(holder).query.deposit(1001, recipient.address)).to.be.revertedWithError('InsufficientClaimableRequest');
+
+      const claimTx = vault.withSigner(holder).tx.deposit(1000, recipient.address);
+      await expect(claimTx).to.changePSP22Balances(vault, [vault.address, recipient.address], [new BN(-1000), new BN(1000)]);
+      await expect(claimTx).to.emitEvent(vault, 'Deposit', { sender: holder.address, owner: recipient.address, assets: 1000, shares: 1000 });
+      await expect(vault.query.claimableDepositRequest(holder.address)).to.haveOkResult(0);
+    });
+  });
+
+  describe('redeem request', function () {
+    beforeEach(async function () {
+      await vault.withSigner(holder).tx.requestDeposit(1000, holder.address, holder.address);
+      await vault.tx.fulfillDeposit(holder.address, 1000);
+      await vault.withSigner(holder).tx.mint(1000, holder.address);
+    });
+
+    it('locks shares of the owner', async function () {
+      const tx = vault.withSigner(holder).tx.requestRedeem(400, holder.address, holder.address);
+
+      await expect(tx).to.changePSP22Balances(vault, [holder.address, vault.address], [new BN(-400), new BN(400)]);
+      await expect(tx).to.emitEvent(vault, 'RedeemRequest', {
+        controller: holder.address,
+        owner: holder.address,
+        sender: holder.address,
+        shares: 400,
+      });
+      await expect(vault.query.pendingRedeemRequest(holder.address)).to.haveOkResult(400);
+    });
+
+    it('spends allowance of a spender', async function () {
+      await expect(vault.withSigner(other).query.requestRedeem(400, other.address, holder.address)).to.be.revertedWithError({
+        insufficientAllowance: null,
+      });
+      await vault.withSigner(holder).tx.approve(other.address, 400);
+      await vault.withSigner(other).tx.requestRedeem(400, other.address, holder.address);
+      await expect(vault.query.allowance(holder.address, other.address)).to.haveOkResult(0);
+      await expect(vault.query.pendingRedeemRequest(other.address)).to.haveOkResult(400);
+    });
+
+    it('is claimable after fulfillment', async function () {
+      await vault.withSigner(holder).tx.requestRedeem(400, holder.address, holder.address);
+      // profit of 1000 assets
+      await token.tx.tMint(vault.address, 1000);
+
+      // assets = 400 * (2000 + 1) / (1000 + 1)
+      const fulfillTx = vault.tx.fulfillRedeem(holder.address, 400);
+      await expect(fulfillTx).to.changePSP22Balances(vault, [vault.address], [new BN(-400)]);
+      await expect(fulfillTx).to.emitEvent(vault, 'RedeemClaimable', { controller: holder.address, assets: 799, shares: 400 });
+
+      await expect(vault.query.claimableRedeemRequest(holder.address)).to.haveOkResult(400);
+      await expect(vault.query.maxWithdraw(holder.address)).to.haveOkResult(799);
+      await expect(vault.query.maxRedeem(holder.address)).to.haveOkResult(400);
+      await expect(vault.query.totalAssets()).to.haveOkResult(2000 - 799);
+
+      await expect(vault.withSigner(other).query.redeem(400, other.address, holder.address)).to.be.revertedWithError('InvalidRequestCaller');
+
+      const claimTx = vault.withSigner(holder).tx.redeem(400, recipient.address, holder.address);
+      await expect(claimTx).to.changePSP22Balances(token, [vault.address, recipient.address], [new BN(-799), new BN(799)]);
+      await expect(claimTx).to.emitEvent(vault, 'Withdraw', {
+        sender: holder.address,
+        receiver: recipient.address,
+        owner: holder.address,
+        assets: 799,
+        shares: 400,
+      });
+      await expect(vault.query.claimableRedeemRequest(holder.address)).to.haveOkResult(0);
+      await expect(vault.query.totalAssets()).to.haveOkResult(2000 - 799);
+    });
+
+    it('charges the withdraw fee at fulfillment', async function () {
+      await vault.tx.tSetVaultFees(0, 1000, other.address);
+      await vault.withSigner(holder).tx.requestRedeem(400, holder.address, holder.address);
+
+      // fee = 400 * 1000 / (1000 + 10000) rounded up
+      const fulfillTx = vault.tx.fulfillRedeem(holder.address, 400);
+      await expect(fulfillTx).to.changePSP22Balances(token, [vault.address, other.address], [new BN(-37), new BN(37)]);
+      await expect(fulfillTx).to.emitEvent(vault, 'RedeemClaimable', { controller: holder.address, assets: 363, shares: 400 });
+
+      await expect(vault.query.maxWithdraw(holder.address)).to.haveOkResult(363);
+      const claimTx = vault.withSigner(holder).tx.redeem(400, recipient.address, holder.address);
+      await expect(claimTx).to.changePSP22Balances(token, [vault.address, recipient.address, other.address], [new BN(-363), new BN(363), new BN(0)]);
+    });
+
+    it('withdraw claims only the claimable assets', async function () {
+      await expect(vault.withSigner(holder).query.withdraw(1, holder.address, holder.address)).to.be.revertedWithError(
+        'InsufficientClaimableRequest',
+      );
+    });
+  });
+
+  describe('synchronous deposits', function () {
+    beforeEach(async function () {
+      vault = (await new TVaultAsyncDeployer(api, deployer).new(token.address, true)).contract;
+      await token.withSigner(holder).tx.approve(vault.address, MAX_U128);
+    });
+
+    it('deposit is synchronous', async function () {
+      const tx = vault.withSigner(holder).tx.deposit(1000, holder.address);
+      await expect(tx).to.changePSP22Balances(vault, [holder.address], [new BN(1000)]);
+      await expect(vault.withSigner(holder).query.redeem(1000, holder.address, holder.address)).to.be.revertedWithError(
+        'InsufficientClaimableRequest',
+      );
+    });
+  });
+});
@@ -0,0 +1,63 @@
+import { ApiPromise } from '@polkadot/api';
+import BN from 'bn.js';
+import TPsp22Deployer from 'typechain/deployers/t_psp22';
+import TPsp22Contract from 'typechain/contracts/t_psp22';
+import TVaultAsyncStrategiesDeployer from 'typechain/deployers/t_vault_async_strategies';
+import TVaultAsyncStrategiesContract from 'typechain/contracts/t_vault_async_strategies';
+import TVaultStrategyDeployer from 'typechain/deployers/t_vault_strategy';
+import TVaultStrategyContract from 'typechain/contracts/t_vault_strategy';
+import '@c-forge/polkahat-chai-matchers';
+import { expect } from 'chai';
+import { getSigners, localApi } from '@c-forge/polkahat-network-helpers';
+
+const decimals: number = 12;
+
+const MAX_U128 = new BN('340282366920938463463374607431768211455');
+
+const [deployer, holder, recipient] = getSigners();
+
+let token: TPsp22Contract;
+let vault: TVaultAsyncStrategiesContract;
+let strategy: TVaultStrategyContract;
+
+describe('ERC7540 with strategies', function () {
+  let api: ApiPromise;
+  beforeEach(async () => {
+    api = await localApi.get();
+    token = (await new TPsp22Deployer(api, deployer).new(0, '', '', decimals)).contract;
+    vault = (await new TVaultAsyncStrategiesDeployer(api, deployer).new(token.address)).contract;
+    strategy = (await new TVaultStrategyDeployer(api, deployer).new(token.address, vault.address)).contract;
+
+    await token.tx.tMint(holder.address, 10000);
+    await token.withSigner(holder).tx.approve(vault.address, MAX_U128);
+    await vault.withSigner(holder).tx.deposit(10000, holder.address);
+
+    await vault.tx.addStrategy(strategy.address, 8000);
+    await vault.tx.rebalance();
+    await vault.withSigner(holder).tx.requestRedeem(5000, holder.address, holder.address);
+  });
+
+  it('fulfills a redeem with the idle assets only', async function () {
+    await expect(vault.query.idleAssets()).to.haveOkResult(2000);
+    await expect(vault.query.deployedAssets()).to.haveOkResult(8000);
+
+    await expect(vault.query.fulfillRedeem(holder.address, 5000)).to.be.revertedWithError('InsufficientBalance');
+
+    const tx = vault.tx.fulfillRedeem(holder.address, 2000);
+    await expect(tx).to.emitEvent(vault, 'RedeemClaimable', { controller: holder.address, assets: 2000, shares: 2000 });
+    await expect(vault.query.idleAssets()).to.haveOkResult(0);
+    await expect(vault.query.totalAssets()).to.haveOkResult(8000);
+  });
+
+  it('fulfills a redeem after the deployed assets are pulled back', async function () {
+    await vault.tx.setStrategyWeight(strategy.address, 0);
+    await vault.tx.rebalance();
+
+    await vault.tx.fulfillRedeem(holder.address, 5000);
+    await expect(vault.query.claimableRedeemRequest(holder.address)).to.haveOkResult(5000);
+
+    const claimTx = vault.withSigner(holder).tx.redeem(5000, recipient.address, holder.address);
+    await expect(claimTx).to.changePSP22Balances(token, [vault.address, recipient.address], [new BN(-5000), new BN(5000)]);
+    await expect(vault.query.totalAssets()).to.haveOkResult(5000);
+  });
+});
@@ -0,0 +1,160 @@
+import { ApiPromise } from '@polkadot/api';
+import BN from 'bn.js';
+import TPsp22Deployer from 'typechain/deployers/t_psp22';
+import TPsp22Contract from 'typechain/contracts/t_psp22';
+import TVaultStrategiesDeployer from 'typechain/deployers/t_vault_strategies';
+import TVaultStrategiesContract from 'typechain/contracts/t_vault_strategies';
+import TVaultStrategyDeployer from 'typechain/deployers/t_vault_strategy';
+import TVaultStrategyContract from 'typechain/contracts/t_vault_strategy';
+import '@c-forge/polkahat-chai-matchers';
+import { expect } from 'chai';
+import { getSigners, localApi } from '@c-forge/polkahat-network-helpers';
+
+const decimals: number = 12;
+
+const MAX_U128 = new BN('340282366920938463463374607431768211455');
+
+const [deployer, holder, recipient, other] = getSigners();
+
+let token: TPsp22Contract;
+let vault: TVaultStrategiesContract;
+let strategyA: TVaultStrategyContract;
+let strategyB: TVaultStrategyContract;
+
+describe('PSP22VaultStrategies', function () {
+  let api: ApiPromise;
+  beforeEach(async () => {
+    api = await localApi.get();
+    token = (await new TPsp22Deployer(api, deployer).new(0, '', '', decimals)).contract;
+    vault = (await new TVaultStrategiesDeployer(api, deployer).new(token.address)).contract;
+    strategyA = (await new TVaultStrategyDeployer(api, deployer).new(token.address, vault.address)).contract;
+    strategyB = (await new TVaultStrategyDeployer(api, deployer).new(token.address, vault.address)).contract;
+
+    await token.tx.tMint(holder.address, 10000);
+    await token.withSigner(holder).tx.approve(vault.address, MAX_U128);
+    await vault.withSigner(holder).tx.deposit(10000, holder.address);
+  });
+
+  describe('registry', function () {
+    it('adds strategies', async function () {
+      const tx = vault.tx.addStrategy(strategyA.address, 6000);
+      await expect(tx).to.emitEvent(vault, 'StrategyAdded', { strategy: strategyA.address, weight: 6000 });
+      await vault.tx.addStrategy(strategyB.address, 4000);
+
+      const strategies = (await vault.query.strategies()).value.ok!;
+      expect(strategies.map((s) => s.account.toString())).to.deep.equal([strategyA.address, strategyB.address]);
+      expect(strategies.map((s) => s.weight)).to.deep.equal([6000, 4000]);
+    });
+
+    it('can be managed by the owner only', async function () {
+      await expect(vault.withSigner(other).query.addStrategy(strategyA.address, 6000)).to.be.revertedWithError('CallerIsNotOwner');
+      await vault.tx.addStrategy(strategyA.address, 6000);
+      await expect(vault.withSigner(other).query.setStrategyWeight(strategyA.address, 0)).to.be.revertedWithError('CallerIsNotOwner');
+      await expect(vault.withSigner(other).query.removeStrategy(strategyA.address)).to.be.revertedWithError('CallerIsNotOwner');
+      await expect(vault.withSigner(other).query.forceRemoveStrategy(strategyA.address)).to.be.revertedWithError('CallerIsNotOwner');
+      await expect(vault.withSigner(other).query.rebalance()).to.be.revertedWithError('CallerIsNotOwner');
+    });
+
+    it('rejects a strategy added twice', async function () {
+      await vault.tx.addStrategy(strategyA.address, 6000);
+      await expect(vault.query.addStrategy(strategyA.address, 1000)).to.be.revertedWithError('StrategyAlreadyAdded');
+    });
+
+    it('rejects an unknown strategy', async function () {
+      await expect(vault.query.setStrategyWeight(strategyA.address, 1000)).to.be.revertedWithError('StrategyNotFound');
+      await expect(vault.query.removeStrategy(strategyA.address)).to.be.revertedWithError('StrategyNotFound');
+      await expect(vault.query.forceRemoveStrategy(strategyA.address)).to.be.revertedWithError('StrategyNotFound');
+    });
+
+    it('rejects weights above 100%', async function () {
+      await vault.tx.addStrategy(strategyA.address, 6000);
+      await expect(vault.query.addStrategy(strategyB.address, 4001)).to.be.revertedWithError('InvalidStrategyWeight');
+      await vault.tx.addStrategy(strategyB.address, 4000);
+      await expect(vault.query.setStrategyWeight(strategyA.address, 6001)).to.be.revertedWithError('InvalidStrategyWeight');
+    });
+  });
+
+  describe('with allocated assets', function () {
+    beforeEach(async function () {
+      await vault.tx.addStrategy(strategyA.address, 6000);
+      await vault.tx.addStrategy(strategyB.address, 2000);
+      await vault.tx.rebalance();
+    });
+
+    it('rebalance deploys the idle assets according to the weights', async function () {
+      await expect(token.query.balanceOf(strategyA.address)).to.haveOkResult(6000);
+      await expect(token.query.balanceOf(strategyB.address)).to.haveOkResult(2000);
+      await expect(vault.query.idleAssets()).to.haveOkResult(2000);
+      await expect(vault.query.deployedAssets()).to.haveOkResult(8000);
+      await expect(vault.query.totalAssets()).to.haveOkResult(10000);
+    });
+
+    it('total assets include the yield of the strategies', async function () {
+      await token.tx.tMint(strategyA.address, 1000);
+      await expect(vault.query.deployedAssets()).to.haveOkResult(9000);
+      await expect(vault.query.totalAssets()).to.haveOkResult(11000);
+    });
+
+    it('rebalance withdraws the excess assets first', async function () {
+      await vault.tx.setStrategyWeight(strategyA.address, 1000);
+      await vault.tx.setStrategyWeight(strategyB.address, 8000);
+
+      const tx = vault.tx.rebalance();
+      await expect(tx).to.emitEvent(vault, 'StrategyDeallocated', { strategy: strategyA.address, assets: 5000 });
+      await expect(tx).to.emitEvent(vault, 'StrategyAllocated', { strategy: strategyB.address, assets: 6000 });
+      await expect(token.query.balanceOf(strategyA.address)).to.haveOkResult(1000);
+      await expect(token.query.balanceOf(strategyB.address)).to.haveOkResult(8000);
+      await expect(vault.query.idleAssets()).to.haveOkResult(1000);
+    });
+
+    it('withdraw pulls the missing assets from the strategies', async function () {
+      const tx = vault.withSigner(holder).tx.withdraw(5000, recipient.address, holder.address);
+
+      await expect(tx).to.changePSP22Balances(
+        token,
+        [vault.address, strategyA.address, strategyB.address, recipient.address],
+        [new BN(-2000), new BN(-3000), new BN(0), new BN(5000)],
+      );
+      await expect(tx).to.emitEvent(vault, 'StrategyDeallocated', { strategy: strategyA.address, assets: 3000 });
+      await expect(vault.query.totalAssets()).to.haveOkResult(5000);
+    });
+
+    it('withdraw pulls the withdrawal fee too', async function () {
+      await vault.tx.tSetVaultFees(0, 1000, other.address);
+      const tx = vault.withSigner(holder).tx.withdraw(5000, recipient.address, holder.address);
+
+      await expect(tx).to.changePSP22Balances(
+        token,
+        [strategyA.address, recipient.address, other.address],
+        [new BN(-3500), new BN(5000), new BN(500)],
+      );
+    });
+
+    it('redeem of all shares empties the strategies', async function () {
+      await expect(vault.withSigner(holder).tx.redeem(10000, holder.address, holder.address)).to.be.fulfilled;
+      await expect(token.query.balanceOf(holder.address)).to.haveOkResult(10000);
+      await expect(vault.query.totalAssets()).to.haveOkResult(0);
+    });
+
+    it('remove strategy returns its assets to the vault', async function () {
+      const tx = vault.tx.removeStrategy(strategyA.address);
+
+      await expect(tx).to.changePSP22Balances(token, [vault.address, strategyA.address], [new BN(6000), new BN(-6000)]);
+      await expect(tx).to.emitEvent(vault, 'StrategyRemoved', { strategy: strategyA.address });
+      await expect(vault.query.totalAssets()).to.haveOkResult(10000);
+      expect((await vault.query.strategies()).value.ok!.length).to.equal(1);
+    });
+
+    it('force remove strategy drops a strategy failing to withdraw', async function () {
+      await strategyA.tx.tSetFrozen(true);
+      await expect(vault.query.removeStrategy(strategyA.address)).to.be.revertedWithError({ custom: 'S:Frozen' });
+
+      const tx = vault.tx.forceRemoveStrategy(strategyA.address);
+      await expect(tx).to.changePSP22Balances(token, [vault.address, strategyA.address], [new BN(0), new BN(0)]);
+      await expect(tx).to.emitEvent(vault, 'StrategyRemoved', { strategy: strategyA.address });
+      // the assets of the removed strategy are written off
+      await expect(vault.query.totalAssets()).to.haveOkResult(4000);
+      expect((await vault.query.strategies()).value.ok!.map((s) => s.account.toString())).to.deep.equal([strategyB.address]);
+    });
+  });
+});